@@ -0,0 +1,60 @@
+//! Key-committing AEAD algorithms.
+
+use crate::AeadInPlace;
+
+#[cfg(feature = "digest")]
+use {
+    crate::{check_nonce_size, Nonce, Result},
+    digest::{CollisionResistant, Output},
+};
+
+/// Marker trait for key-committing AEAD algorithms.
+///
+/// Implementing this trait asserts that a ciphertext (in particular its tag)
+/// can only be produced under a single key: it is computationally infeasible
+/// to find two keys under which the same nonce, associated data and
+/// ciphertext decrypt successfully. Commonly used algorithms like AES-GCM
+/// and ChaCha20Poly1305 do NOT have this property, which enables
+/// partitioning oracle attacks when the key is derived from a low-entropy
+/// secret or chosen among several candidates.
+pub trait CommittingAead: AeadInPlace {
+    /// Compute a commitment to the key of this AEAD instance and `aad`,
+    /// usable as a key identifier.
+    ///
+    /// The commitment is `D(b"aead-commit-tag" || tag)` where `tag` is the
+    /// tag of the encryption of the empty message under `commit_nonce` and
+    /// `aad`. Protocols can transmit it alongside a ciphertext and compare it
+    /// against the commitment of each candidate key before decrypting, so
+    /// that key confusion is detected without using the AEAD as a
+    /// decryption oracle.
+    ///
+    /// The commitment only binds the key because of the guarantee made by
+    /// [`CommittingAead`] and the collision resistance of `D`: for other
+    /// algorithms distinct keys may produce equal commitments. It reveals
+    /// nothing about the key beyond what the tag of an empty message would.
+    ///
+    /// # Security
+    ///
+    /// Computing the commitment performs an encryption under `commit_nonce`.
+    /// `commit_nonce` MUST therefore be reserved for commitments: it MUST
+    /// NOT be used to encrypt any message under the same key, and it MUST
+    /// NOT be used with more than one `aad` value per key. Otherwise the
+    /// nonce is reused, which for GCM-style algorithms reveals the
+    /// authentication key. A simple way to satisfy this is a fixed nonce
+    /// which the protocol never assigns to messages (e.g. all-ones when
+    /// message nonces are counters starting at zero) together with a fixed
+    /// `aad`.
+    #[cfg(feature = "digest")]
+    fn commit_tag<D: CollisionResistant>(
+        &self,
+        commit_nonce: &Nonce<Self>,
+        aad: &[u8],
+    ) -> Result<Output<D>> {
+        check_nonce_size::<Self>()?;
+        let tag = self.encrypt_in_place_detached(commit_nonce, aad, &mut [])?;
+        Ok(D::new()
+            .chain_update(b"aead-commit-tag")
+            .chain_update(tag)
+            .finalize())
+    }
+}
@@ -1,6 +1,106 @@
 //! Development-related functionality
 pub use blobby;
 
+#[cfg(feature = "alloc")]
+use crate::{Aead, KeyInit, Nonce, Payload};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// AEAD test vector.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Copy, Debug)]
+pub struct TestVector<'a> {
+    /// Key.
+    pub key: &'a [u8],
+    /// Nonce.
+    pub nonce: &'a [u8],
+    /// Associated data.
+    pub aad: &'a [u8],
+    /// Plaintext.
+    pub pt: &'a [u8],
+    /// Ciphertext without the tag.
+    pub ct: &'a [u8],
+    /// Authentication tag, which is appended to the ciphertext.
+    pub tag: &'a [u8],
+}
+
+/// Run the given test vectors against the AEAD `A`.
+///
+/// For every vector this checks that encryption produces `ct || tag`, that
+/// decryption recovers `pt`, and that decryption fails if a bit of the tag
+/// is flipped.
+///
+/// # Panics
+/// If any of the checks fail.
+#[cfg(feature = "alloc")]
+pub fn run_test_vectors<A: Aead + KeyInit>(vectors: &[TestVector<'_>]) {
+    for (i, tv) in vectors.iter().enumerate() {
+        if let Err(reason) = run_test_vector::<A>(tv) {
+            panic!(
+                "\n\
+                    Failed test №{}\n\
+                    reason: \t{:?}\n\
+                    vector:\t{:?}\n\
+                ",
+                i, reason, tv,
+            );
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn run_test_vector<A: Aead + KeyInit>(tv: &TestVector<'_>) -> Result<(), &'static str> {
+    let cipher = A::new_from_slice(tv.key).map_err(|_| "wrong key size")?;
+    let nonce = <&Nonce<A>>::try_from(tv.nonce).map_err(|_| "wrong nonce size")?;
+
+    let mut expected = Vec::with_capacity(tv.ct.len() + tv.tag.len());
+    expected.extend_from_slice(tv.ct);
+    expected.extend_from_slice(tv.tag);
+
+    let res = cipher
+        .encrypt(
+            nonce,
+            Payload {
+                msg: tv.pt,
+                aad: tv.aad,
+            },
+        )
+        .map_err(|_| "encryption failure")?;
+    if res != expected {
+        return Err("encrypted data is different from target ciphertext");
+    }
+
+    let res = cipher
+        .decrypt(
+            nonce,
+            Payload {
+                msg: &expected,
+                aad: tv.aad,
+            },
+        )
+        .map_err(|_| "decryption failure")?;
+    if res != tv.pt {
+        return Err("decrypted data is different from target plaintext");
+    }
+
+    let last = expected.last_mut().ok_or("empty ciphertext")?;
+    *last ^= 1;
+    if cipher
+        .decrypt(
+            nonce,
+            Payload {
+                msg: &expected,
+                aad: tv.aad,
+            },
+        )
+        .is_ok()
+    {
+        return Err("decryption of forged ciphertext must return error");
+    }
+
+    Ok(())
+}
+
 /// Define AEAD test
 #[macro_export]
 macro_rules! new_test {
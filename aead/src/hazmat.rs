@@ -0,0 +1,33 @@
+//! Hazardous materials: low-level APIs which can be used to break the
+//! security of an AEAD if misused.
+//!
+//! # ⚠️ Warning
+//!
+//! These APIs are intended for interoperability testing and debugging only.
+//! Please use the high-level [`Aead`][crate::Aead] and
+//! [`AeadInPlace`][crate::AeadInPlace] traits instead.
+
+use crate::{AeadCore, Nonce};
+
+/// AEAD algorithms whose confidentiality layer is a stream cipher (e.g. CTR
+/// mode in AES-GCM or ChaCha20 in ChaCha20Poly1305) which can expose the raw
+/// keystream used for a given nonce.
+///
+/// This trait is optional: algorithms without such a layer (e.g. AES-SIV
+/// or AES-GCM-SIV, where the keystream depends on the message) do not
+/// implement it.
+///
+/// # ⚠️ Security Warning
+///
+/// The keystream is equivalent to the key for the given nonce: anyone who
+/// learns it can decrypt any message encrypted under that nonce. It MUST
+/// NOT be used to encrypt data (which would reuse it), logged, or otherwise
+/// leave the debugging context it was produced in.
+pub trait KeystreamAead: AeadCore {
+    /// Write the keystream which encryption under `nonce` XORs with the
+    /// plaintext into `out`, starting from the first plaintext byte.
+    ///
+    /// Encrypting `out.len()` zero bytes under `nonce` produces the same
+    /// bytes followed by the authentication tag.
+    fn keystream(&self, nonce: &Nonce<Self>, out: &mut [u8]);
+}
@@ -0,0 +1,52 @@
+//! Typed headers authenticated as associated data.
+
+use crate::{Buffer, Result};
+
+/// Structured metadata (e.g. a protocol version or sender ID) which is
+/// bound to a ciphertext as its associated data.
+///
+/// Used with [`Aead::encrypt_with_header`][crate::Aead::encrypt_with_header]
+/// and [`Aead::decrypt_with_header`][crate::Aead::decrypt_with_header].
+///
+/// # Encoding
+///
+/// Implementations must write each field with [`push_aad_field`], in a fixed
+/// order, so that the AAD is a canonical length-prefixed encoding:
+///
+/// ```text
+/// u64_be(field_1.len()) || field_1 || u64_be(field_2.len()) || field_2 || ...
+/// ```
+///
+/// Length prefixes make the encoding unambiguous: moving bytes from one
+/// field into an adjacent one results in a different AAD. Fixed-size
+/// integers should be encoded big-endian before being pushed as a field.
+///
+/// ```
+/// use aead::{push_aad_field, AadHeader, Buffer, Result};
+///
+/// struct Header<'a> {
+///     version: u16,
+///     sender: &'a [u8],
+/// }
+///
+/// impl AadHeader for Header<'_> {
+///     fn to_aad(&self, out: &mut impl Buffer) -> Result<()> {
+///         push_aad_field(out, &self.version.to_be_bytes())?;
+///         push_aad_field(out, self.sender)
+///     }
+/// }
+/// ```
+pub trait AadHeader {
+    /// Append the encoding of this header to `out`.
+    ///
+    /// Returns [`Error`][crate::Error] if `out` has insufficient capacity.
+    fn to_aad(&self, out: &mut impl Buffer) -> Result<()>;
+}
+
+/// Append `field` to `out` prefixed with its length as a big-endian `u64`.
+///
+/// See [`AadHeader`] for the resulting encoding.
+pub fn push_aad_field(out: &mut impl Buffer, field: &[u8]) -> Result<()> {
+    out.extend_from_slice(&(field.len() as u64).to_be_bytes())?;
+    out.extend_from_slice(field)
+}
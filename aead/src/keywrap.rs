@@ -0,0 +1,142 @@
+//! Key wrapping as defined in [RFC 3394] (e.g. AES-KW).
+//!
+//! Key wrapping is used to encrypt symmetric keys under a key-encryption key
+//! (KEK). Unlike regular AEAD encryption it does not use a nonce: integrity is
+//! provided by the fixed initial value which is checked during unwrapping.
+//!
+//! [RFC 3394]: https://www.rfc-editor.org/rfc/rfc3394
+
+use crate::{Error, Result};
+use cipher::{consts::U16, Block, BlockCipherDecrypt, BlockCipherEncrypt, BlockSizeUser};
+use crypto_common::{InnerInit, InnerUser};
+
+/// Size of a semiblock (half of the cipher block) in bytes.
+pub const SEMIBLOCK_SIZE: usize = 8;
+
+/// Default initial value defined in [RFC 3394 § 2.2.3.1].
+///
+/// [RFC 3394 § 2.2.3.1]: https://www.rfc-editor.org/rfc/rfc3394#section-2.2.3.1
+pub const DEFAULT_IV: [u8; SEMIBLOCK_SIZE] = [0xA6; SEMIBLOCK_SIZE];
+
+/// Key wrapping algorithm.
+pub trait KeyWrap {
+    /// Wrap `key` and write the result into `out`.
+    ///
+    /// The length of `key` MUST be a multiple of 8 bytes and at least 16 bytes.
+    /// `out` MUST be at least 8 bytes longer than `key`.
+    ///
+    /// Returns the number of bytes written into `out`, which is always equal
+    /// to the length of `key` plus 8.
+    fn wrap(&self, key: &[u8], out: &mut [u8]) -> Result<usize>;
+
+    /// Unwrap `wrapped` and write the recovered key into `out`.
+    ///
+    /// The length of `wrapped` MUST be a multiple of 8 bytes and at least
+    /// 24 bytes. `out` MUST be at least as long as `wrapped` minus 8 bytes.
+    ///
+    /// Returns the number of bytes written into `out`, which is always equal
+    /// to the length of `wrapped` minus 8. Returns [`Error`] if the integrity
+    /// check fails, in which case `out` is zeroed.
+    fn unwrap(&self, wrapped: &[u8], out: &mut [u8]) -> Result<usize>;
+}
+
+/// Generic implementation of the [RFC 3394] key wrap algorithm over
+/// a 128-bit block cipher `C` (e.g. AES-KW for AES).
+///
+/// It uses the [`DEFAULT_IV`] initial value.
+///
+/// [RFC 3394]: https://www.rfc-editor.org/rfc/rfc3394
+#[derive(Clone, Debug)]
+pub struct Kw<C> {
+    cipher: C,
+}
+
+impl<C> InnerUser for Kw<C> {
+    type Inner = C;
+}
+
+impl<C> InnerInit for Kw<C> {
+    #[inline]
+    fn inner_init(cipher: C) -> Self {
+        Self { cipher }
+    }
+}
+
+impl<C> KeyWrap for Kw<C>
+where
+    C: BlockSizeUser<BlockSize = U16> + BlockCipherEncrypt + BlockCipherDecrypt,
+{
+    fn wrap(&self, key: &[u8], out: &mut [u8]) -> Result<usize> {
+        if key.len() % SEMIBLOCK_SIZE != 0 || key.len() < 2 * SEMIBLOCK_SIZE {
+            return Err(Error);
+        }
+        let out_len = key.len() + SEMIBLOCK_SIZE;
+        let out = out.get_mut(..out_len).ok_or(Error)?;
+        let (a, r) = out.split_at_mut(SEMIBLOCK_SIZE);
+        a.copy_from_slice(&DEFAULT_IV);
+        r.copy_from_slice(key);
+
+        let n = r.len() / SEMIBLOCK_SIZE;
+        let mut block = Block::<C>::default();
+        for j in 0..6 {
+            for (i, ri) in r.chunks_exact_mut(SEMIBLOCK_SIZE).enumerate() {
+                block[..SEMIBLOCK_SIZE].copy_from_slice(a);
+                block[SEMIBLOCK_SIZE..].copy_from_slice(ri);
+                self.cipher.encrypt_block(&mut block);
+
+                let t = (n * j + i + 1) as u64;
+                xor_counter(&mut block[..SEMIBLOCK_SIZE], t);
+                a.copy_from_slice(&block[..SEMIBLOCK_SIZE]);
+                ri.copy_from_slice(&block[SEMIBLOCK_SIZE..]);
+            }
+        }
+
+        Ok(out_len)
+    }
+
+    fn unwrap(&self, wrapped: &[u8], out: &mut [u8]) -> Result<usize> {
+        if wrapped.len() % SEMIBLOCK_SIZE != 0 || wrapped.len() < 3 * SEMIBLOCK_SIZE {
+            return Err(Error);
+        }
+        let out_len = wrapped.len() - SEMIBLOCK_SIZE;
+        let r = out.get_mut(..out_len).ok_or(Error)?;
+        let mut a = [0u8; SEMIBLOCK_SIZE];
+        a.copy_from_slice(&wrapped[..SEMIBLOCK_SIZE]);
+        r.copy_from_slice(&wrapped[SEMIBLOCK_SIZE..]);
+
+        let n = r.len() / SEMIBLOCK_SIZE;
+        let mut block = Block::<C>::default();
+        for j in (0..6).rev() {
+            for (i, ri) in r.chunks_exact_mut(SEMIBLOCK_SIZE).enumerate().rev() {
+                let t = (n * j + i + 1) as u64;
+                xor_counter(&mut a, t);
+                block[..SEMIBLOCK_SIZE].copy_from_slice(&a);
+                block[SEMIBLOCK_SIZE..].copy_from_slice(ri);
+                self.cipher.decrypt_block(&mut block);
+
+                a.copy_from_slice(&block[..SEMIBLOCK_SIZE]);
+                ri.copy_from_slice(&block[SEMIBLOCK_SIZE..]);
+            }
+        }
+
+        // Constant-time comparison of the recovered initial value
+        let diff = a
+            .iter()
+            .zip(DEFAULT_IV.iter())
+            .fold(0u8, |acc, (x, y)| acc | (x ^ y));
+        if diff != 0 {
+            r.fill(0);
+            return Err(Error);
+        }
+
+        Ok(out_len)
+    }
+}
+
+/// XOR big-endian representation of the step counter `t` into `a`.
+#[inline]
+fn xor_counter(a: &mut [u8], t: u64) {
+    for (x, y) in a.iter_mut().zip(t.to_be_bytes()) {
+        *x ^= y;
+    }
+}
@@ -15,11 +15,33 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
+mod committing;
 #[cfg(feature = "dev")]
 pub mod dev;
+pub mod hazmat;
+mod header;
 
+#[cfg(feature = "keywrap")]
+pub mod keywrap;
+#[cfg(feature = "nonce-reuse-guard")]
+mod nonce_guard;
+#[cfg(feature = "alloc")]
+mod one_shot;
 pub mod stream;
+#[cfg(feature = "digest")]
+mod subkey;
+
+pub use committing::CommittingAead;
+pub use header::{push_aad_field, AadHeader};
+#[cfg(feature = "nonce-reuse-guard")]
+pub use nonce_guard::NonceReuseGuard;
+#[cfg(feature = "alloc")]
+pub use one_shot::OneShotAead;
+#[cfg(feature = "digest")]
+pub use subkey::DeriveSubkey;
 
 pub use crypto_common::{
     array::{self, typenum::consts},
@@ -32,6 +54,8 @@ pub use arrayvec;
 pub use bytes;
 #[cfg(feature = "getrandom")]
 pub use crypto_common::rand_core::OsRng;
+#[cfg(feature = "digest")]
+pub use digest;
 #[cfg(feature = "heapless")]
 pub use heapless;
 
@@ -68,6 +92,50 @@ impl fmt::Display for Error {
 
 impl core::error::Error for Error {}
 
+/// Error type returned by the `decrypt_checked` family of methods.
+///
+/// Unlike [`Error`], it distinguishes between ciphertexts which are too short
+/// to contain an authentication tag and ciphertexts which failed authentication.
+/// The length check depends only on public information and is performed
+/// before any cryptographic work, so reporting it separately does not leak
+/// anything about the key or the plaintext. Authentication failures remain
+/// opaque and are detected in constant time by the underlying algorithm.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum DecryptError {
+    /// Ciphertext is shorter than the authentication tag.
+    TooShort,
+    /// Ciphertext failed authentication.
+    Authentication,
+}
+
+impl fmt::Display for DecryptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::TooShort => "aead::DecryptError: ciphertext is too short",
+            Self::Authentication => "aead::DecryptError: authentication failed",
+        })
+    }
+}
+
+impl core::error::Error for DecryptError {}
+
+impl From<DecryptError> for Error {
+    #[inline]
+    fn from(_: DecryptError) -> Error {
+        Error
+    }
+}
+
+/// Position of the authentication tag within an attached ciphertext
+/// message, see [`AeadCore::TAG_POSITION`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum TagPosition {
+    /// The tag precedes the ciphertext: `tag || ciphertext`.
+    Prefix,
+    /// The tag follows the ciphertext: `ciphertext || tag`.
+    Postfix,
+}
+
 /// Nonce: single-use value for ensuring ciphertexts are unique
 pub type Nonce<A> = Array<u8, <A as AeadCore>::NonceSize>;
 
@@ -89,6 +157,148 @@ pub trait AeadCore {
     /// ciphertext vs. a plaintext.
     type CiphertextOverhead: ArraySize + Unsigned;
 
+    /// The length of a nonce in bytes, usable in `const` contexts.
+    const NONCE_SIZE: usize = Self::NonceSize::USIZE;
+
+    /// The maximum length of the tag in bytes, usable in `const` contexts.
+    const TAG_SIZE: usize = Self::TagSize::USIZE;
+
+    /// The upper bound amount of additional space in bytes required to
+    /// support a ciphertext vs. a plaintext, usable in `const` contexts.
+    const CIPHERTEXT_OVERHEAD: usize = Self::CiphertextOverhead::USIZE;
+
+    /// Maximum length of a plaintext in bytes which can be safely encrypted
+    /// under a single nonce.
+    ///
+    /// Exceeding this limit breaks security guarantees of the algorithm, so
+    /// the provided encryption methods return [`Error`] for longer inputs
+    /// before performing any encryption. Implementations of
+    /// `encrypt_in_place_detached` should check it as well.
+    ///
+    /// Limits of some commonly used algorithms are:
+    /// - AES-GCM: 2^36 - 32 bytes (~64 GiB)
+    /// - AES-GCM-SIV: 2^36 bytes
+    /// - ChaCha20Poly1305: 2^38 - 64 bytes (~256 GiB)
+    ///
+    /// Defaults to `u64::MAX`, i.e. no limit.
+    const MAX_PLAINTEXT_LEN: u64 = u64::MAX;
+
+    /// Whether a zero-length nonce (i.e. `NonceSize = U0`) is intended.
+    ///
+    /// Some constructions are only secure with single-use keys and hence
+    /// need no nonce at all. For any other algorithm an empty nonce is almost
+    /// certainly a misconfiguration resulting in catastrophic nonce reuse, so
+    /// unless this is set to `true`, [`AeadCore::nonce_from_slice`] and the
+    /// provided encryption methods return [`Error`] if the nonce size is zero.
+    ///
+    /// Defaults to `false`.
+    const ALLOW_EMPTY_NONCE: bool = false;
+
+    /// Position of the tag in messages produced by the attached-tag
+    /// methods, e.g. [`Aead::encrypt`].
+    ///
+    /// The provided `encrypt_in_place` and `decrypt_in_place` methods of
+    /// [`AeadInPlace`] and [`AeadMutInPlace`], and through them the blanket
+    /// [`Aead`] and [`AeadMut`] impls, honor this constant.
+    ///
+    /// Defaults to [`TagPosition::Postfix`].
+    const TAG_POSITION: TagPosition = TagPosition::Postfix;
+
+    /// Interpret `bytes` as a nonce for this AEAD algorithm.
+    ///
+    /// The nonce is not copied: the returned reference points into `bytes`.
+    ///
+    /// Returns [`Error`] if the length of `bytes` is not equal to the nonce
+    /// size, or if the nonce size is zero and [`AeadCore::ALLOW_EMPTY_NONCE`]
+    /// is `false`.
+    #[inline]
+    fn nonce_from_slice(bytes: &[u8]) -> Result<&Nonce<Self>> {
+        check_nonce_size::<Self>()?;
+        bytes.try_into().map_err(|_| Error)
+    }
+
+    /// Interpret `bytes` as a tag for this AEAD algorithm.
+    ///
+    /// The tag is not copied: the returned reference points into `bytes`.
+    ///
+    /// Returns [`Error`] if the length of `bytes` is not equal to the tag size.
+    #[inline]
+    fn tag_from_slice(bytes: &[u8]) -> Result<&Tag<Self>> {
+        bytes.try_into().map_err(|_| Error)
+    }
+
+    /// Split a combined `ciphertext || tag` message into the ciphertext and
+    /// the tag which follows it.
+    ///
+    /// Nothing is copied: the returned references point into `data`.
+    ///
+    /// Returns [`Error`] if `data` is shorter than the tag size.
+    #[inline]
+    fn split_ct_tag(data: &[u8]) -> Result<(&[u8], &Tag<Self>)> {
+        let ct_len = data.len().checked_sub(Self::TAG_SIZE).ok_or(Error)?;
+        let (ct, tag) = data.split_at(ct_len);
+        Ok((ct, Self::tag_from_slice(tag)?))
+    }
+
+    /// Split a combined `tag || ciphertext` message into the tag which
+    /// precedes the ciphertext and the ciphertext.
+    ///
+    /// Nothing is copied: the returned references point into `data`.
+    ///
+    /// Returns [`Error`] if `data` is shorter than the tag size.
+    #[inline]
+    fn split_tag_ct(data: &[u8]) -> Result<(&Tag<Self>, &[u8])> {
+        if data.len() < Self::TAG_SIZE {
+            return Err(Error);
+        }
+        let (tag, ct) = data.split_at(Self::TAG_SIZE);
+        Ok((Self::tag_from_slice(tag)?, ct))
+    }
+
+    /// Convert an attached-tag message into its detached ciphertext and
+    /// tag, honoring [`AeadCore::TAG_POSITION`].
+    ///
+    /// This is a pure byte layout transform which requires no key and
+    /// performs no authentication. Nothing is copied: the returned
+    /// references point into `data`.
+    ///
+    /// Returns [`Error`] if `data` is shorter than the tag size.
+    #[inline]
+    fn attached_to_detached(data: &[u8]) -> Result<(&[u8], &Tag<Self>)> {
+        match Self::TAG_POSITION {
+            TagPosition::Prefix => Self::split_tag_ct(data).map(|(tag, ct)| (ct, tag)),
+            TagPosition::Postfix => Self::split_ct_tag(data),
+        }
+    }
+
+    /// Convert a detached ciphertext and tag into an attached-tag message
+    /// written to the start of `out`, honoring [`AeadCore::TAG_POSITION`].
+    ///
+    /// This is a pure byte layout transform which requires no key and
+    /// performs no authentication. Returns the written part of `out`, or
+    /// [`Error`] if `out` is shorter than `ct.len()` plus the tag size.
+    fn detached_to_attached_into<'out>(
+        ct: &[u8],
+        tag: &Tag<Self>,
+        out: &'out mut [u8],
+    ) -> Result<&'out mut [u8]> {
+        let len = ct.len().checked_add(Self::TAG_SIZE).ok_or(Error)?;
+        let out = out.get_mut(..len).ok_or(Error)?;
+        match Self::TAG_POSITION {
+            TagPosition::Prefix => {
+                let (tag_out, ct_out) = out.split_at_mut(Self::TAG_SIZE);
+                tag_out.copy_from_slice(tag);
+                ct_out.copy_from_slice(ct);
+            }
+            TagPosition::Postfix => {
+                let (ct_out, tag_out) = out.split_at_mut(ct.len());
+                ct_out.copy_from_slice(ct);
+                tag_out.copy_from_slice(tag);
+            }
+        }
+        Ok(out)
+    }
+
     /// Generate a random nonce for this AEAD algorithm.
     ///
     /// AEAD algorithms accept a parameter to encryption/decryption called
@@ -177,10 +387,9 @@ pub trait Aead: AeadCore {
     /// let ciphertext = cipher.encrypt(nonce, plaintext);
     /// ```
     ///
-    /// The default implementation assumes a postfix tag (ala AES-GCM,
-    /// AES-GCM-SIV, ChaCha20Poly1305). [`Aead`] implementations which do not
-    /// use a postfix tag will need to override this to correctly assemble the
-    /// ciphertext message.
+    /// The blanket implementation for [`AeadInPlace`] types places the tag
+    /// as specified by [`AeadCore::TAG_POSITION`], i.e. after the ciphertext
+    /// by default (ala AES-GCM, AES-GCM-SIV, ChaCha20Poly1305).
     fn encrypt<'msg, 'aad>(
         &self,
         nonce: &Nonce<Self>,
@@ -200,15 +409,117 @@ pub trait Aead: AeadCore {
     /// let plaintext = cipher.decrypt(nonce, ciphertext)?;
     /// ```
     ///
-    /// The default implementation assumes a postfix tag (ala AES-GCM,
-    /// AES-GCM-SIV, ChaCha20Poly1305). [`Aead`] implementations which do not
-    /// use a postfix tag will need to override this to correctly parse the
-    /// ciphertext message.
+    /// The blanket implementation for [`AeadInPlace`] types expects the tag
+    /// at the position specified by [`AeadCore::TAG_POSITION`].
     fn decrypt<'msg, 'aad>(
         &self,
         nonce: &Nonce<Self>,
         ciphertext: impl Into<Payload<'msg, 'aad>>,
     ) -> Result<Vec<u8>>;
+
+    /// Decrypt the given ciphertext slice similarly to [`Aead::decrypt()`],
+    /// but report ciphertexts too short to contain a tag separately
+    /// from authentication failures.
+    ///
+    /// See [`DecryptError`] for details.
+    fn decrypt_checked<'msg, 'aad>(
+        &self,
+        nonce: &Nonce<Self>,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> core::result::Result<Vec<u8>, DecryptError> {
+        let payload = ciphertext.into();
+        if payload.msg.len() < Self::TAG_SIZE {
+            return Err(DecryptError::TooShort);
+        }
+        self.decrypt(nonce, payload)
+            .map_err(|_| DecryptError::Authentication)
+    }
+
+    /// Encrypt the given framed payload, binding the plaintext length to
+    /// the ciphertext by authenticating it as part of the AAD.
+    ///
+    /// See [`FramedPayload`] for the exact encoding of the AAD.
+    fn encrypt_framed(
+        &self,
+        nonce: &Nonce<Self>,
+        payload: FramedPayload<'_, '_>,
+    ) -> Result<Vec<u8>> {
+        let aad = payload.framed_aad(payload.msg.len());
+        self.encrypt(
+            nonce,
+            Payload {
+                msg: payload.msg,
+                aad: &aad,
+            },
+        )
+    }
+
+    /// Decrypt the given framed payload produced by [`Aead::encrypt_framed`].
+    ///
+    /// The plaintext length is reconstructed from the ciphertext length, so
+    /// a truncated or extended ciphertext fails authentication. The default
+    /// implementation assumes that the ciphertext length is equal to the
+    /// plaintext length plus [`AeadCore::TAG_SIZE`] and
+    /// [`AeadCore::CIPHERTEXT_OVERHEAD`].
+    fn decrypt_framed(
+        &self,
+        nonce: &Nonce<Self>,
+        payload: FramedPayload<'_, '_>,
+    ) -> Result<Vec<u8>> {
+        let msg_len = payload
+            .msg
+            .len()
+            .checked_sub(Self::TAG_SIZE + Self::CIPHERTEXT_OVERHEAD)
+            .ok_or(Error)?;
+        let aad = payload.framed_aad(msg_len);
+        let plaintext = self.decrypt(
+            nonce,
+            Payload {
+                msg: payload.msg,
+                aad: &aad,
+            },
+        )?;
+        if plaintext.len() != msg_len {
+            return Err(Error);
+        }
+        Ok(plaintext)
+    }
+
+    /// Encrypt `msg`, authenticating the canonical encoding of `header` as
+    /// the AAD.
+    ///
+    /// See [`AadHeader`] for the encoding.
+    fn encrypt_with_header<H: AadHeader>(
+        &self,
+        nonce: &Nonce<Self>,
+        header: &H,
+        msg: &[u8],
+    ) -> Result<Vec<u8>> {
+        let mut aad = Vec::new();
+        header.to_aad(&mut aad)?;
+        self.encrypt(nonce, Payload { msg, aad: &aad })
+    }
+
+    /// Decrypt a ciphertext produced by [`Aead::encrypt_with_header`].
+    ///
+    /// Returns [`Error`] if `header` differs from the one used for
+    /// encryption in any field.
+    fn decrypt_with_header<H: AadHeader>(
+        &self,
+        nonce: &Nonce<Self>,
+        header: &H,
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>> {
+        let mut aad = Vec::new();
+        header.to_aad(&mut aad)?;
+        self.decrypt(
+            nonce,
+            Payload {
+                msg: ciphertext,
+                aad: &aad,
+            },
+        )
+    }
 }
 
 /// Stateful Authenticated Encryption with Associated Data algorithm.
@@ -235,29 +546,87 @@ pub trait AeadMut: AeadCore {
         nonce: &Nonce<Self>,
         ciphertext: impl Into<Payload<'msg, 'aad>>,
     ) -> Result<Vec<u8>>;
+
+    /// Decrypt the given ciphertext slice similarly to [`AeadMut::decrypt()`],
+    /// but report ciphertexts too short to contain a tag separately
+    /// from authentication failures.
+    ///
+    /// See [`DecryptError`] for details.
+    fn decrypt_checked<'msg, 'aad>(
+        &mut self,
+        nonce: &Nonce<Self>,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> core::result::Result<Vec<u8>, DecryptError> {
+        let payload = ciphertext.into();
+        if payload.msg.len() < Self::TAG_SIZE {
+            return Err(DecryptError::TooShort);
+        }
+        self.decrypt(nonce, payload)
+            .map_err(|_| DecryptError::Authentication)
+    }
+}
+
+/// Check that the nonce is not empty unless allowed by
+/// [`AeadCore::ALLOW_EMPTY_NONCE`].
+#[inline]
+fn check_nonce_size<A: AeadCore + ?Sized>() -> Result<()> {
+    if A::NONCE_SIZE == 0 && !A::ALLOW_EMPTY_NONCE {
+        Err(Error)
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that `len` does not exceed [`AeadCore::MAX_PLAINTEXT_LEN`].
+#[inline]
+fn check_plaintext_len<A: AeadCore + ?Sized>(len: usize) -> Result<()> {
+    match u64::try_from(len) {
+        Ok(len) if len <= A::MAX_PLAINTEXT_LEN => Ok(()),
+        _ => Err(Error),
+    }
 }
 
 /// Implement the `decrypt_in_place` method on [`AeadInPlace`] and
 /// [`AeadMutInPlace]`, using a macro to gloss over the `&self` vs `&mut self`.
 ///
-/// Assumes a postfix authentication tag. AEAD ciphers which do not use a
-/// postfix authentication tag will need to define their own implementation.
+/// The tag is expected at the position given by [`AeadCore::TAG_POSITION`].
 macro_rules! impl_decrypt_in_place {
     ($aead:expr, $nonce:expr, $aad:expr, $buffer:expr) => {{
-        let tag_pos = $buffer
-            .len()
-            .checked_sub(Self::TagSize::to_usize())
-            .ok_or(Error)?;
+        let tag_size = Self::TagSize::to_usize();
+        let msg_len = $buffer.len().checked_sub(tag_size).ok_or(Error)?;
 
-        let (msg, tag) = $buffer.as_mut().split_at_mut(tag_pos);
+        let (msg, tag) = match Self::TAG_POSITION {
+            TagPosition::Prefix => {
+                let (tag, msg) = $buffer.as_mut().split_at_mut(tag_size);
+                (msg, tag)
+            }
+            TagPosition::Postfix => $buffer.as_mut().split_at_mut(msg_len),
+        };
         let tag = Tag::<Self>::try_from(&*tag).expect("tag length mismatch");
 
         $aead.decrypt_in_place_detached($nonce, $aad, msg, &tag)?;
-        $buffer.truncate(tag_pos);
+        if Self::TAG_POSITION == TagPosition::Prefix {
+            $buffer.as_mut().copy_within(tag_size.., 0);
+        }
+        $buffer.truncate(msg_len);
         Ok(())
     }};
 }
 
+/// Append `tag` to the ciphertext in `buffer`, or prepend it if
+/// [`AeadCore::TAG_POSITION`] of `A` is [`TagPosition::Prefix`].
+fn attach_tag<A, B>(buffer: &mut B, tag: &Tag<A>) -> Result<()>
+where
+    A: AeadCore + ?Sized,
+    B: Buffer + ?Sized,
+{
+    buffer.extend_from_slice(tag)?;
+    if A::TAG_POSITION == TagPosition::Prefix {
+        buffer.as_mut().rotate_right(A::TAG_SIZE);
+    }
+    Ok(())
+}
+
 /// In-place stateless AEAD trait.
 ///
 /// This trait is both object safe and has no dependencies on `alloc` or `std`.
@@ -270,16 +639,18 @@ pub trait AeadInPlace: AeadCore {
     /// the size of an authentication tag.
     ///
     /// Returns an error if the buffer has insufficient capacity to store the
-    /// resulting ciphertext message.
+    /// resulting ciphertext message, or if the plaintext is longer than
+    /// [`AeadCore::MAX_PLAINTEXT_LEN`].
     fn encrypt_in_place(
         &self,
         nonce: &Nonce<Self>,
         associated_data: &[u8],
         buffer: &mut dyn Buffer,
     ) -> Result<()> {
+        check_nonce_size::<Self>()?;
+        check_plaintext_len::<Self>(buffer.len())?;
         let tag = self.encrypt_in_place_detached(nonce, associated_data, buffer.as_mut())?;
-        buffer.extend_from_slice(tag.as_slice())?;
-        Ok(())
+        attach_tag::<Self, _>(buffer, &tag)
     }
 
     /// Encrypt the data in-place, returning the authentication tag
@@ -290,6 +661,55 @@ pub trait AeadInPlace: AeadCore {
         buffer: &mut [u8],
     ) -> Result<Tag<Self>>;
 
+    /// Encrypt the data in-place, writing the authentication tag into `tag_out`.
+    ///
+    /// This is useful when the tag has to be placed into an existing location
+    /// (e.g. a slot of a packet buffer). The default implementation calls
+    /// [`encrypt_in_place_detached`][Self::encrypt_in_place_detached] and
+    /// copies the returned tag, implementations may override it to avoid
+    /// the copy.
+    ///
+    /// On error `tag_out` is left unmodified.
+    fn encrypt_in_place_detached_into(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag_out: &mut Tag<Self>,
+    ) -> Result<()> {
+        check_nonce_size::<Self>()?;
+        check_plaintext_len::<Self>(buffer.len())?;
+        *tag_out = self.encrypt_in_place_detached(nonce, associated_data, buffer)?;
+        Ok(())
+    }
+
+    /// Encrypt the first `msg_len` bytes of `in_out` in-place, writing the
+    /// authentication tag into the first [`AeadCore::TAG_SIZE`] bytes of
+    /// `tag_out`, using only slice-based arguments.
+    ///
+    /// This is intended for exposing the AEAD over a C FFI. Returns the total
+    /// number of bytes written, i.e. `msg_len` plus the tag size.
+    ///
+    /// Returns [`Error`] if `nonce` has the wrong length, `msg_len` exceeds
+    /// the length of `in_out`, or `tag_out` is too short to hold the tag.
+    /// All lengths are validated before anything is written.
+    fn ffi_encrypt(
+        &self,
+        nonce: &[u8],
+        aad: &[u8],
+        in_out: &mut [u8],
+        msg_len: usize,
+        tag_out: &mut [u8],
+    ) -> Result<usize> {
+        let nonce = <&Nonce<Self>>::try_from(nonce).map_err(|_| Error)?;
+        let buffer = in_out.get_mut(..msg_len).ok_or(Error)?;
+        let tag_out = tag_out.get_mut(..Self::TAG_SIZE).ok_or(Error)?;
+        let tag_out = <&mut Tag<Self>>::try_from(tag_out).map_err(|_| Error)?;
+        let written = msg_len.checked_add(Self::TAG_SIZE).ok_or(Error)?;
+        self.encrypt_in_place_detached_into(nonce, aad, buffer, tag_out)?;
+        Ok(written)
+    }
+
     /// Decrypt the message in-place, returning an error in the event the
     /// provided authentication tag does not match the given ciphertext.
     ///
@@ -304,6 +724,25 @@ pub trait AeadInPlace: AeadCore {
         impl_decrypt_in_place!(self, nonce, associated_data, buffer)
     }
 
+    /// Decrypt the message in-place similarly to
+    /// [`decrypt_in_place`][Self::decrypt_in_place], but report buffers too
+    /// short to contain a tag separately from authentication failures.
+    ///
+    /// See [`DecryptError`] for details. The buffer is left unmodified if
+    /// [`DecryptError::TooShort`] is returned.
+    fn decrypt_in_place_checked(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> core::result::Result<(), DecryptError> {
+        if buffer.len() < Self::TAG_SIZE {
+            return Err(DecryptError::TooShort);
+        }
+        self.decrypt_in_place(nonce, associated_data, buffer)
+            .map_err(|_| DecryptError::Authentication)
+    }
+
     /// Decrypt the message in-place, returning an error in the event the provided
     /// authentication tag does not match the given ciphertext (i.e. ciphertext
     /// is modified/unauthentic)
@@ -314,6 +753,88 @@ pub trait AeadInPlace: AeadCore {
         buffer: &mut [u8],
         tag: &Tag<Self>,
     ) -> Result<()>;
+
+    /// Authenticate `associated_data` without encrypting any message,
+    /// returning the authentication tag.
+    ///
+    /// This allows using the AEAD as a MAC. The default implementation
+    /// performs detached encryption of an empty message, and returns
+    /// [`Error`] if the nonce size is zero and [`AeadCore::ALLOW_EMPTY_NONCE`]
+    /// is `false`.
+    ///
+    /// Nonce requirements of the AEAD still apply: a nonce MUST NOT be reused
+    /// with the same key, including across calls of this method and
+    /// encryption of regular messages.
+    fn authenticate(&self, nonce: &Nonce<Self>, associated_data: &[u8]) -> Result<Tag<Self>> {
+        check_nonce_size::<Self>()?;
+        self.encrypt_in_place_detached(nonce, associated_data, &mut [])
+    }
+
+    /// Verify that `tag` authenticates `associated_data` under `nonce`,
+    /// i.e. that it was produced by [`authenticate`][Self::authenticate].
+    ///
+    /// The default implementation performs detached decryption of an empty
+    /// message, and rejects empty nonces like [`authenticate`][Self::authenticate].
+    fn verify_authenticated(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        check_nonce_size::<Self>()?;
+        self.decrypt_in_place_detached(nonce, associated_data, &mut [], tag)
+    }
+
+    /// Encrypt the given plaintext payload into a stack-allocated
+    /// [`ArrayVec`][arrayvec::ArrayVec] with capacity `N`.
+    ///
+    /// Returns [`Error`] if the resulting ciphertext (including the tag)
+    /// would be longer than `N` bytes.
+    #[cfg(feature = "arrayvec")]
+    fn encrypt_to_arrayvec<'msg, 'aad, const N: usize>(
+        &self,
+        nonce: &Nonce<Self>,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<arrayvec::ArrayVec<u8, N>>
+    where
+        Self: Sized,
+    {
+        let payload = plaintext.into();
+        let ct_len = payload.msg.len() + Self::CIPHERTEXT_OVERHEAD + Self::TAG_SIZE;
+        if ct_len > N {
+            return Err(Error);
+        }
+
+        let mut buffer = arrayvec::ArrayVec::new();
+        buffer
+            .try_extend_from_slice(payload.msg)
+            .map_err(|_| Error)?;
+        self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// Decrypt the given ciphertext payload into a stack-allocated
+    /// [`ArrayVec`][arrayvec::ArrayVec] with capacity `N`.
+    ///
+    /// Returns [`Error`] if the ciphertext (including the tag) is longer
+    /// than `N` bytes, or if it is not authentic.
+    #[cfg(feature = "arrayvec")]
+    fn decrypt_to_arrayvec<'msg, 'aad, const N: usize>(
+        &self,
+        nonce: &Nonce<Self>,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<arrayvec::ArrayVec<u8, N>>
+    where
+        Self: Sized,
+    {
+        let payload = ciphertext.into();
+        let mut buffer = arrayvec::ArrayVec::new();
+        buffer
+            .try_extend_from_slice(payload.msg)
+            .map_err(|_| Error)?;
+        self.decrypt_in_place(nonce, payload.aad, &mut buffer)?;
+        Ok(buffer)
+    }
 }
 
 /// In-place stateful AEAD trait.
@@ -328,16 +849,18 @@ pub trait AeadMutInPlace: AeadCore {
     /// the size of an authentication tag.
     ///
     /// Returns an error if the buffer has insufficient capacity to store the
-    /// resulting ciphertext message.
+    /// resulting ciphertext message, or if the plaintext is longer than
+    /// [`AeadCore::MAX_PLAINTEXT_LEN`].
     fn encrypt_in_place(
         &mut self,
         nonce: &Nonce<Self>,
         associated_data: &[u8],
         buffer: &mut impl Buffer,
     ) -> Result<()> {
+        check_nonce_size::<Self>()?;
+        check_plaintext_len::<Self>(buffer.len())?;
         let tag = self.encrypt_in_place_detached(nonce, associated_data, buffer.as_mut())?;
-        buffer.extend_from_slice(tag.as_slice())?;
-        Ok(())
+        attach_tag::<Self, _>(buffer, &tag)
     }
 
     /// Encrypt the data in-place, returning the authentication tag
@@ -348,6 +871,28 @@ pub trait AeadMutInPlace: AeadCore {
         buffer: &mut [u8],
     ) -> Result<Tag<Self>>;
 
+    /// Encrypt the data in-place, writing the authentication tag into `tag_out`.
+    ///
+    /// This is useful when the tag has to be placed into an existing location
+    /// (e.g. a slot of a packet buffer). The default implementation calls
+    /// [`encrypt_in_place_detached`][Self::encrypt_in_place_detached] and
+    /// copies the returned tag, implementations may override it to avoid
+    /// the copy.
+    ///
+    /// On error `tag_out` is left unmodified.
+    fn encrypt_in_place_detached_into(
+        &mut self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag_out: &mut Tag<Self>,
+    ) -> Result<()> {
+        check_nonce_size::<Self>()?;
+        check_plaintext_len::<Self>(buffer.len())?;
+        *tag_out = self.encrypt_in_place_detached(nonce, associated_data, buffer)?;
+        Ok(())
+    }
+
     /// Decrypt the message in-place, returning an error in the event the
     /// provided authentication tag does not match the given ciphertext.
     ///
@@ -362,6 +907,25 @@ pub trait AeadMutInPlace: AeadCore {
         impl_decrypt_in_place!(self, nonce, associated_data, buffer)
     }
 
+    /// Decrypt the message in-place similarly to
+    /// [`decrypt_in_place`][Self::decrypt_in_place], but report buffers too
+    /// short to contain a tag separately from authentication failures.
+    ///
+    /// See [`DecryptError`] for details. The buffer is left unmodified if
+    /// [`DecryptError::TooShort`] is returned.
+    fn decrypt_in_place_checked(
+        &mut self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut impl Buffer,
+    ) -> core::result::Result<(), DecryptError> {
+        if buffer.len() < Self::TAG_SIZE {
+            return Err(DecryptError::TooShort);
+        }
+        self.decrypt_in_place(nonce, associated_data, buffer)
+            .map_err(|_| DecryptError::Authentication)
+    }
+
     /// Decrypt the data in-place, returning an error in the event the provided
     /// authentication tag does not match the given ciphertext (i.e. ciphertext
     /// is modified/unauthentic)
@@ -374,6 +938,95 @@ pub trait AeadMutInPlace: AeadCore {
     ) -> Result<()>;
 }
 
+/// In-place stateless AEAD trait for algorithms which accept nonces of
+/// several lengths, e.g. a standard nonce and an extended one from which
+/// a working key and nonce are derived (like in XChaCha20Poly1305).
+///
+/// The default method implementations accept only nonces of the standard
+/// [`AeadCore::NonceSize`] length and return [`Error`] for any other length.
+/// Algorithms supporting extended nonces should override them.
+pub trait ExtendedNonceAead: AeadInPlace {
+    /// Encrypt the data in-place using a nonce of any supported length,
+    /// returning the authentication tag.
+    ///
+    /// Returns [`Error`] if the length of `nonce` is not supported.
+    fn encrypt_in_place_detached_ext(
+        &self,
+        nonce: &[u8],
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        let nonce = <&Nonce<Self>>::try_from(nonce).map_err(|_| Error)?;
+        self.encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    /// Decrypt the message in-place using a nonce of any supported length,
+    /// returning an error in the event the provided authentication tag does
+    /// not match the given ciphertext.
+    ///
+    /// Returns [`Error`] if the length of `nonce` is not supported.
+    fn decrypt_in_place_detached_ext(
+        &self,
+        nonce: &[u8],
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        let nonce = <&Nonce<Self>>::try_from(nonce).map_err(|_| Error)?;
+        self.decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+/// In-place AEAD trait with a reusable per-key context.
+///
+/// Some AEADs derive per-key values (e.g. the GHASH subkey of AES-GCM) on
+/// every call. Implementations of this trait can compute such values once
+/// in [`context`][Self::context] and reuse them across many messages
+/// encrypted or decrypted under the same key.
+///
+/// Algorithms without anything to cache can use `()` as the context and
+/// rely on the default method implementations, which ignore the context
+/// and use the regular [`AeadInPlace`] methods.
+pub trait AeadContextInPlace: AeadInPlace {
+    /// Reusable context type.
+    type Context;
+
+    /// Create a context for use with this key.
+    fn context(&self) -> Self::Context;
+
+    /// Encrypt the data in-place using a context previously created by
+    /// [`context`][Self::context], returning the authentication tag.
+    ///
+    /// The result must be identical to
+    /// [`AeadInPlace::encrypt_in_place_detached`].
+    fn encrypt_in_place_detached_with_context(
+        &self,
+        _context: &Self::Context,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        self.encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    /// Decrypt the message in-place using a context previously created by
+    /// [`context`][Self::context], returning an error in the event the
+    /// provided authentication tag does not match the given ciphertext.
+    ///
+    /// The result must be identical to
+    /// [`AeadInPlace::decrypt_in_place_detached`].
+    fn decrypt_in_place_detached_with_context(
+        &self,
+        _context: &Self::Context,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        self.decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<Alg: AeadInPlace> Aead for Alg {
     fn encrypt<'msg, 'aad>(
@@ -382,7 +1035,10 @@ impl<Alg: AeadInPlace> Aead for Alg {
         plaintext: impl Into<Payload<'msg, 'aad>>,
     ) -> Result<Vec<u8>> {
         let payload = plaintext.into();
-        let mut buffer = Vec::with_capacity(payload.msg.len() + Self::TagSize::to_usize());
+        check_nonce_size::<Self>()?;
+        check_plaintext_len::<Self>(payload.msg.len())?;
+        let mut buffer =
+            Vec::with_capacity(payload.msg.len() + Self::CIPHERTEXT_OVERHEAD + Self::TAG_SIZE);
         buffer.extend_from_slice(payload.msg);
         self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
         Ok(buffer)
@@ -408,7 +1064,10 @@ impl<Alg: AeadMutInPlace> AeadMut for Alg {
         plaintext: impl Into<Payload<'msg, 'aad>>,
     ) -> Result<Vec<u8>> {
         let payload = plaintext.into();
-        let mut buffer = Vec::with_capacity(payload.msg.len() + Self::TagSize::to_usize());
+        check_nonce_size::<Self>()?;
+        check_plaintext_len::<Self>(payload.msg.len())?;
+        let mut buffer =
+            Vec::with_capacity(payload.msg.len() + Self::CIPHERTEXT_OVERHEAD + Self::TAG_SIZE);
         buffer.extend_from_slice(payload.msg);
         self.encrypt_in_place(nonce, payload.aad, &mut buffer)?;
         Ok(buffer)
@@ -445,6 +1104,22 @@ impl<Alg: AeadInPlace> AeadMutInPlace for Alg {
         <Self as AeadInPlace>::encrypt_in_place_detached(self, nonce, associated_data, buffer)
     }
 
+    fn encrypt_in_place_detached_into(
+        &mut self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag_out: &mut Tag<Self>,
+    ) -> Result<()> {
+        <Self as AeadInPlace>::encrypt_in_place_detached_into(
+            self,
+            nonce,
+            associated_data,
+            buffer,
+            tag_out,
+        )
+    }
+
     fn decrypt_in_place(
         &mut self,
         nonce: &Nonce<Self>,
@@ -454,6 +1129,15 @@ impl<Alg: AeadInPlace> AeadMutInPlace for Alg {
         <Self as AeadInPlace>::decrypt_in_place(self, nonce, associated_data, buffer)
     }
 
+    fn decrypt_in_place_checked(
+        &mut self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut impl Buffer,
+    ) -> core::result::Result<(), DecryptError> {
+        <Self as AeadInPlace>::decrypt_in_place_checked(self, nonce, associated_data, buffer)
+    }
+
     fn decrypt_in_place_detached(
         &mut self,
         nonce: &Nonce<Self>,
@@ -465,6 +1149,88 @@ impl<Alg: AeadInPlace> AeadMutInPlace for Alg {
     }
 }
 
+impl<Alg: AeadCore> AeadCore for &Alg {
+    type NonceSize = Alg::NonceSize;
+    type TagSize = Alg::TagSize;
+    type CiphertextOverhead = Alg::CiphertextOverhead;
+    const MAX_PLAINTEXT_LEN: u64 = Alg::MAX_PLAINTEXT_LEN;
+    const ALLOW_EMPTY_NONCE: bool = Alg::ALLOW_EMPTY_NONCE;
+    const TAG_POSITION: TagPosition = Alg::TAG_POSITION;
+}
+
+/// Forwarding impl which allows passing references to AEADs to generic code
+/// (e.g. accepting `impl Aead`) without cloning the cipher.
+impl<Alg: AeadInPlace> AeadInPlace for &Alg {
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        Alg::encrypt_in_place(self, nonce, associated_data, buffer)
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        Alg::encrypt_in_place_detached(self, nonce, associated_data, buffer)
+    }
+
+    fn encrypt_in_place_detached_into(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag_out: &mut Tag<Self>,
+    ) -> Result<()> {
+        Alg::encrypt_in_place_detached_into(self, nonce, associated_data, buffer, tag_out)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        Alg::decrypt_in_place(self, nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_checked(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> core::result::Result<(), DecryptError> {
+        Alg::decrypt_in_place_checked(self, nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        Alg::decrypt_in_place_detached(self, nonce, associated_data, buffer, tag)
+    }
+
+    fn authenticate(&self, nonce: &Nonce<Self>, associated_data: &[u8]) -> Result<Tag<Self>> {
+        Alg::authenticate(self, nonce, associated_data)
+    }
+
+    fn verify_authenticated(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        Alg::verify_authenticated(self, nonce, associated_data, tag)
+    }
+}
+
 /// AEAD payloads (message + AAD).
 ///
 /// Combination of a message (plaintext or ciphertext) and
@@ -493,6 +1259,54 @@ impl<'msg> From<&'msg [u8]> for Payload<'msg, '_> {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl<'msg, 'aad> Payload<'msg, 'aad> {
+    /// Create a [`FramedPayload`] for `msg` with no other AAD.
+    pub fn framed(msg: &'msg [u8]) -> FramedPayload<'msg, 'aad> {
+        FramedPayload { msg, aad: b"" }
+    }
+
+    /// Convert this payload into a [`FramedPayload`], which appends the
+    /// message length to the AAD.
+    pub fn into_framed(self) -> FramedPayload<'msg, 'aad> {
+        FramedPayload {
+            msg: self.msg,
+            aad: self.aad,
+        }
+    }
+}
+
+/// AEAD payload whose AAD is extended with the plaintext length.
+///
+/// Used with [`Aead::encrypt_framed`] and [`Aead::decrypt_framed`]. The AAD
+/// which is actually authenticated is the concatenation of [`Self::aad`] and
+/// the length of the plaintext in bytes encoded as a big-endian `u64`:
+///
+/// ```text
+/// aad || u64_be(plaintext.len())
+/// ```
+///
+/// With an empty `aad` this is just the 8-byte length.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct FramedPayload<'msg, 'aad> {
+    /// Message to be encrypted/decrypted
+    pub msg: &'msg [u8],
+
+    /// Additional associated data preceding the encoded length.
+    pub aad: &'aad [u8],
+}
+
+#[cfg(feature = "alloc")]
+impl FramedPayload<'_, '_> {
+    fn framed_aad(&self, msg_len: usize) -> Vec<u8> {
+        let mut aad = Vec::with_capacity(self.aad.len() + 8);
+        aad.extend_from_slice(self.aad);
+        aad.extend_from_slice(&(msg_len as u64).to_be_bytes());
+        aad
+    }
+}
+
 /// In-place encryption/decryption byte buffers.
 ///
 /// This trait defines the set of methods needed to support in-place operations
@@ -0,0 +1,151 @@
+//! Nonce reuse detector for testing.
+
+use crate::{AeadCore, AeadInPlace, Buffer, Nonce, Result, Tag, TagPosition};
+use core::fmt;
+
+#[cfg(debug_assertions)]
+use std::{
+    collections::HashSet,
+    sync::{Mutex, PoisonError},
+};
+
+/// AEAD wrapper which panics in debug builds if the same nonce is used
+/// more than once for encryption.
+///
+/// Seen nonces are tracked in a [`HashSet`][std::collections::HashSet]. In
+/// release builds (i.e. without `debug_assertions`) the wrapper does not
+/// track anything and is a zero-cost pass-through to the inner AEAD.
+///
+/// # ⚠️ Testing aid only
+///
+/// This wrapper is intended to catch accidental nonce reuse in tests. It is
+/// NOT a production safeguard: it does nothing in release builds, does not
+/// persist seen nonces, and can not detect reuse across different instances
+/// sharing the same key. Its memory usage grows with every encryption.
+pub struct NonceReuseGuard<A: AeadCore> {
+    inner: A,
+    #[cfg(debug_assertions)]
+    seen: Mutex<HashSet<Nonce<A>>>,
+}
+
+impl<A: AeadCore> NonceReuseGuard<A> {
+    /// Wrap the given AEAD.
+    pub fn new(inner: A) -> Self {
+        Self {
+            inner,
+            #[cfg(debug_assertions)]
+            seen: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Get reference to the wrapped AEAD.
+    pub fn inner(&self) -> &A {
+        &self.inner
+    }
+
+    /// Get the wrapped AEAD.
+    pub fn into_inner(self) -> A {
+        self.inner
+    }
+
+    /// Record use of `nonce` for encryption.
+    ///
+    /// # Panics
+    /// In debug builds, if `nonce` was already used.
+    #[inline]
+    fn track(&self, nonce: &Nonce<A>) {
+        #[cfg(debug_assertions)]
+        {
+            let mut seen = self.seen.lock().unwrap_or_else(PoisonError::into_inner);
+            assert!(seen.insert(nonce.clone()), "AEAD nonce reuse detected");
+        }
+        #[cfg(not(debug_assertions))]
+        let _ = nonce;
+    }
+}
+
+impl<A: AeadCore> AeadCore for NonceReuseGuard<A> {
+    type NonceSize = A::NonceSize;
+    type TagSize = A::TagSize;
+    type CiphertextOverhead = A::CiphertextOverhead;
+    const MAX_PLAINTEXT_LEN: u64 = A::MAX_PLAINTEXT_LEN;
+    const ALLOW_EMPTY_NONCE: bool = A::ALLOW_EMPTY_NONCE;
+    const TAG_POSITION: TagPosition = A::TAG_POSITION;
+}
+
+impl<A: AeadInPlace> AeadInPlace for NonceReuseGuard<A> {
+    fn encrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        self.track(nonce);
+        self.inner.encrypt_in_place(nonce, associated_data, buffer)
+    }
+
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        self.track(nonce);
+        self.inner
+            .encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn encrypt_in_place_detached_into(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag_out: &mut Tag<Self>,
+    ) -> Result<()> {
+        self.track(nonce);
+        self.inner
+            .encrypt_in_place_detached_into(nonce, associated_data, buffer, tag_out)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        self.inner.decrypt_in_place(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        self.inner
+            .decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+
+    fn authenticate(&self, nonce: &Nonce<Self>, associated_data: &[u8]) -> Result<Tag<Self>> {
+        self.track(nonce);
+        self.inner.authenticate(nonce, associated_data)
+    }
+
+    fn verify_authenticated(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        self.inner.verify_authenticated(nonce, associated_data, tag)
+    }
+}
+
+impl<A: AeadCore + fmt::Debug> fmt::Debug for NonceReuseGuard<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NonceReuseGuard")
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,67 @@
+//! Single-use AEAD wrapper.
+
+use crate::{Aead, Nonce, Payload, Result};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// AEAD wrapper which can be used for exactly one encryption or decryption.
+///
+/// Both operations consume the wrapper, so the compiler rejects any attempt
+/// to use the same instance (and thus the same key) twice, e.g. with a
+/// repeated nonce:
+///
+/// ```compile_fail
+/// use aead::{Aead, Nonce, OneShotAead};
+///
+/// fn encrypt_twice<A: Aead>(aead: OneShotAead<A>, nonce: &Nonce<A>) {
+///     let _ = aead.into_encrypt(nonce, b"first".as_slice());
+///     let _ = aead.into_encrypt(nonce, b"second".as_slice()); // use of moved value
+/// }
+/// ```
+///
+/// The wrapper intentionally does not implement [`Clone`] and provides no
+/// access to the inner AEAD.
+///
+/// # ⚠️ Warning
+///
+/// This only guards against API misuse within a program. It does not
+/// prevent creating several wrappers with the same key, and provides no
+/// protection against side-channel attacks.
+pub struct OneShotAead<A: Aead> {
+    inner: A,
+}
+
+impl<A: Aead> OneShotAead<A> {
+    /// Wrap the given AEAD.
+    pub fn new(inner: A) -> Self {
+        Self { inner }
+    }
+
+    /// Encrypt the given payload, consuming the wrapper.
+    ///
+    /// See [`Aead::encrypt`] for details.
+    pub fn into_encrypt<'msg, 'aad>(
+        self,
+        nonce: &Nonce<A>,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>> {
+        self.inner.encrypt(nonce, plaintext)
+    }
+
+    /// Decrypt the given payload, consuming the wrapper.
+    ///
+    /// See [`Aead::decrypt`] for details.
+    pub fn into_decrypt<'msg, 'aad>(
+        self,
+        nonce: &Nonce<A>,
+        ciphertext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>> {
+        self.inner.decrypt(nonce, ciphertext)
+    }
+}
+
+impl<A: Aead> fmt::Debug for OneShotAead<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OneShotAead").finish_non_exhaustive()
+    }
+}
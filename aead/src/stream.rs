@@ -131,6 +131,103 @@ where
     }
 }
 
+/// STREAM primitive adapter which authenticates the segment counter as
+/// part of the associated data.
+///
+/// On every segment the big-endian encoding of the STREAM counter (as `u64`)
+/// is prepended to the caller-provided associated data before it is passed
+/// to the wrapped primitive `S`. This binds each segment to its position even
+/// if the nonce derivation of `S` fails to do so, e.g. because of a bug or
+/// because framing is handled outside of [`Encryptor`]/[`Decryptor`].
+///
+/// # Wire format
+///
+/// The counter is not transmitted, so ciphertext lengths are not affected.
+/// However, segments produced with this adapter are authenticated over
+/// different associated data than segments produced by `S` alone, so both
+/// parties MUST use the adapter: ciphertexts are not interchangeable between
+/// `CounterAad<S>` and `S`.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct CounterAad<S> {
+    stream: S,
+}
+
+#[cfg(feature = "alloc")]
+impl<S> CounterAad<S> {
+    /// Wrap the given STREAM primitive.
+    pub fn new(stream: S) -> Self {
+        Self { stream }
+    }
+
+    /// Get the wrapped STREAM primitive.
+    pub fn into_inner(self) -> S {
+        self.stream
+    }
+
+    /// Prepend big-endian `position` to `associated_data`.
+    fn aad<C: Into<u64>>(position: C, associated_data: &[u8]) -> Vec<u8> {
+        let counter = position.into().to_be_bytes();
+        let mut aad = Vec::with_capacity(counter.len() + associated_data.len());
+        aad.extend_from_slice(&counter);
+        aad.extend_from_slice(associated_data);
+        aad
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A, S> NewStream<A> for CounterAad<S>
+where
+    A: AeadInPlace,
+    S: NewStream<A>,
+    S::Counter: Into<u64>,
+    A::NonceSize: Sub<S::NonceOverhead>,
+    NonceSize<A, S>: ArraySize,
+{
+    fn from_aead(aead: A, nonce: &Nonce<A, Self>) -> Self {
+        Self::new(S::from_aead(aead, nonce))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<A, S> StreamPrimitive<A> for CounterAad<S>
+where
+    A: AeadInPlace,
+    S: StreamPrimitive<A>,
+    S::Counter: Into<u64>,
+    A::NonceSize: Sub<S::NonceOverhead>,
+    NonceSize<A, S>: ArraySize,
+{
+    type NonceOverhead = S::NonceOverhead;
+    type Counter = S::Counter;
+    const COUNTER_INCR: Self::Counter = S::COUNTER_INCR;
+    const COUNTER_MAX: Self::Counter = S::COUNTER_MAX;
+
+    fn encrypt_in_place(
+        &self,
+        position: Self::Counter,
+        last_block: bool,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        let aad = Self::aad(position, associated_data);
+        self.stream
+            .encrypt_in_place(position, last_block, &aad, buffer)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        position: Self::Counter,
+        last_block: bool,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        let aad = Self::aad(position, associated_data);
+        self.stream
+            .decrypt_in_place(position, last_block, &aad, buffer)
+    }
+}
+
 /// Implement a stateful STREAM object (i.e. encryptor or decryptor)
 macro_rules! impl_stream_object {
     (
@@ -208,6 +305,21 @@ macro_rules! impl_stream_object {
                 }
             }
 
+            /// Number of segments which can still be processed with the
+            /// `*_next` methods before the STREAM counter is exhausted.
+            ///
+            /// The last segment is not included in this count. Long-lived
+            /// STREAMs should be finished and a new key or nonce used well
+            /// before this reaches zero.
+            pub fn remaining_segments(&self) -> u64
+            where
+                S::Counter: Into<u64>,
+            {
+                let max: u64 = S::COUNTER_MAX.into();
+                let position: u64 = self.position.into();
+                (max - position) / S::COUNTER_INCR.into()
+            }
+
             #[doc = "Use the underlying AEAD to"]
             #[doc = $op_desc]
             #[doc = "the next AEAD message in this STREAM, returning the"]
@@ -0,0 +1,46 @@
+//! Derivation of subkeys from a master key.
+
+use crypto_common::{BlockSizeUser, Key, KeySizeUser};
+use digest::{Digest, HmacPrf};
+
+/// Derivation of purpose-specific keys for `Self` from a master key.
+///
+/// This trait is implemented for all [`KeySizeUser`] types, e.g. AEAD
+/// algorithms implementing [`KeyInit`][crate::KeyInit].
+pub trait DeriveSubkey: KeySizeUser {
+    /// Derive a key for `Self` from the `master` key of `K` and a `label`
+    /// describing its purpose, e.g. `b"header"` and `b"body"`.
+    ///
+    /// The derivation is HKDF-Expand as specified in [RFC 5869 § 2.3] with
+    /// HMAC instantiated with `D`, the master key used as the pseudorandom
+    /// key `PRK`, and the label used as `info`:
+    ///
+    /// ```text
+    /// T(0) = empty
+    /// T(i) = HMAC-D(master, T(i - 1) || label || u8(i))
+    /// subkey = first KeySize bytes of T(1) || T(2) || ...
+    /// ```
+    ///
+    /// The master key MUST be uniformly random: unlike full HKDF, no
+    /// extraction step is performed. Different labels yield independent
+    /// subkeys, the same label always yields the same subkey. The expansion
+    /// is performed by [`HmacPrf::hkdf_expand`], which zeroizes the
+    /// intermediate blocks.
+    ///
+    /// # Panics
+    ///
+    /// If the key size of `Self` exceeds 255 times the output size of `D`.
+    ///
+    /// [RFC 5869 § 2.3]: https://www.rfc-editor.org/rfc/rfc5869#section-2.3
+    fn derive_subkey_from<K, D>(master: &Key<K>, label: &[u8]) -> Key<Self>
+    where
+        K: KeySizeUser,
+        D: Digest + BlockSizeUser,
+    {
+        let mut subkey = Key::<Self>::default();
+        HmacPrf::<D>::hkdf_expand(master, label, &mut subkey).expect("subkey is too long");
+        subkey
+    }
+}
+
+impl<T: KeySizeUser> DeriveSubkey for T {}
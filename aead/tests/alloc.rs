@@ -0,0 +1,64 @@
+//! Tests for the `alloc`-based AEAD traits.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aead::{Aead, AeadCore, AeadMut, Error, Nonce};
+use common::MockAead;
+
+const PLAINTEXT: &[u8] = b"example plaintext message";
+
+#[test]
+fn encrypt_exact_length_and_capacity() {
+    let nonce = Nonce::<MockAead>::from([0x42; 12]);
+    let mut cipher = MockAead::default();
+    let ct = cipher.encrypt(&nonce, PLAINTEXT).unwrap();
+    let needed = PLAINTEXT.len() + MockAead::CIPHERTEXT_OVERHEAD + MockAead::TAG_SIZE;
+    assert_eq!(ct.len(), needed);
+    assert!(ct.capacity() >= needed);
+
+    let ct_mut = AeadMut::encrypt(&mut cipher, &nonce, PLAINTEXT).unwrap();
+    assert_eq!(ct_mut, ct);
+    assert!(ct_mut.capacity() >= needed);
+}
+
+#[test]
+fn decrypt_exact_length() {
+    let nonce = Nonce::<MockAead>::from([0x42; 12]);
+    let mut cipher = MockAead::default();
+    let ct = cipher.encrypt(&nonce, PLAINTEXT).unwrap();
+
+    let pt = cipher.decrypt(&nonce, ct.as_slice()).unwrap();
+    assert_eq!(pt, PLAINTEXT);
+    assert_eq!(pt.len(), PLAINTEXT.len());
+
+    let pt = AeadMut::decrypt(&mut cipher, &nonce, ct.as_slice()).unwrap();
+    assert_eq!(pt, PLAINTEXT);
+}
+
+#[test]
+fn decrypt_tampered() {
+    let nonce = Nonce::<MockAead>::from([0x42; 12]);
+    let cipher = MockAead::default();
+    let mut ct = cipher.encrypt(&nonce, PLAINTEXT).unwrap();
+    ct[0] ^= 1;
+    assert_eq!(cipher.decrypt(&nonce, ct.as_slice()), Err(Error));
+}
+
+fn roundtrip<A: Aead>(aead: A, nonce: &Nonce<A>) -> Vec<u8> {
+    let ct = aead.encrypt(nonce, PLAINTEXT).unwrap();
+    assert_eq!(aead.decrypt(nonce, ct.as_slice()).unwrap(), PLAINTEXT);
+    ct
+}
+
+#[test]
+fn aead_through_reference() {
+    let nonce = Nonce::<MockAead>::from([0x42; 12]);
+    let cipher = MockAead::default();
+    let expected = cipher.encrypt(&nonce, PLAINTEXT).unwrap();
+
+    assert_eq!(roundtrip(&cipher, &[0x42; 12].into()), expected);
+    assert_eq!(roundtrip(&&cipher, &[0x42; 12].into()), expected);
+    assert_eq!(<&MockAead as AeadCore>::TAG_SIZE, MockAead::TAG_SIZE);
+}
@@ -0,0 +1,68 @@
+//! Tests for encryption into stack-allocated `ArrayVec` buffers.
+
+#![cfg(feature = "arrayvec")]
+
+mod common;
+
+use aead::{arrayvec::ArrayVec, AeadInPlace, Error, Nonce, Payload};
+use common::MockAead;
+
+const NONCE: [u8; 12] = [0x42; 12];
+const AAD: &[u8] = b"header";
+const PLAINTEXT: &[u8] = b"short message";
+
+fn payload(msg: &[u8]) -> Payload<'_, '_> {
+    Payload { msg, aad: AAD }
+}
+
+#[test]
+fn arrayvec_round_trip() {
+    let nonce = Nonce::<MockAead>::from(NONCE);
+    let ct: ArrayVec<u8, 64> = MockAead::default()
+        .encrypt_to_arrayvec(&nonce, payload(PLAINTEXT))
+        .unwrap();
+    assert_eq!(ct.len(), PLAINTEXT.len() + 16);
+
+    let pt: ArrayVec<u8, 64> = MockAead::default()
+        .decrypt_to_arrayvec(&nonce, payload(&ct))
+        .unwrap();
+    assert_eq!(pt.as_slice(), PLAINTEXT);
+}
+
+#[test]
+fn arrayvec_exact_capacity() {
+    let nonce = Nonce::<MockAead>::from(NONCE);
+    let ct: ArrayVec<u8, 29> = MockAead::default()
+        .encrypt_to_arrayvec(&nonce, payload(PLAINTEXT))
+        .unwrap();
+    let pt: ArrayVec<u8, 29> = MockAead::default()
+        .decrypt_to_arrayvec(&nonce, payload(&ct))
+        .unwrap();
+    assert_eq!(pt.as_slice(), PLAINTEXT);
+}
+
+#[test]
+fn arrayvec_too_small() {
+    let nonce = Nonce::<MockAead>::from(NONCE);
+
+    // Plaintext fits, but the tag doesn't
+    let res = MockAead::default().encrypt_to_arrayvec::<28>(&nonce, payload(PLAINTEXT));
+    assert_eq!(res, Err(Error));
+
+    let ct: ArrayVec<u8, 64> = MockAead::default()
+        .encrypt_to_arrayvec(&nonce, payload(PLAINTEXT))
+        .unwrap();
+    let res = MockAead::default().decrypt_to_arrayvec::<28>(&nonce, payload(&ct));
+    assert_eq!(res, Err(Error));
+}
+
+#[test]
+fn arrayvec_decrypt_unauthentic() {
+    let nonce = Nonce::<MockAead>::from(NONCE);
+    let mut ct: ArrayVec<u8, 64> = MockAead::default()
+        .encrypt_to_arrayvec(&nonce, payload(PLAINTEXT))
+        .unwrap();
+    ct[0] ^= 1;
+    let res = MockAead::default().decrypt_to_arrayvec::<64>(&nonce, payload(&ct));
+    assert_eq!(res, Err(Error));
+}
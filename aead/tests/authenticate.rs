@@ -0,0 +1,55 @@
+//! Tests for AAD-only authentication.
+
+mod common;
+
+use aead::{AeadInPlace, Error, Nonce};
+use common::MockAead;
+
+const NONCE: [u8; 12] = [0x42; 12];
+const AAD: &[u8] = b"authenticated header";
+
+#[test]
+fn authenticate_matches_empty_detached_encryption() {
+    let nonce = Nonce::<MockAead>::from(NONCE);
+    let tag = MockAead::default().authenticate(&nonce, AAD).unwrap();
+    let expected = MockAead::default()
+        .encrypt_in_place_detached(&nonce, AAD, &mut [])
+        .unwrap();
+    assert_eq!(tag, expected);
+    assert_eq!(
+        MockAead::default().verify_authenticated(&nonce, AAD, &tag),
+        Ok(())
+    );
+}
+
+#[test]
+fn tampered_aad_changes_tag() {
+    let nonce = Nonce::<MockAead>::from(NONCE);
+    let tag = MockAead::default().authenticate(&nonce, AAD).unwrap();
+
+    let mut aad = AAD.to_vec();
+    for i in [0, 7, AAD.len() - 1] {
+        aad[i] ^= 1;
+        assert_ne!(MockAead::default().authenticate(&nonce, &aad).unwrap(), tag);
+        assert_eq!(
+            MockAead::default().verify_authenticated(&nonce, &aad, &tag),
+            Err(Error)
+        );
+        aad[i] ^= 1;
+    }
+
+    // Truncated and extended AAD
+    assert_ne!(
+        MockAead::default().authenticate(&nonce, &AAD[1..]).unwrap(),
+        tag
+    );
+    aad.push(0);
+    assert_ne!(MockAead::default().authenticate(&nonce, &aad).unwrap(), tag);
+
+    let mut bad_tag = tag;
+    bad_tag[0] ^= 1;
+    assert_eq!(
+        MockAead::default().verify_authenticated(&nonce, AAD, &bad_tag),
+        Err(Error)
+    );
+}
@@ -0,0 +1,82 @@
+//! Tests for key commitments of committing AEADs.
+
+#![cfg(feature = "digest")]
+
+mod common;
+
+use aead::CommittingAead;
+use aead::KeyInit;
+use common::MockAead;
+use digest::{
+    consts::U32, CollisionResistant, Digest, FixedOutput, HashMarker, Output, OutputSizeUser,
+    Update,
+};
+
+impl CommittingAead for MockAead {}
+
+/// Wrapper which marks SHA-256 as collision resistant.
+#[derive(Default)]
+struct Sha256(sha2::Sha256);
+
+impl OutputSizeUser for Sha256 {
+    type OutputSize = U32;
+}
+
+impl Update for Sha256 {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+}
+
+impl FixedOutput for Sha256 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        Digest::finalize_into(self.0, out);
+    }
+}
+
+impl HashMarker for Sha256 {}
+impl CollisionResistant for Sha256 {}
+
+/// Nonce reserved for commitments, never used to encrypt messages.
+const COMMIT_NONCE: [u8; 12] = [0xFF; 12];
+const AAD: &[u8] = b"header";
+
+#[test]
+fn different_keys_yield_different_commitments() {
+    let nonce = COMMIT_NONCE.into();
+    let a = MockAead::new(&[1; 16].into())
+        .commit_tag::<Sha256>(&nonce, AAD)
+        .unwrap();
+    let b = MockAead::new(&[2; 16].into())
+        .commit_tag::<Sha256>(&nonce, AAD)
+        .unwrap();
+    assert_ne!(a, b);
+}
+
+#[test]
+fn commitment_is_deterministic() {
+    let nonce = COMMIT_NONCE.into();
+    let cipher = MockAead::new(&[1; 16].into());
+    let a = cipher.commit_tag::<Sha256>(&nonce, AAD).unwrap();
+    let b = cipher.commit_tag::<Sha256>(&nonce, AAD).unwrap();
+    assert_eq!(a, b);
+}
+
+#[test]
+fn commitment_binds_nonce_and_aad() {
+    // Using one commitment nonce with several `aad` values under the same
+    // key violates the nonce rule of `commit_tag`; it is only done here
+    // because the mock is not affected by nonce reuse.
+    let cipher = MockAead::new(&[1; 16].into());
+    let a = cipher
+        .commit_tag::<Sha256>(&COMMIT_NONCE.into(), AAD)
+        .unwrap();
+    let b = cipher
+        .commit_tag::<Sha256>(&[0xFE; 12].into(), AAD)
+        .unwrap();
+    let c = cipher
+        .commit_tag::<Sha256>(&COMMIT_NONCE.into(), b"other")
+        .unwrap();
+    assert_ne!(a, b);
+    assert_ne!(a, c);
+}
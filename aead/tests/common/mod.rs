@@ -0,0 +1,124 @@
+//! Mock AEAD shared by the integration tests.
+
+#![allow(dead_code)]
+
+use aead::{
+    consts::{U0, U12, U16},
+    AeadCore, AeadInPlace, Error, Key, KeyInit, KeySizeUser, Nonce, Result, Tag,
+};
+use core::cell::Cell;
+
+/// Position-dependent checksum of the concatenation of `parts`.
+pub fn checksum(parts: &[&[u8]]) -> [u8; 16] {
+    let mut sum = [0u8; 16];
+    for (i, b) in parts.iter().flat_map(|part| part.iter()).enumerate() {
+        sum[i % 16] = sum[i % 16].wrapping_add(*b).rotate_left(3);
+    }
+    sum
+}
+
+/// Mock AEAD which XORs the data with the key and the first byte of the
+/// nonce, and uses a position-dependent checksum of the key, nonce,
+/// associated data and ciphertext as the tag. It counts calls of detached
+/// encryption and decryption.
+#[derive(Debug, Default)]
+pub struct MockAead {
+    key: [u8; 16],
+    /// Number of calls of [`AeadInPlace::encrypt_in_place_detached`].
+    pub encrypt_calls: Cell<usize>,
+    /// Number of calls of [`AeadInPlace::decrypt_in_place_detached`].
+    pub decrypt_calls: Cell<usize>,
+}
+
+impl MockAead {
+    fn tag(&self, nonce: &Nonce<Self>, associated_data: &[u8], ciphertext: &[u8]) -> Tag<Self> {
+        checksum(&[&self.key, nonce, associated_data, ciphertext]).into()
+    }
+
+    fn xor(&self, nonce: &Nonce<Self>, buffer: &mut [u8]) {
+        for (i, b) in buffer.iter_mut().enumerate() {
+            *b ^= self.key[i % 16] ^ nonce[0];
+        }
+    }
+}
+
+impl KeySizeUser for MockAead {
+    type KeySize = U16;
+}
+
+impl KeyInit for MockAead {
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            key: key.0,
+            ..Default::default()
+        }
+    }
+}
+
+impl AeadCore for MockAead {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for MockAead {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        self.encrypt_calls.set(self.encrypt_calls.get() + 1);
+        self.xor(nonce, buffer);
+        Ok(self.tag(nonce, associated_data, buffer))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        self.decrypt_calls.set(self.decrypt_calls.get() + 1);
+        if self.tag(nonce, associated_data, buffer) != *tag {
+            return Err(Error);
+        }
+        self.xor(nonce, buffer);
+        Ok(())
+    }
+}
+
+/// [`MockAead`] which rejects plaintexts longer than `MAX` bytes.
+#[derive(Debug, Default)]
+pub struct LimitedAead<const MAX: u64>(pub MockAead);
+
+impl<const MAX: u64> AeadCore for LimitedAead<MAX> {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+    const MAX_PLAINTEXT_LEN: u64 = MAX;
+}
+
+impl<const MAX: u64> AeadInPlace for LimitedAead<MAX> {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        self.0
+            .encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        self.0
+            .decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
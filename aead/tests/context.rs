@@ -0,0 +1,255 @@
+//! Tests for the `AeadContextInPlace` trait.
+
+use aead::{
+    consts::{U0, U12, U16},
+    AeadContextInPlace, AeadCore, AeadInPlace, Error, Nonce, Result, Tag,
+};
+
+/// Mock AEAD which XORs the data with a byte derived from a subkey and the
+/// nonce, and uses a checksum of the ciphertext as the tag. The subkey is
+/// cached in the context.
+struct MockAead {
+    key: [u8; 16],
+}
+
+/// Cached subkey of [`MockAead`].
+struct MockContext {
+    subkey: u8,
+}
+
+impl MockAead {
+    fn subkey(&self) -> u8 {
+        self.key.iter().fold(0x5a, |acc, b| acc.rotate_left(1) ^ b)
+    }
+
+    fn ks(subkey: u8, nonce: &Nonce<Self>) -> u8 {
+        nonce.iter().fold(subkey, |acc, b| acc.wrapping_add(*b))
+    }
+
+    fn tag(ks: u8, associated_data: &[u8], ciphertext: &[u8]) -> Tag<Self> {
+        let sum = associated_data
+            .iter()
+            .chain(ciphertext)
+            .fold(ks, |acc, b| acc.wrapping_add(*b));
+        Tag::<Self>::from([sum; 16])
+    }
+
+    fn encrypt(subkey: u8, nonce: &Nonce<Self>, aad: &[u8], buffer: &mut [u8]) -> Tag<Self> {
+        let ks = Self::ks(subkey, nonce);
+        buffer.iter_mut().for_each(|b| *b ^= ks);
+        Self::tag(ks, aad, buffer)
+    }
+
+    fn decrypt(
+        subkey: u8,
+        nonce: &Nonce<Self>,
+        aad: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        let ks = Self::ks(subkey, nonce);
+        if Self::tag(ks, aad, buffer) != *tag {
+            return Err(Error);
+        }
+        buffer.iter_mut().for_each(|b| *b ^= ks);
+        Ok(())
+    }
+}
+
+impl AeadCore for MockAead {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for MockAead {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        Ok(Self::encrypt(self.subkey(), nonce, associated_data, buffer))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        Self::decrypt(self.subkey(), nonce, associated_data, buffer, tag)
+    }
+}
+
+impl AeadContextInPlace for MockAead {
+    type Context = MockContext;
+
+    fn context(&self) -> MockContext {
+        MockContext {
+            subkey: self.subkey(),
+        }
+    }
+
+    fn encrypt_in_place_detached_with_context(
+        &self,
+        context: &MockContext,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        Ok(Self::encrypt(
+            context.subkey,
+            nonce,
+            associated_data,
+            buffer,
+        ))
+    }
+
+    fn decrypt_in_place_detached_with_context(
+        &self,
+        context: &MockContext,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        Self::decrypt(context.subkey, nonce, associated_data, buffer, tag)
+    }
+}
+
+/// Mock AEAD without a cached context, relying on the default methods.
+struct PlainAead(MockAead);
+
+impl AeadCore for PlainAead {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for PlainAead {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        self.0
+            .encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        self.0
+            .decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+impl AeadContextInPlace for PlainAead {
+    type Context = ();
+
+    fn context(&self) {}
+}
+
+const KEY: [u8; 16] = *b"0123456789abcdef";
+const AAD: &[u8] = b"header";
+const MSG: &[u8] = b"many small messages";
+
+fn nonces() -> impl Iterator<Item = Nonce<MockAead>> {
+    (0u8..8).map(|i| Nonce::<MockAead>::from([i; 12]))
+}
+
+#[test]
+fn context_matches_normal_path() {
+    let aead = MockAead { key: KEY };
+    let ctx = aead.context();
+
+    for nonce in nonces() {
+        let mut normal = MSG.to_vec();
+        let normal_tag = aead
+            .encrypt_in_place_detached(&nonce, AAD, &mut normal)
+            .unwrap();
+
+        let mut with_ctx = MSG.to_vec();
+        let ctx_tag = aead
+            .encrypt_in_place_detached_with_context(&ctx, &nonce, AAD, &mut with_ctx)
+            .unwrap();
+
+        assert_eq!(normal, with_ctx);
+        assert_eq!(normal_tag, ctx_tag);
+    }
+}
+
+#[test]
+fn context_round_trip() {
+    let aead = MockAead { key: KEY };
+    let ctx = aead.context();
+
+    for nonce in nonces() {
+        let mut buf = MSG.to_vec();
+        let tag = aead
+            .encrypt_in_place_detached_with_context(&ctx, &nonce, AAD, &mut buf)
+            .unwrap();
+        aead.decrypt_in_place_detached(&nonce, AAD, &mut buf, &tag)
+            .unwrap();
+        assert_eq!(buf, MSG);
+
+        let tag = aead
+            .encrypt_in_place_detached(&nonce, AAD, &mut buf)
+            .unwrap();
+        aead.decrypt_in_place_detached_with_context(&ctx, &nonce, AAD, &mut buf, &tag)
+            .unwrap();
+        assert_eq!(buf, MSG);
+    }
+}
+
+#[test]
+fn context_rejects_bad_tag() {
+    let aead = MockAead { key: KEY };
+    let ctx = aead.context();
+    let nonce = Nonce::<MockAead>::from([7; 12]);
+
+    let mut buf = MSG.to_vec();
+    let mut tag = aead
+        .encrypt_in_place_detached_with_context(&ctx, &nonce, AAD, &mut buf)
+        .unwrap();
+    tag[0] ^= 1;
+    let ciphertext = buf.clone();
+    assert_eq!(
+        aead.decrypt_in_place_detached_with_context(&ctx, &nonce, AAD, &mut buf, &tag),
+        Err(Error)
+    );
+    assert_eq!(buf, ciphertext);
+}
+
+#[test]
+fn default_context_matches_normal_path() {
+    let aead = PlainAead(MockAead { key: KEY });
+    #[allow(clippy::let_unit_value)]
+    let ctx = aead.context();
+
+    for nonce in nonces() {
+        let mut normal = MSG.to_vec();
+        let normal_tag = aead
+            .encrypt_in_place_detached(&nonce, AAD, &mut normal)
+            .unwrap();
+
+        let mut with_ctx = MSG.to_vec();
+        let ctx_tag = aead
+            .encrypt_in_place_detached_with_context(&ctx, &nonce, AAD, &mut with_ctx)
+            .unwrap();
+
+        assert_eq!(normal, with_ctx);
+        assert_eq!(normal_tag, ctx_tag);
+
+        aead.decrypt_in_place_detached_with_context(&ctx, &nonce, AAD, &mut with_ctx, &ctx_tag)
+            .unwrap();
+        assert_eq!(with_ctx, MSG);
+    }
+}
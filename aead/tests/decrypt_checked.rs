@@ -0,0 +1,76 @@
+//! Tests for decryption with distinguished error kinds.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aead::{Aead, AeadInPlace, AeadMutInPlace, DecryptError, Error};
+use common::MockAead;
+
+const NONCE: [u8; 12] = [0x42; 12];
+const MSG: &[u8] = b"hello world";
+
+#[test]
+fn decrypt_checked_success() {
+    let aead = MockAead::default();
+    let nonce = NONCE.into();
+    let ct = aead.encrypt(&nonce, MSG).unwrap();
+    assert_eq!(aead.decrypt_checked(&nonce, ct.as_slice()).unwrap(), MSG);
+
+    let mut buf = ct.clone();
+    AeadInPlace::decrypt_in_place_checked(&aead, &nonce, b"", &mut buf).unwrap();
+    assert_eq!(buf, MSG);
+
+    let mut buf = ct;
+    let mut aead = aead;
+    AeadMutInPlace::decrypt_in_place_checked(&mut aead, &nonce, b"", &mut buf).unwrap();
+    assert_eq!(buf, MSG);
+}
+
+#[test]
+fn decrypt_checked_too_short() {
+    let aead = MockAead::default();
+    let nonce = NONCE.into();
+    for len in [0, 1, 15] {
+        let ct = vec![0u8; len];
+        assert_eq!(
+            aead.decrypt_checked(&nonce, ct.as_slice()),
+            Err(DecryptError::TooShort)
+        );
+
+        let mut buf = ct.clone();
+        assert_eq!(
+            AeadInPlace::decrypt_in_place_checked(&aead, &nonce, b"", &mut buf),
+            Err(DecryptError::TooShort)
+        );
+        assert_eq!(buf, ct);
+    }
+    // No cryptographic work is performed for too short ciphertexts
+    assert_eq!(aead.decrypt_calls.get(), 0);
+}
+
+#[test]
+fn decrypt_checked_authentication() {
+    let aead = MockAead::default();
+    let nonce = NONCE.into();
+
+    // Tag-only ciphertext is long enough, but fails authentication
+    let ct = [0u8; 16];
+    assert_eq!(
+        aead.decrypt_checked(&nonce, &ct[..]),
+        Err(DecryptError::Authentication)
+    );
+    assert_eq!(aead.decrypt_calls.get(), 1);
+
+    let mut ct = aead.encrypt(&nonce, MSG).unwrap();
+    ct[0] ^= 1;
+    assert_eq!(
+        aead.decrypt_checked(&nonce, ct.as_slice()),
+        Err(DecryptError::Authentication)
+    );
+    assert_eq!(
+        AeadInPlace::decrypt_in_place_checked(&aead, &nonce, b"", &mut ct),
+        Err(DecryptError::Authentication)
+    );
+    assert_eq!(Error::from(DecryptError::Authentication), Error);
+}
@@ -0,0 +1,50 @@
+//! Tests for writing detached tags into caller-provided locations.
+
+mod common;
+
+use aead::{AeadInPlace, AeadMutInPlace, Error, Nonce, Tag};
+use common::{LimitedAead, MockAead};
+
+const NONCE: [u8; 12] = [0x42; 12];
+const AAD: &[u8] = b"packet header";
+const PLAINTEXT: &[u8] = b"example plaintext message";
+
+#[test]
+fn tag_into_matches_returned_tag() {
+    let nonce = Nonce::<MockAead>::from(NONCE);
+
+    let mut expected_ct = PLAINTEXT.to_vec();
+    let expected_tag = MockAead::default()
+        .encrypt_in_place_detached(&nonce, AAD, &mut expected_ct)
+        .unwrap();
+
+    // Tags are stored in slots of a larger array
+    let mut tags = [Tag::<MockAead>::default(); 3];
+    let mut ct = PLAINTEXT.to_vec();
+    MockAead::default()
+        .encrypt_in_place_detached_into(&nonce, AAD, &mut ct, &mut tags[1])
+        .unwrap();
+    assert_eq!(ct, expected_ct);
+    assert_eq!(tags[1], expected_tag);
+    assert_eq!(tags[0], Tag::<MockAead>::default());
+    assert_eq!(tags[2], Tag::<MockAead>::default());
+
+    let mut ct = PLAINTEXT.to_vec();
+    let mut tag = Tag::<MockAead>::default();
+    let mut aead = MockAead::default();
+    AeadMutInPlace::encrypt_in_place_detached_into(&mut aead, &nonce, AAD, &mut ct, &mut tag)
+        .unwrap();
+    assert_eq!(ct, expected_ct);
+    assert_eq!(tag, expected_tag);
+}
+
+#[test]
+fn tag_into_unmodified_on_error() {
+    let nonce = Nonce::<MockAead>::from(NONCE);
+    let mut buf = [0u8; 65];
+    let mut tag = Tag::<MockAead>::from([0xAA; 16]);
+    let res = LimitedAead::<64>::default()
+        .encrypt_in_place_detached_into(&nonce, AAD, &mut buf, &mut tag);
+    assert_eq!(res, Err(Error));
+    assert_eq!(tag, Tag::<MockAead>::from([0xAA; 16]));
+}
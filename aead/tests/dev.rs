@@ -0,0 +1,85 @@
+//! Tests for the `dev` test vector harness.
+
+#![cfg(all(feature = "dev", feature = "alloc"))]
+
+mod common;
+
+use aead::{
+    dev::{run_test_vectors, TestVector},
+    AeadInPlace, KeyInit, Nonce, Tag,
+};
+use common::MockAead;
+
+/// Owned test vector computed using the mock itself.
+struct MockVector {
+    key: &'static [u8],
+    nonce: Nonce<MockAead>,
+    aad: &'static [u8],
+    pt: &'static [u8],
+    ct: Vec<u8>,
+    tag: Tag<MockAead>,
+}
+
+impl MockVector {
+    fn new(key: &'static [u8], aad: &'static [u8], pt: &'static [u8]) -> Self {
+        let cipher = MockAead::new_from_slice(key).unwrap();
+        let nonce = Nonce::<MockAead>::from([0x42; 12]);
+        let mut ct = pt.to_vec();
+        let tag = cipher
+            .encrypt_in_place_detached(&nonce, aad, &mut ct)
+            .unwrap();
+        Self {
+            key,
+            nonce,
+            aad,
+            pt,
+            ct,
+            tag,
+        }
+    }
+
+    fn as_test_vector(&self) -> TestVector<'_> {
+        TestVector {
+            key: self.key,
+            nonce: &self.nonce,
+            aad: self.aad,
+            pt: self.pt,
+            ct: &self.ct,
+            tag: &self.tag,
+        }
+    }
+}
+
+#[test]
+fn mock_vectors_pass() {
+    let vectors = [
+        MockVector::new(b"key0-0123456789a", b"", b""),
+        MockVector::new(b"key1-0123456789a", b"header", b""),
+        MockVector::new(b"key2-0123456789a", b"", b"plaintext"),
+        MockVector::new(
+            b"key3-0123456789a",
+            b"header",
+            b"longer example plaintext message",
+        ),
+    ];
+    let vectors: Vec<_> = vectors.iter().map(MockVector::as_test_vector).collect();
+    run_test_vectors::<MockAead>(&vectors);
+}
+
+#[test]
+#[should_panic(expected = "encrypted data is different from target ciphertext")]
+fn wrong_ciphertext_detected() {
+    let vector = MockVector::new(b"key0-0123456789a", b"header", b"plaintext");
+    let mut tv = vector.as_test_vector();
+    tv.ct = b"ciphertxt";
+    run_test_vectors::<MockAead>(&[tv]);
+}
+
+#[test]
+#[should_panic(expected = "wrong key size")]
+fn wrong_key_size_detected() {
+    let vector = MockVector::new(b"key0-0123456789a", b"header", b"plaintext");
+    let mut tv = vector.as_test_vector();
+    tv.key = b"key";
+    run_test_vectors::<MockAead>(&[tv]);
+}
@@ -0,0 +1,117 @@
+//! Tests for the rejection of zero-length nonces.
+
+mod common;
+
+use aead::{
+    consts::{U0, U16},
+    AeadCore, AeadInPlace, Error, Nonce, Result, Tag,
+};
+use common::checksum;
+
+/// Mock AEAD with an empty nonce which XORs the data with a constant and
+/// uses a checksum of the associated data and ciphertext as the tag.
+macro_rules! mock_aead {
+    ($name:ident, $allow:expr) => {
+        struct $name;
+
+        impl AeadCore for $name {
+            type NonceSize = U0;
+            type TagSize = U16;
+            type CiphertextOverhead = U0;
+            const ALLOW_EMPTY_NONCE: bool = $allow;
+        }
+
+        impl AeadInPlace for $name {
+            fn encrypt_in_place_detached(
+                &self,
+                _nonce: &Nonce<Self>,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+            ) -> Result<Tag<Self>> {
+                buffer.iter_mut().for_each(|b| *b ^= 0xA5);
+                Ok(checksum(&[associated_data, buffer]).into())
+            }
+
+            fn decrypt_in_place_detached(
+                &self,
+                _nonce: &Nonce<Self>,
+                associated_data: &[u8],
+                buffer: &mut [u8],
+                expected: &Tag<Self>,
+            ) -> Result<()> {
+                if checksum(&[associated_data, buffer]) != expected.as_slice() {
+                    return Err(Error);
+                }
+                buffer.iter_mut().for_each(|b| *b ^= 0xA5);
+                Ok(())
+            }
+        }
+    };
+}
+
+mock_aead!(SingleUseKeyAead, true);
+mock_aead!(MisconfiguredAead, false);
+
+const MSG: &[u8] = b"hello world";
+
+#[test]
+fn empty_nonce_allowed() {
+    let nonce = SingleUseKeyAead::nonce_from_slice(&[]).unwrap();
+    let mut buf = *b"hello world";
+    let mut tag = Tag::<SingleUseKeyAead>::default();
+    SingleUseKeyAead
+        .encrypt_in_place_detached_into(nonce, b"", &mut buf, &mut tag)
+        .unwrap();
+    SingleUseKeyAead
+        .decrypt_in_place_detached(nonce, b"", &mut buf, &tag)
+        .unwrap();
+    assert_eq!(buf, MSG);
+}
+
+#[test]
+fn empty_nonce_rejected() {
+    assert_eq!(MisconfiguredAead::nonce_from_slice(&[]), Err(Error));
+
+    let nonce = Nonce::<MisconfiguredAead>::default();
+    let mut buf = *b"hello world";
+    let mut tag = Tag::<MisconfiguredAead>::default();
+    assert_eq!(
+        MisconfiguredAead.encrypt_in_place_detached_into(&nonce, b"", &mut buf, &mut tag),
+        Err(Error)
+    );
+    // Nothing is encrypted on error.
+    assert_eq!(buf, MSG);
+}
+
+#[test]
+fn empty_nonce_authenticate() {
+    let nonce = Nonce::<SingleUseKeyAead>::default();
+    let tag = SingleUseKeyAead.authenticate(&nonce, b"aad").unwrap();
+    assert_eq!(
+        SingleUseKeyAead.verify_authenticated(&nonce, b"aad", &tag),
+        Ok(())
+    );
+
+    let nonce = Nonce::<MisconfiguredAead>::default();
+    assert_eq!(MisconfiguredAead.authenticate(&nonce, b"aad"), Err(Error));
+    assert_eq!(
+        MisconfiguredAead.verify_authenticated(&nonce, b"aad", &tag),
+        Err(Error)
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn empty_nonce_alloc_api() {
+    use aead::Aead;
+
+    let nonce = Nonce::<SingleUseKeyAead>::default();
+    let ct = SingleUseKeyAead.encrypt(&nonce, MSG).unwrap();
+    assert_eq!(
+        SingleUseKeyAead.decrypt(&nonce, ct.as_slice()).unwrap(),
+        MSG
+    );
+
+    let nonce = Nonce::<MisconfiguredAead>::default();
+    assert_eq!(MisconfiguredAead.encrypt(&nonce, MSG), Err(Error));
+}
@@ -0,0 +1,193 @@
+//! Tests for the `ExtendedNonceAead` trait.
+
+use aead::{
+    consts::{U0, U12, U16},
+    AeadCore, AeadInPlace, Error, ExtendedNonceAead, Nonce, Result, Tag,
+};
+
+/// Length of the extended nonce supported by [`MockAead`].
+const EXTENDED_NONCE_SIZE: usize = 24;
+
+/// Mock AEAD which XORs the data with a keystream byte derived from the key
+/// and nonce, and uses a checksum of the ciphertext as the tag.
+struct MockAead {
+    key: u8,
+}
+
+impl MockAead {
+    fn ks(&self, nonce: &Nonce<Self>) -> u8 {
+        nonce.iter().fold(self.key, |acc, b| acc.wrapping_add(*b))
+    }
+
+    fn tag(ks: u8, ciphertext: &[u8]) -> Tag<Self> {
+        let sum = ciphertext.iter().fold(ks, |acc, b| acc.wrapping_add(*b));
+        Tag::<Self>::from([sum; 16])
+    }
+
+    /// Derive a subkey from the first part of the extended nonce and return
+    /// it along with the working nonce.
+    fn derive(&self, nonce: &[u8]) -> Result<(Self, Nonce<Self>)> {
+        if nonce.len() != EXTENDED_NONCE_SIZE {
+            return Err(Error);
+        }
+        let (prefix, rest) = nonce.split_at(EXTENDED_NONCE_SIZE - 12);
+        let key = prefix
+            .iter()
+            .fold(self.key, |acc, b| acc ^ b.rotate_left(3));
+        let nonce = Nonce::<Self>::try_from(rest).map_err(|_| Error)?;
+        Ok((Self { key }, nonce))
+    }
+}
+
+impl AeadCore for MockAead {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for MockAead {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        _associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        let ks = self.ks(nonce);
+        buffer.iter_mut().for_each(|b| *b ^= ks);
+        Ok(Self::tag(ks, buffer))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        _associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        let ks = self.ks(nonce);
+        if Self::tag(ks, buffer) != *tag {
+            return Err(Error);
+        }
+        buffer.iter_mut().for_each(|b| *b ^= ks);
+        Ok(())
+    }
+}
+
+impl ExtendedNonceAead for MockAead {
+    fn encrypt_in_place_detached_ext(
+        &self,
+        nonce: &[u8],
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        let (aead, nonce) = self.derive(nonce)?;
+        aead.encrypt_in_place_detached(&nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached_ext(
+        &self,
+        nonce: &[u8],
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        let (aead, nonce) = self.derive(nonce)?;
+        aead.decrypt_in_place_detached(&nonce, associated_data, buffer, tag)
+    }
+}
+
+/// Mock AEAD which only supports standard nonces.
+struct StandardOnly(MockAead);
+
+impl AeadCore for StandardOnly {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for StandardOnly {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        self.0
+            .encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        self.0
+            .decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+impl ExtendedNonceAead for StandardOnly {}
+
+const PLAINTEXT: &[u8] = b"example plaintext";
+
+#[test]
+fn extended_nonce_round_trip() {
+    let aead = MockAead { key: 0x17 };
+    let nonce = [0x42u8; EXTENDED_NONCE_SIZE];
+
+    let mut buf = PLAINTEXT.to_vec();
+    let tag = aead
+        .encrypt_in_place_detached_ext(&nonce, b"", &mut buf)
+        .unwrap();
+    assert_ne!(buf, PLAINTEXT);
+
+    aead.decrypt_in_place_detached_ext(&nonce, b"", &mut buf, &tag)
+        .unwrap();
+    assert_eq!(buf, PLAINTEXT);
+}
+
+#[test]
+fn unsupported_nonce_length_rejected() {
+    let aead = MockAead { key: 0x17 };
+    let nonce = [0x42u8; 20];
+    let mut buf = PLAINTEXT.to_vec();
+
+    assert_eq!(
+        aead.encrypt_in_place_detached_ext(&nonce, b"", &mut buf),
+        Err(Error)
+    );
+    assert_eq!(
+        aead.decrypt_in_place_detached_ext(&nonce, b"", &mut buf, &Tag::<MockAead>::default()),
+        Err(Error)
+    );
+    assert_eq!(buf, PLAINTEXT);
+}
+
+#[test]
+fn default_accepts_only_standard_nonce() {
+    let aead = StandardOnly(MockAead { key: 0x17 });
+    let nonce = [0x42u8; 12];
+
+    let mut expected = PLAINTEXT.to_vec();
+    let expected_tag = aead
+        .encrypt_in_place_detached(&nonce.into(), b"", &mut expected)
+        .unwrap();
+
+    let mut buf = PLAINTEXT.to_vec();
+    let tag = aead
+        .encrypt_in_place_detached_ext(&nonce, b"", &mut buf)
+        .unwrap();
+    assert_eq!(buf, expected);
+    assert_eq!(tag, expected_tag);
+
+    aead.decrypt_in_place_detached_ext(&nonce, b"", &mut buf, &tag)
+        .unwrap();
+    assert_eq!(buf, PLAINTEXT);
+
+    assert_eq!(
+        aead.encrypt_in_place_detached_ext(&[0u8; EXTENDED_NONCE_SIZE], b"", &mut buf),
+        Err(Error)
+    );
+}
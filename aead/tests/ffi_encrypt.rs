@@ -0,0 +1,94 @@
+//! Tests for slice-based encryption intended for C FFI.
+
+mod common;
+
+use aead::{AeadInPlace, Error, Tag};
+use common::MockAead;
+
+const NONCE: [u8; 12] = [0x42; 12];
+const AAD: &[u8] = b"packet header";
+const PLAINTEXT: &[u8] = b"example plaintext message";
+
+fn expected() -> (Vec<u8>, Tag<MockAead>) {
+    let mut ct = PLAINTEXT.to_vec();
+    let tag = MockAead::default()
+        .encrypt_in_place_detached(&NONCE.into(), AAD, &mut ct)
+        .unwrap();
+    (ct, tag)
+}
+
+#[test]
+fn ffi_encrypt() {
+    let (expected_ct, expected_tag) = expected();
+
+    // Message is followed by unused space, which is left untouched
+    let mut in_out = [0xAAu8; 32];
+    in_out[..PLAINTEXT.len()].copy_from_slice(PLAINTEXT);
+    let mut tag_out = [0u8; 16];
+    let written = MockAead::default()
+        .ffi_encrypt(&NONCE, AAD, &mut in_out, PLAINTEXT.len(), &mut tag_out)
+        .unwrap();
+
+    assert_eq!(written, PLAINTEXT.len() + 16);
+    assert_eq!(&in_out[..PLAINTEXT.len()], expected_ct);
+    assert!(in_out[PLAINTEXT.len()..].iter().all(|&b| b == 0xAA));
+    assert_eq!(tag_out, expected_tag.as_slice());
+}
+
+#[test]
+fn ffi_encrypt_longer_tag_out() {
+    let (_, expected_tag) = expected();
+
+    let mut in_out = PLAINTEXT.to_vec();
+    let mut tag_out = [0xAAu8; 20];
+    MockAead::default()
+        .ffi_encrypt(&NONCE, AAD, &mut in_out, PLAINTEXT.len(), &mut tag_out)
+        .unwrap();
+    assert_eq!(tag_out[..16], expected_tag[..]);
+    assert_eq!(tag_out[16..], [0xAA; 4]);
+}
+
+#[test]
+fn ffi_encrypt_short_tag_out() {
+    let mut in_out = PLAINTEXT.to_vec();
+    let mut tag_out = [0u8; 15];
+    assert_eq!(
+        MockAead::default().ffi_encrypt(&NONCE, AAD, &mut in_out, PLAINTEXT.len(), &mut tag_out),
+        Err(Error)
+    );
+    assert_eq!(in_out, PLAINTEXT);
+    assert_eq!(tag_out, [0u8; 15]);
+}
+
+#[test]
+fn ffi_encrypt_invalid_lengths() {
+    let mut in_out = PLAINTEXT.to_vec();
+    let mut tag_out = [0u8; 16];
+
+    // Nonce of the wrong length
+    assert_eq!(
+        MockAead::default().ffi_encrypt(
+            &NONCE[..11],
+            AAD,
+            &mut in_out,
+            PLAINTEXT.len(),
+            &mut tag_out
+        ),
+        Err(Error)
+    );
+
+    // Message longer than the buffer
+    assert_eq!(
+        MockAead::default().ffi_encrypt(
+            &NONCE,
+            AAD,
+            &mut in_out,
+            PLAINTEXT.len() + 1,
+            &mut tag_out
+        ),
+        Err(Error)
+    );
+
+    assert_eq!(in_out, PLAINTEXT);
+    assert_eq!(tag_out, [0u8; 16]);
+}
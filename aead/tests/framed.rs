@@ -0,0 +1,132 @@
+//! Tests for length-framed payloads.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aead::{
+    consts::{U0, U12, U16},
+    Aead, AeadCore, AeadInPlace, Error, Nonce, Payload, Result, Tag,
+};
+use common::checksum;
+
+/// Mock AEAD which XORs the data with the first byte of the nonce and whose
+/// tag is a checksum of the nonce and associated data only, so it does not
+/// detect truncation of the ciphertext by itself.
+struct MockAead;
+
+impl MockAead {
+    fn tag(nonce: &Nonce<Self>, associated_data: &[u8]) -> Tag<Self> {
+        checksum(&[nonce, associated_data]).into()
+    }
+}
+
+impl AeadCore for MockAead {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for MockAead {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        buffer.iter_mut().for_each(|b| *b ^= nonce[0]);
+        Ok(Self::tag(nonce, associated_data))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        if Self::tag(nonce, associated_data) != *tag {
+            return Err(Error);
+        }
+        buffer.iter_mut().for_each(|b| *b ^= nonce[0]);
+        Ok(())
+    }
+}
+
+const NONCE: [u8; 12] = [0x42; 12];
+const MSG: &[u8] = b"hello world";
+const AAD: &[u8] = b"header";
+
+/// Remove the last message byte while keeping the tag.
+fn truncate(ct: &[u8]) -> Vec<u8> {
+    let (msg, tag) = ct.split_at(ct.len() - 16);
+    [&msg[..msg.len() - 1], tag].concat()
+}
+
+#[test]
+fn framed_round_trip() {
+    let nonce = NONCE.into();
+    let ct = MockAead
+        .encrypt_framed(&nonce, Payload::framed(MSG))
+        .unwrap();
+    let pt = MockAead
+        .decrypt_framed(&nonce, Payload::framed(&ct))
+        .unwrap();
+    assert_eq!(pt, MSG);
+}
+
+#[test]
+fn framed_encoding() {
+    let nonce = NONCE.into();
+    let payload = Payload { msg: MSG, aad: AAD }.into_framed();
+    let ct = MockAead.encrypt_framed(&nonce, payload).unwrap();
+
+    let mut aad = AAD.to_vec();
+    aad.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 11]);
+    let pt = MockAead
+        .decrypt(
+            &nonce,
+            Payload {
+                msg: &ct,
+                aad: &aad,
+            },
+        )
+        .unwrap();
+    assert_eq!(pt, MSG);
+}
+
+#[test]
+fn truncation_fails() {
+    let nonce = NONCE.into();
+    let payload = Payload { msg: MSG, aad: AAD };
+    let ct = MockAead
+        .encrypt_framed(&nonce, payload.into_framed())
+        .unwrap();
+    let truncated = truncate(&ct);
+
+    let payload = Payload {
+        msg: &truncated,
+        aad: AAD,
+    };
+    assert_eq!(
+        MockAead.decrypt_framed(&nonce, payload.into_framed()),
+        Err(Error)
+    );
+
+    // Without framing the mock does not detect the truncation.
+    let ct = MockAead.encrypt(&nonce, MSG).unwrap();
+    let truncated = truncate(&ct);
+    assert_eq!(
+        MockAead.decrypt(&nonce, truncated.as_slice()).unwrap(),
+        &MSG[..MSG.len() - 1]
+    );
+}
+
+#[test]
+fn too_short_fails() {
+    let nonce = NONCE.into();
+    assert_eq!(
+        MockAead.decrypt_framed(&nonce, Payload::framed(&[0u8; 15])),
+        Err(Error)
+    );
+}
@@ -0,0 +1,92 @@
+//! Tests for typed AAD headers.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aead::{push_aad_field, AadHeader, Aead, Buffer, Error, Result};
+use common::MockAead;
+
+struct Header {
+    version: u16,
+    sender: &'static [u8],
+    channel: &'static [u8],
+}
+
+impl AadHeader for Header {
+    fn to_aad(&self, out: &mut impl Buffer) -> Result<()> {
+        push_aad_field(out, &self.version.to_be_bytes())?;
+        push_aad_field(out, self.sender)?;
+        push_aad_field(out, self.channel)
+    }
+}
+
+const NONCE: [u8; 12] = [0x42; 12];
+const MSG: &[u8] = b"hello world";
+const HEADER: Header = Header {
+    version: 1,
+    sender: b"alice",
+    channel: b"general",
+};
+
+#[test]
+fn header_round_trip() {
+    let nonce = NONCE.into();
+    let ct = MockAead::default()
+        .encrypt_with_header(&nonce, &HEADER, MSG)
+        .unwrap();
+    let pt = MockAead::default()
+        .decrypt_with_header(&nonce, &HEADER, &ct)
+        .unwrap();
+    assert_eq!(pt, MSG);
+}
+
+#[test]
+fn changed_header_fields_fail() {
+    let nonce = NONCE.into();
+    let ct = MockAead::default()
+        .encrypt_with_header(&nonce, &HEADER, MSG)
+        .unwrap();
+
+    let changed = [
+        Header {
+            version: 2,
+            ..HEADER
+        },
+        Header {
+            sender: b"mallory",
+            ..HEADER
+        },
+        Header {
+            channel: b"random",
+            ..HEADER
+        },
+        // Moving bytes across a field boundary changes the encoding.
+        Header {
+            sender: b"aliceg",
+            channel: b"eneral",
+            ..HEADER
+        },
+    ];
+    for header in &changed {
+        assert_eq!(
+            MockAead::default().decrypt_with_header(&nonce, header, &ct),
+            Err(Error)
+        );
+    }
+}
+
+#[test]
+fn encoding_is_length_prefixed() {
+    let mut aad = Vec::new();
+    HEADER.to_aad(&mut aad).unwrap();
+    let expected = [
+        &[0, 0, 0, 0, 0, 0, 0, 2, 0, 1][..],
+        &[0, 0, 0, 0, 0, 0, 0, 5],
+        b"alice",
+        &[0, 0, 0, 0, 0, 0, 0, 7],
+        b"general",
+    ]
+    .concat();
+    assert_eq!(aad, expected);
+}
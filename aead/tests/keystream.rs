@@ -0,0 +1,108 @@
+//! Tests for the hazmat keystream API.
+
+mod common;
+
+use aead::{
+    consts::{U0, U12, U16},
+    hazmat::KeystreamAead,
+    AeadCore, AeadInPlace, Error, Nonce, Result, Tag,
+};
+use common::checksum;
+
+/// Mock CTR-based AEAD: the keystream is produced by a counter-keyed
+/// mixing function and the tag is a checksum of the nonce, associated
+/// data, and ciphertext.
+struct MockCtrAead {
+    key: u32,
+}
+
+impl MockCtrAead {
+    fn keystream_byte(&self, nonce: &Nonce<Self>, pos: usize) -> u8 {
+        let seed = self.key ^ u32::from_le_bytes(nonce[..4].try_into().unwrap());
+        let x = seed.wrapping_add(pos as u32).wrapping_mul(0x9E37_79B9);
+        (x >> 24) as u8
+    }
+
+    fn apply_keystream(&self, nonce: &Nonce<Self>, buffer: &mut [u8]) {
+        for (i, b) in buffer.iter_mut().enumerate() {
+            *b ^= self.keystream_byte(nonce, i);
+        }
+    }
+
+    fn tag(nonce: &Nonce<Self>, associated_data: &[u8], ciphertext: &[u8]) -> Tag<Self> {
+        checksum(&[nonce, associated_data, ciphertext]).into()
+    }
+}
+
+impl AeadCore for MockCtrAead {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+impl AeadInPlace for MockCtrAead {
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        self.apply_keystream(nonce, buffer);
+        Ok(Self::tag(nonce, associated_data, buffer))
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        if Self::tag(nonce, associated_data, buffer) != *tag {
+            return Err(Error);
+        }
+        self.apply_keystream(nonce, buffer);
+        Ok(())
+    }
+}
+
+impl KeystreamAead for MockCtrAead {
+    fn keystream(&self, nonce: &Nonce<Self>, out: &mut [u8]) {
+        out.fill(0);
+        self.apply_keystream(nonce, out);
+    }
+}
+
+const NONCE: [u8; 12] = [0x24; 12];
+
+#[test]
+fn keystream_equals_ciphertext_of_zeros() {
+    let aead = MockCtrAead { key: 0x0123_4567 };
+    let nonce = NONCE.into();
+
+    let mut keystream = [0xFFu8; 37];
+    aead.keystream(&nonce, &mut keystream);
+
+    let mut zeros = [0u8; 37];
+    aead.encrypt_in_place_detached(&nonce, b"", &mut zeros)
+        .unwrap();
+    assert_eq!(keystream, zeros);
+}
+
+#[test]
+fn keystream_xor_plaintext_equals_ciphertext() {
+    let aead = MockCtrAead { key: 0x89AB_CDEF };
+    let nonce = NONCE.into();
+    let plaintext = *b"hello keystream world";
+
+    let mut keystream = [0u8; 21];
+    aead.keystream(&nonce, &mut keystream);
+
+    let mut ciphertext = plaintext;
+    aead.encrypt_in_place_detached(&nonce, b"aad", &mut ciphertext)
+        .unwrap();
+
+    for ((p, k), c) in plaintext.iter().zip(&keystream).zip(&ciphertext) {
+        assert_eq!(p ^ k, *c);
+    }
+}
@@ -0,0 +1,87 @@
+//! RFC 3394 key wrap tests.
+
+#![cfg(feature = "keywrap")]
+
+use aead::{
+    keywrap::{KeyWrap, Kw},
+    Error, KeyInit,
+};
+use aes::{Aes128, Aes192, Aes256};
+use hex_literal::hex;
+
+fn check<C>(kek: &[u8], key: &[u8], expected: &[u8])
+where
+    Kw<C>: KeyWrap + KeyInit,
+{
+    let kw = Kw::<C>::new_from_slice(kek).unwrap();
+
+    let mut wrapped = [0u8; 40];
+    let n = kw.wrap(key, &mut wrapped).unwrap();
+    assert_eq!(&wrapped[..n], expected);
+
+    let mut unwrapped = [0u8; 32];
+    let n = kw.unwrap(expected, &mut unwrapped).unwrap();
+    assert_eq!(&unwrapped[..n], key);
+
+    let mut tampered = [0u8; 40];
+    tampered[..expected.len()].copy_from_slice(expected);
+    tampered[expected.len() - 1] ^= 1;
+    assert_eq!(
+        kw.unwrap(&tampered[..expected.len()], &mut unwrapped),
+        Err(Error)
+    );
+    assert!(unwrapped.iter().all(|&b| b == 0));
+}
+
+const KEK: [u8; 32] = hex!("000102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F");
+const KEY: [u8; 32] = hex!("00112233445566778899AABBCCDDEEFF000102030405060708090A0B0C0D0E0F");
+
+/// RFC 3394 § 4.1
+#[test]
+fn wrap_128_key_with_128_kek() {
+    check::<Aes128>(
+        &KEK[..16],
+        &KEY[..16],
+        &hex!("1FA68B0A8112B447 AEF34BD8FB5A7B82 9D3E862371D2CFE5"),
+    );
+}
+
+/// RFC 3394 § 4.2
+#[test]
+fn wrap_128_key_with_192_kek() {
+    check::<Aes192>(
+        &KEK[..24],
+        &KEY[..16],
+        &hex!("96778B25AE6CA435 F92B5B97C050AED2 468AB8A17AD84E5D"),
+    );
+}
+
+/// RFC 3394 § 4.6
+#[test]
+fn wrap_256_key_with_256_kek() {
+    check::<Aes256>(
+        &KEK,
+        &KEY,
+        &hex!(
+            "28C9F404C4B810F4 CBCCB35CFB87F826 3F5786E2D80ED326"
+            "CBC7F0E71A99F43B FB988B9B7A02DD21"
+        ),
+    );
+}
+
+#[test]
+fn invalid_lengths() {
+    let kw = Kw::<Aes128>::new_from_slice(&KEK[..16]).unwrap();
+    let mut out = [0u8; 40];
+
+    // Not a multiple of 8 bytes
+    assert_eq!(kw.wrap(&KEY[..17], &mut out), Err(Error));
+    // Too short
+    assert_eq!(kw.wrap(&KEY[..8], &mut out), Err(Error));
+    // Output buffer too small
+    assert_eq!(kw.wrap(&KEY[..16], &mut out[..23]), Err(Error));
+
+    assert_eq!(kw.unwrap(&out[..20], &mut [0u8; 40]), Err(Error));
+    assert_eq!(kw.unwrap(&out[..16], &mut [0u8; 40]), Err(Error));
+    assert_eq!(kw.unwrap(&out[..24], &mut [0u8; 15]), Err(Error));
+}
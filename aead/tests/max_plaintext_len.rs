@@ -0,0 +1,98 @@
+//! Tests for the maximum plaintext length limit.
+
+mod common;
+
+use aead::{AeadInPlace, AeadMutInPlace, Buffer, Error, Result, Tag};
+use common::LimitedAead;
+
+/// Mock AEAD with a tiny plaintext length limit.
+type MockAead = LimitedAead<8>;
+
+const NONCE: [u8; 12] = [0x42; 12];
+
+#[test]
+fn in_place_within_limit() {
+    let aead = MockAead::default();
+    let mut buf = test_buf(8);
+    aead.encrypt_in_place(&NONCE.into(), b"", &mut buf).unwrap();
+    assert_eq!(buf.len(), 8 + 16);
+
+    let mut buf = [0u8; 8];
+    let mut tag = Tag::<MockAead>::default();
+    aead.encrypt_in_place_detached_into(&NONCE.into(), b"", &mut buf, &mut tag)
+        .unwrap();
+    assert_eq!(aead.0.encrypt_calls.get(), 2);
+}
+
+#[test]
+fn in_place_over_limit() {
+    let mut aead = MockAead::default();
+    let mut buf = test_buf(9);
+    assert_eq!(
+        AeadInPlace::encrypt_in_place(&aead, &NONCE.into(), b"", &mut buf),
+        Err(Error)
+    );
+    assert_eq!(
+        AeadMutInPlace::encrypt_in_place(&mut aead, &NONCE.into(), b"", &mut buf),
+        Err(Error)
+    );
+    assert_eq!(buf, test_buf(9));
+
+    let mut buf = [0u8; 9];
+    let mut tag = Tag::<MockAead>::default();
+    assert_eq!(
+        aead.encrypt_in_place_detached_into(&NONCE.into(), b"", &mut buf, &mut tag),
+        Err(Error)
+    );
+    assert_eq!(buf, [0u8; 9]);
+
+    // Oversize input is rejected before encryption
+    assert_eq!(aead.0.encrypt_calls.get(), 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn aead_encrypt_over_limit() {
+    use aead::Aead;
+
+    let aead = MockAead::default();
+    assert!(aead.encrypt(&NONCE.into(), &[0u8; 8][..]).is_ok());
+    assert_eq!(aead.encrypt(&NONCE.into(), &[0u8; 9][..]), Err(Error));
+    let by_ref = &aead;
+    assert_eq!(
+        <&MockAead as Aead>::encrypt(&by_ref, &NONCE.into(), &[0u8; 9][..]),
+        Err(Error)
+    );
+    assert_eq!(aead.0.encrypt_calls.get(), 1);
+}
+
+/// Growable buffer which does not depend on the `alloc` feature.
+#[derive(Debug, PartialEq)]
+struct TestBuf(Vec<u8>);
+
+impl AsRef<[u8]> for TestBuf {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for TestBuf {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl Buffer for TestBuf {
+    fn extend_from_slice(&mut self, other: &[u8]) -> Result<()> {
+        self.0.extend_from_slice(other);
+        Ok(())
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+}
+
+fn test_buf(len: usize) -> TestBuf {
+    TestBuf(vec![0x11; len])
+}
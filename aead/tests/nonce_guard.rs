@@ -0,0 +1,43 @@
+//! Tests for the nonce reuse detector.
+
+#![cfg(all(feature = "nonce-reuse-guard", debug_assertions))]
+
+mod common;
+
+use aead::{Aead, AeadInPlace, Nonce, NonceReuseGuard};
+use common::MockAead;
+
+#[test]
+fn distinct_nonces() {
+    let aead = NonceReuseGuard::new(MockAead::default());
+    let n1 = Nonce::<MockAead>::from([1; 12]);
+    let n2 = Nonce::<MockAead>::from([2; 12]);
+
+    let ct1 = aead.encrypt(&n1, &b"hello"[..]).unwrap();
+    let ct2 = aead.encrypt(&n2, &b"hello"[..]).unwrap();
+    aead.authenticate(&Nonce::<MockAead>::from([3; 12]), b"aad")
+        .unwrap();
+
+    // Decryption may reuse nonces
+    assert_eq!(aead.decrypt(&n1, ct1.as_slice()).unwrap(), b"hello");
+    assert_eq!(aead.decrypt(&n1, ct1.as_slice()).unwrap(), b"hello");
+    assert_eq!(aead.decrypt(&n2, ct2.as_slice()).unwrap(), b"hello");
+}
+
+#[test]
+#[should_panic(expected = "AEAD nonce reuse detected")]
+fn reused_nonce_panics() {
+    let aead = NonceReuseGuard::new(MockAead::default());
+    let nonce = Nonce::<MockAead>::from([1; 12]);
+    aead.encrypt(&nonce, &b"hello"[..]).unwrap();
+    let _ = aead.encrypt(&nonce, &b"world"[..]);
+}
+
+#[test]
+#[should_panic(expected = "AEAD nonce reuse detected")]
+fn reused_nonce_detached_panics() {
+    let aead = NonceReuseGuard::new(MockAead::default());
+    let nonce = Nonce::<MockAead>::from([1; 12]);
+    aead.authenticate(&nonce, b"aad").unwrap();
+    let _ = aead.encrypt_in_place_detached(&nonce, b"", &mut [0u8; 4]);
+}
@@ -0,0 +1,40 @@
+//! Tests for the single-use AEAD wrapper.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aead::{Aead, Error, OneShotAead, Payload};
+use common::MockAead;
+
+const NONCE: [u8; 12] = [0x42; 12];
+const MSG: &[u8] = b"hello world";
+const AAD: &[u8] = b"header";
+
+#[test]
+fn one_shot_encrypt_matches_aead() {
+    let nonce = NONCE.into();
+    let payload = Payload { msg: MSG, aad: AAD };
+    let ct = OneShotAead::new(MockAead::default())
+        .into_encrypt(&nonce, payload)
+        .unwrap();
+    let payload = Payload { msg: MSG, aad: AAD };
+    assert_eq!(ct, MockAead::default().encrypt(&nonce, payload).unwrap());
+
+    let payload = Payload { msg: &ct, aad: AAD };
+    let pt = OneShotAead::new(MockAead::default())
+        .into_decrypt(&nonce, payload)
+        .unwrap();
+    assert_eq!(pt, MSG);
+}
+
+#[test]
+fn one_shot_decrypt_failure() {
+    let nonce = NONCE.into();
+    let mut ct = MockAead::default().encrypt(&nonce, MSG).unwrap();
+    ct[0] ^= 1;
+    assert_eq!(
+        OneShotAead::new(MockAead::default()).into_decrypt(&nonce, ct.as_slice()),
+        Err(Error)
+    );
+}
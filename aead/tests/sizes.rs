@@ -0,0 +1,92 @@
+use aead::{
+    consts::{U0, U12, U16},
+    AeadCore, Error, Nonce, Tag,
+};
+
+/// Mock AEAD which only defines its sizes.
+struct MockAead;
+
+impl AeadCore for MockAead {
+    type NonceSize = U12;
+    type TagSize = U16;
+    type CiphertextOverhead = U0;
+}
+
+/// Size of a message framed as `nonce || ciphertext || tag`, computed in
+/// a `const` context.
+const fn framed_len<A: AeadCore>(msg_len: usize) -> usize {
+    A::NONCE_SIZE + msg_len + A::CIPHERTEXT_OVERHEAD + A::TAG_SIZE
+}
+
+const NONCE_SIZE: usize = MockAead::NONCE_SIZE;
+const TAG_SIZE: usize = MockAead::TAG_SIZE;
+const CIPHERTEXT_OVERHEAD: usize = MockAead::CIPHERTEXT_OVERHEAD;
+const FRAMED: [u8; framed_len::<MockAead>(4)] = [0; framed_len::<MockAead>(4)];
+
+#[test]
+fn const_sizes() {
+    assert_eq!(NONCE_SIZE, Nonce::<MockAead>::default().len());
+    assert_eq!(TAG_SIZE, Tag::<MockAead>::default().len());
+    assert_eq!(CIPHERTEXT_OVERHEAD, 0);
+    assert_eq!(FRAMED.len(), 12 + 4 + 16);
+}
+
+#[test]
+fn nonce_from_slice() {
+    let bytes = [7u8; 13];
+    let nonce = MockAead::nonce_from_slice(&bytes[..12]).unwrap();
+    assert_eq!(nonce.as_slice(), &bytes[..12]);
+    // The nonce is not copied
+    assert_eq!(nonce.as_ptr(), bytes.as_ptr());
+
+    assert_eq!(MockAead::nonce_from_slice(&bytes[..11]), Err(Error));
+    assert_eq!(MockAead::nonce_from_slice(&bytes), Err(Error));
+}
+
+#[test]
+fn tag_from_slice() {
+    let bytes = [7u8; 17];
+    let tag = MockAead::tag_from_slice(&bytes[..16]).unwrap();
+    assert_eq!(tag.as_slice(), &bytes[..16]);
+    assert_eq!(tag.as_ptr(), bytes.as_ptr());
+
+    assert_eq!(MockAead::tag_from_slice(&bytes[..15]), Err(Error));
+    assert_eq!(MockAead::tag_from_slice(&bytes), Err(Error));
+    assert_eq!(MockAead::tag_from_slice(&[]), Err(Error));
+}
+
+#[test]
+fn split_ct_tag() {
+    let data: Vec<u8> = (0..20).collect();
+    let (ct, tag) = MockAead::split_ct_tag(&data).unwrap();
+    assert_eq!(ct, &data[..4]);
+    assert_eq!(tag.as_slice(), &data[4..]);
+    // Nothing is copied
+    assert_eq!(ct.as_ptr(), data.as_ptr());
+    assert_eq!(tag.as_ptr(), data[4..].as_ptr());
+
+    // Tag only
+    let (ct, tag) = MockAead::split_ct_tag(&data[..16]).unwrap();
+    assert!(ct.is_empty());
+    assert_eq!(tag.as_slice(), &data[..16]);
+
+    assert_eq!(MockAead::split_ct_tag(&data[..15]), Err(Error));
+    assert_eq!(MockAead::split_ct_tag(&[]), Err(Error));
+}
+
+#[test]
+fn split_tag_ct() {
+    let data: Vec<u8> = (0..20).collect();
+    let (tag, ct) = MockAead::split_tag_ct(&data).unwrap();
+    assert_eq!(tag.as_slice(), &data[..16]);
+    assert_eq!(ct, &data[16..]);
+    assert_eq!(tag.as_ptr(), data.as_ptr());
+    assert_eq!(ct.as_ptr(), data[16..].as_ptr());
+
+    let (tag, ct) = MockAead::split_tag_ct(&data[..16]).unwrap();
+    assert_eq!(tag.as_slice(), &data[..16]);
+    assert!(ct.is_empty());
+
+    assert_eq!(MockAead::split_tag_ct(&data[..15]), Err(Error));
+    assert_eq!(MockAead::split_tag_ct(&[]), Err(Error));
+}
@@ -0,0 +1,183 @@
+//! Tests for the STREAM adapters.
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use aead::{
+    consts::U0,
+    stream::{CounterAad, Decryptor, Encryptor, NewStream, Nonce as StreamNonce, StreamPrimitive},
+    AeadInPlace, Buffer, Error, Nonce, Payload, Result,
+};
+use common::MockAead;
+
+/// Broken STREAM primitive which does not include the counter in the
+/// nonce, i.e. segments are not bound to their position.
+struct BrokenStream {
+    aead: MockAead,
+    nonce: Nonce<MockAead>,
+}
+
+impl NewStream<MockAead> for BrokenStream {
+    fn from_aead(aead: MockAead, nonce: &StreamNonce<MockAead, Self>) -> Self {
+        Self {
+            aead,
+            nonce: *nonce,
+        }
+    }
+}
+
+impl StreamPrimitive<MockAead> for BrokenStream {
+    type NonceOverhead = U0;
+    type Counter = u32;
+    const COUNTER_INCR: u32 = 1;
+    const COUNTER_MAX: u32 = u32::MAX;
+
+    fn encrypt_in_place(
+        &self,
+        _position: u32,
+        _last_block: bool,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        self.aead
+            .encrypt_in_place(&self.nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        _position: u32,
+        _last_block: bool,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        self.aead
+            .decrypt_in_place(&self.nonce, associated_data, buffer)
+    }
+}
+
+/// STREAM primitive with a tiny counter, wrapping [`BrokenStream`].
+struct ShortStream(BrokenStream);
+
+impl StreamPrimitive<MockAead> for ShortStream {
+    type NonceOverhead = U0;
+    type Counter = u8;
+    const COUNTER_INCR: u8 = 1;
+    const COUNTER_MAX: u8 = 3;
+
+    fn encrypt_in_place(
+        &self,
+        position: u8,
+        last_block: bool,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        self.0
+            .encrypt_in_place(position.into(), last_block, associated_data, buffer)
+    }
+
+    fn decrypt_in_place(
+        &self,
+        position: u8,
+        last_block: bool,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<()> {
+        self.0
+            .decrypt_in_place(position.into(), last_block, associated_data, buffer)
+    }
+}
+
+fn short_stream() -> ShortStream {
+    ShortStream(BrokenStream::from_aead(
+        MockAead::default(),
+        &[0x42; 12].into(),
+    ))
+}
+
+const AAD: &[u8] = b"frame header";
+const SEGMENTS: [&[u8]; 2] = [b"first segment", b"second segment"];
+
+fn encrypt_segments<S: NewStream<MockAead, Counter = u32, NonceOverhead = U0>>() -> Vec<Vec<u8>> {
+    let mut encryptor = S::from_aead(MockAead::default(), &[0x42; 12].into()).encryptor();
+    SEGMENTS
+        .iter()
+        .map(|segment| {
+            encryptor
+                .encrypt_next(Payload {
+                    msg: segment,
+                    aad: AAD,
+                })
+                .unwrap()
+        })
+        .collect()
+}
+
+fn decrypt_segments<S: NewStream<MockAead, Counter = u32, NonceOverhead = U0>>(
+    segments: &[Vec<u8>],
+) -> Result<Vec<Vec<u8>>> {
+    let mut decryptor = S::from_aead(MockAead::default(), &[0x42; 12].into()).decryptor();
+    segments
+        .iter()
+        .map(|segment| {
+            decryptor.decrypt_next(Payload {
+                msg: segment,
+                aad: AAD,
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn counter_aad_roundtrip() {
+    let ct = encrypt_segments::<CounterAad<BrokenStream>>();
+    let pt = decrypt_segments::<CounterAad<BrokenStream>>(&ct).unwrap();
+    assert_eq!(pt, SEGMENTS);
+
+    // Ciphertexts are not interchangeable with the wrapped primitive
+    assert_ne!(ct, encrypt_segments::<BrokenStream>());
+    assert_eq!(decrypt_segments::<BrokenStream>(&ct), Err(Error));
+}
+
+#[test]
+fn counter_aad_detects_swapped_segments() {
+    // Without the adapter swapped segments go unnoticed
+    let mut ct = encrypt_segments::<BrokenStream>();
+    ct.swap(0, 1);
+    let pt = decrypt_segments::<BrokenStream>(&ct).unwrap();
+    assert_eq!(pt, [SEGMENTS[1], SEGMENTS[0]]);
+
+    let mut ct = encrypt_segments::<CounterAad<BrokenStream>>();
+    ct.swap(0, 1);
+    assert_eq!(
+        decrypt_segments::<CounterAad<BrokenStream>>(&ct),
+        Err(Error)
+    );
+}
+
+#[test]
+fn remaining_segments() {
+    let encryptor = BrokenStream::from_aead(MockAead::default(), &[0x42; 12].into()).encryptor();
+    assert_eq!(encryptor.remaining_segments(), u64::from(u32::MAX));
+
+    let mut encryptor = Encryptor::from_stream_primitive(short_stream());
+    let mut decryptor = Decryptor::from_stream_primitive(short_stream());
+    let mut ciphertexts = Vec::new();
+    for expected in (0..3).rev() {
+        ciphertexts.push(encryptor.encrypt_next(SEGMENTS[0]).unwrap());
+        assert_eq!(encryptor.remaining_segments(), expected);
+    }
+    assert_eq!(encryptor.encrypt_next(SEGMENTS[0]), Err(Error));
+    assert_eq!(encryptor.remaining_segments(), 0);
+    ciphertexts.push(encryptor.encrypt_last(SEGMENTS[1]).unwrap());
+
+    let last = ciphertexts.pop().unwrap();
+    for (ct, expected) in ciphertexts.iter().zip((0..3).rev()) {
+        assert_eq!(decryptor.decrypt_next(ct.as_slice()).unwrap(), SEGMENTS[0]);
+        assert_eq!(decryptor.remaining_segments(), expected);
+    }
+    assert_eq!(
+        decryptor.decrypt_last(last.as_slice()).unwrap(),
+        SEGMENTS[1]
+    );
+}
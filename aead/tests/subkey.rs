@@ -0,0 +1,81 @@
+//! Tests for subkey derivation.
+
+#![cfg(feature = "digest")]
+
+use aead::{
+    consts::{U16, U32, U42},
+    DeriveSubkey, Key, KeySizeUser,
+};
+use hex_literal::hex;
+use sha2::Sha256;
+
+/// Mock key holder with a 32-byte key.
+struct Key32;
+
+impl KeySizeUser for Key32 {
+    type KeySize = U32;
+}
+
+/// Mock key holder with a 16-byte key.
+struct Key16;
+
+impl KeySizeUser for Key16 {
+    type KeySize = U16;
+}
+
+/// Mock key holder with a 42-byte key, i.e. spanning two HMAC blocks.
+struct Key42;
+
+impl KeySizeUser for Key42 {
+    type KeySize = U42;
+}
+
+const MASTER: [u8; 32] = [0x0b; 32];
+
+#[test]
+fn different_labels_yield_different_subkeys() {
+    let master = Key::<Key32>::from(MASTER);
+    let header = Key16::derive_subkey_from::<Key32, Sha256>(&master, b"header");
+    let body = Key16::derive_subkey_from::<Key32, Sha256>(&master, b"body");
+    assert_ne!(header, body);
+    assert_ne!(header.as_slice(), &MASTER[..16]);
+}
+
+#[test]
+fn derivation_is_deterministic() {
+    let master = Key::<Key32>::from(MASTER);
+    let a = Key32::derive_subkey_from::<Key32, Sha256>(&master, b"label");
+    let b = Key32::derive_subkey_from::<Key32, Sha256>(&master, b"label");
+    assert_eq!(a, b);
+
+    let other = Key::<Key32>::from([0x0c; 32]);
+    let c = Key32::derive_subkey_from::<Key32, Sha256>(&other, b"label");
+    assert_ne!(a, c);
+}
+
+#[test]
+fn rfc5869_expand() {
+    // RFC 5869 Appendix A.1, Expand step.
+    let prk = Key::<Key32>::from(hex!(
+        "077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5"
+    ));
+    let info = hex!("f0f1f2f3f4f5f6f7f8f9");
+    let okm = Key42::derive_subkey_from::<Key32, Sha256>(&prk, &info);
+    assert_eq!(
+        okm.as_slice(),
+        hex!(
+            "3cb25f25faacd57a90434f64d0362f2a"
+            "2d2d0a90cf1a5a4c5db02d56ecc4c5bf"
+            "34007208d5b887185865"
+        )
+    );
+}
+
+#[test]
+fn prefix_of_longer_subkey() {
+    // HKDF-Expand output for a shorter key is a prefix of a longer one.
+    let master = Key::<Key32>::from(MASTER);
+    let short = Key16::derive_subkey_from::<Key32, Sha256>(&master, b"label");
+    let long = Key42::derive_subkey_from::<Key32, Sha256>(&master, b"label");
+    assert_eq!(short.as_slice(), &long[..16]);
+}
@@ -0,0 +1,158 @@
+//! Tests for converting between attached and detached tag layouts.
+
+use aead::{
+    consts::{U0, U12, U4},
+    AeadCore, Error, Tag, TagPosition,
+};
+
+/// Mock AEAD layouts; no encryption is performed.
+struct PostfixMock;
+
+impl AeadCore for PostfixMock {
+    type NonceSize = U12;
+    type TagSize = U4;
+    type CiphertextOverhead = U0;
+}
+
+struct PrefixMock;
+
+impl AeadCore for PrefixMock {
+    type NonceSize = U12;
+    type TagSize = U4;
+    type CiphertextOverhead = U0;
+    const TAG_POSITION: TagPosition = TagPosition::Prefix;
+}
+
+const CT: &[u8] = b"ciphertext";
+const TAG: [u8; 4] = [0xDE, 0xAD, 0xBE, 0xEF];
+
+fn round_trip<A: AeadCore>(expected: &[u8]) {
+    let tag = Tag::<A>::try_from(&TAG[..]).unwrap();
+    let mut out = [0u8; 32];
+    let attached = A::detached_to_attached_into(CT, &tag, &mut out).unwrap();
+    assert_eq!(attached, expected);
+
+    let (ct, tag) = A::attached_to_detached(attached).unwrap();
+    assert_eq!(ct, CT);
+    assert_eq!(tag.as_slice(), TAG);
+}
+
+#[test]
+fn postfix_round_trip() {
+    assert_eq!(PostfixMock::TAG_POSITION, TagPosition::Postfix);
+    round_trip::<PostfixMock>(b"ciphertext\xDE\xAD\xBE\xEF");
+}
+
+#[test]
+fn prefix_round_trip() {
+    round_trip::<PrefixMock>(b"\xDE\xAD\xBE\xEFciphertext");
+}
+
+#[test]
+fn attached_too_short() {
+    assert_eq!(PostfixMock::attached_to_detached(&[0; 3]), Err(Error));
+    assert_eq!(PrefixMock::attached_to_detached(&[0; 3]), Err(Error));
+    let (ct, _) = PrefixMock::attached_to_detached(&TAG).unwrap();
+    assert!(ct.is_empty());
+}
+
+#[test]
+fn detached_output_too_short() {
+    let tag = Tag::<PostfixMock>::try_from(&TAG[..]).unwrap();
+    let mut out = [0u8; 13];
+    assert_eq!(
+        PostfixMock::detached_to_attached_into(CT, &tag, &mut out),
+        Err(Error)
+    );
+    let mut out = [0u8; 14];
+    assert_eq!(
+        PostfixMock::detached_to_attached_into(CT, &tag, &mut out)
+            .unwrap()
+            .len(),
+        14
+    );
+}
+
+#[cfg(feature = "alloc")]
+mod attached {
+    use aead::{
+        consts::{U0, U12, U4},
+        Aead, AeadCore, AeadInPlace, Error, Nonce, Result, Tag, TagPosition,
+    };
+
+    /// Mock AEAD with a prefix tag which XORs the data with `0xA5` and uses a
+    /// sum of the associated data and ciphertext as the tag.
+    struct PrefixAead;
+
+    impl PrefixAead {
+        fn tag(associated_data: &[u8], ciphertext: &[u8]) -> Tag<Self> {
+            let sum = associated_data
+                .iter()
+                .chain(ciphertext)
+                .fold(0u32, |acc, &b| acc.wrapping_mul(31).wrapping_add(b.into()));
+            sum.to_be_bytes().into()
+        }
+    }
+
+    impl AeadCore for PrefixAead {
+        type NonceSize = U12;
+        type TagSize = U4;
+        type CiphertextOverhead = U0;
+        const TAG_POSITION: TagPosition = TagPosition::Prefix;
+    }
+
+    impl AeadInPlace for PrefixAead {
+        fn encrypt_in_place_detached(
+            &self,
+            _nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffer: &mut [u8],
+        ) -> Result<Tag<Self>> {
+            buffer.iter_mut().for_each(|b| *b ^= 0xA5);
+            Ok(Self::tag(associated_data, buffer))
+        }
+
+        fn decrypt_in_place_detached(
+            &self,
+            _nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffer: &mut [u8],
+            tag: &Tag<Self>,
+        ) -> Result<()> {
+            if Self::tag(associated_data, buffer) != *tag {
+                return Err(Error);
+            }
+            buffer.iter_mut().for_each(|b| *b ^= 0xA5);
+            Ok(())
+        }
+    }
+
+    const MSG: &[u8] = b"hello world";
+
+    #[test]
+    fn prefix_aead_encrypt_places_tag_first() {
+        let nonce = Nonce::<PrefixAead>::default();
+        let ct = PrefixAead.encrypt(&nonce, MSG).unwrap();
+
+        let mut expected_ct = MSG.to_vec();
+        let tag = PrefixAead
+            .encrypt_in_place_detached(&nonce, b"", &mut expected_ct)
+            .unwrap();
+        assert_eq!(ct, [tag.as_slice(), &expected_ct].concat());
+
+        let (detached_ct, detached_tag) = PrefixAead::attached_to_detached(&ct).unwrap();
+        assert_eq!(detached_ct, expected_ct);
+        assert_eq!(*detached_tag, tag);
+
+        assert_eq!(PrefixAead.decrypt(&nonce, ct.as_slice()).unwrap(), MSG);
+    }
+
+    #[test]
+    fn prefix_aead_rejects_postfix_layout() {
+        let nonce = Nonce::<PrefixAead>::default();
+        let ct = PrefixAead.encrypt(&nonce, MSG).unwrap();
+        let mut swapped = ct[4..].to_vec();
+        swapped.extend_from_slice(&ct[..4]);
+        assert_eq!(PrefixAead.decrypt(&nonce, swapped.as_slice()), Err(Error));
+    }
+}
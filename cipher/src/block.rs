@@ -22,7 +22,11 @@ use inout::{InOut, InOutBuf, NotEqualError};
 
 mod backends;
 mod ctx;
+pub mod keystream;
+#[cfg(feature = "rayon")]
+pub mod par;
 
+pub(crate) use ctx::par_blocks;
 use ctx::{BlockCtx, BlocksCtx};
 
 pub use backends::{
@@ -378,6 +382,45 @@ pub trait BlockModeEncrypt: BlockSizeUser + Sized {
         self.encrypt_padded_inout::<P>(buf)
     }
 
+    /// Pad input and encrypt in-place, then reset mode state for the next message.
+    /// Returns resulting ciphertext slice.
+    ///
+    /// Unlike [`encrypt_padded`][Self::encrypt_padded] this method does not
+    /// consume `self`. After the message is finalized [`BlockModeReset::reset`]
+    /// is called, i.e. the mode is returned to the state it had right after
+    /// initialization (including its IV). State is reset even if an error is
+    /// returned.
+    ///
+    /// Note that encrypting several messages under the same key and IV
+    /// may leak information about the plaintexts. Prefer re-initializing
+    /// the mode with a fresh IV for every message unless the protocol
+    /// explicitly requires IV reuse.
+    ///
+    /// Returns [`PadError`] if length of output buffer is not sufficient.
+    #[cfg(feature = "block-padding")]
+    #[inline]
+    fn encrypt_padded_reset<'a, P: Padding<Self::BlockSize>>(
+        &mut self,
+        buf: &'a mut [u8],
+        msg_len: usize,
+    ) -> Result<&'a [u8], PadError>
+    where
+        Self: BlockModeReset,
+    {
+        let res = InOutBufReserved::from_mut_slice(buf, msg_len)
+            .map_err(|_| PadError)
+            .and_then(|buf| buf.into_padded_blocks::<P, Self::BlockSize>())
+            .map(|mut buf| {
+                self.encrypt_blocks_inout(buf.get_blocks());
+                if let Some(block) = buf.get_tail_block() {
+                    self.encrypt_block_inout(block);
+                }
+                buf.into_out()
+            });
+        self.reset();
+        res
+    }
+
     /// Pad input and encrypt into a newly allocated Vec. Returns resulting ciphertext Vec.
     #[cfg(all(feature = "block-padding", feature = "alloc"))]
     #[inline]
@@ -502,6 +545,37 @@ pub trait BlockModeDecrypt: BlockSizeUser + Sized {
         self.decrypt_padded_inout::<P>(buf)
     }
 
+    /// Decrypt input and unpad it in-place, then reset mode state for the next
+    /// message. Returns resulting plaintext slice.
+    ///
+    /// Unlike [`decrypt_padded`][Self::decrypt_padded] this method does not
+    /// consume `self`. After the message is finalized [`BlockModeReset::reset`]
+    /// is called, i.e. the mode is returned to the state it had right after
+    /// initialization (including its IV). State is reset even if an error is
+    /// returned.
+    ///
+    /// Returns [`UnpadError`] if padding is malformed or if input length is
+    /// not multiple of `Self::BlockSize`.
+    #[cfg(feature = "block-padding")]
+    #[inline]
+    fn decrypt_padded_reset<'a, P: Padding<Self::BlockSize>>(
+        &mut self,
+        buf: &'a mut [u8],
+    ) -> Result<&'a [u8], UnpadError>
+    where
+        Self: BlockModeReset,
+    {
+        let (mut blocks, tail) = InOutBuf::from(buf).into_chunks();
+        let res = if tail.is_empty() {
+            self.decrypt_blocks_inout(blocks.reborrow());
+            P::unpad_blocks(blocks.into_out())
+        } else {
+            Err(UnpadError)
+        };
+        self.reset();
+        res
+    }
+
     /// Decrypt input and unpad it in a newly allocated Vec. Returns resulting
     /// plaintext `Vec`.
     ///
@@ -519,3 +593,16 @@ pub trait BlockModeDecrypt: BlockSizeUser + Sized {
         Ok(out)
     }
 }
+
+/// Block modes which can be reset to their initial state.
+///
+/// Resetting returns the mode to the state it had right after
+/// initialization, i.e. chaining state is discarded and the IV passed
+/// to the constructor is restored. This allows processing of several
+/// independent messages with one mode instance, see
+/// [`BlockModeEncrypt::encrypt_padded_reset`] and
+/// [`BlockModeDecrypt::decrypt_padded_reset`].
+pub trait BlockModeReset {
+    /// Reset mode state to its initial value.
+    fn reset(&mut self);
+}
@@ -1,4 +1,4 @@
-use crypto_common::{typenum::Unsigned, Block, BlockSizeUser, BlockSizes};
+use crypto_common::{typenum::Unsigned, Block, BlockSizeUser, BlockSizes, ParBlocksSizeUser};
 use inout::{InOut, InOutBuf};
 
 use super::{
@@ -6,6 +6,13 @@ use super::{
     BlockModeDecBackend, BlockModeDecClosure, BlockModeEncBackend, BlockModeEncClosure,
 };
 
+/// Number of blocks which backend `B` should process in parallel,
+/// clamped to its static `ParBlocksSize`.
+#[inline(always)]
+pub(crate) fn par_blocks<B: ParBlocksSizeUser>() -> usize {
+    B::par_blocks_runtime().min(B::ParBlocksSize::USIZE)
+}
+
 /// Closure used in methods which operate over separate blocks.
 pub(super) struct BlockCtx<'inp, 'out, BS: BlockSizes> {
     pub block: InOut<'inp, 'out, Block<Self>>,
@@ -54,12 +61,21 @@ impl<BS: BlockSizes> BlockSizeUser for BlocksCtx<'_, '_, BS> {
 impl<BS: BlockSizes> BlockCipherEncClosure for BlocksCtx<'_, '_, BS> {
     #[inline(always)]
     fn call<B: BlockCipherEncBackend<BlockSize = BS>>(self, backend: &B) {
-        if B::ParBlocksSize::USIZE > 1 {
+        let pb = par_blocks::<B>();
+        if pb > 1 && pb == B::ParBlocksSize::USIZE {
             let (chunks, tail) = self.blocks.into_chunks();
             for chunk in chunks {
                 backend.encrypt_par_blocks(chunk);
             }
             backend.encrypt_tail_blocks(tail);
+        } else if pb > 1 {
+            let mut blocks = self.blocks;
+            while !blocks.is_empty() {
+                let n = pb.min(blocks.len());
+                let (chunk, rest) = blocks.split_at(n);
+                backend.encrypt_tail_blocks(chunk);
+                blocks = rest;
+            }
         } else {
             for block in self.blocks {
                 backend.encrypt_block(block);
@@ -71,12 +87,21 @@ impl<BS: BlockSizes> BlockCipherEncClosure for BlocksCtx<'_, '_, BS> {
 impl<BS: BlockSizes> BlockCipherDecClosure for BlocksCtx<'_, '_, BS> {
     #[inline(always)]
     fn call<B: BlockCipherDecBackend<BlockSize = BS>>(self, backend: &B) {
-        if B::ParBlocksSize::USIZE > 1 {
+        let pb = par_blocks::<B>();
+        if pb > 1 && pb == B::ParBlocksSize::USIZE {
             let (chunks, tail) = self.blocks.into_chunks();
             for chunk in chunks {
                 backend.decrypt_par_blocks(chunk);
             }
             backend.decrypt_tail_blocks(tail);
+        } else if pb > 1 {
+            let mut blocks = self.blocks;
+            while !blocks.is_empty() {
+                let n = pb.min(blocks.len());
+                let (chunk, rest) = blocks.split_at(n);
+                backend.decrypt_tail_blocks(chunk);
+                blocks = rest;
+            }
         } else {
             for block in self.blocks {
                 backend.decrypt_block(block);
@@ -88,12 +113,21 @@ impl<BS: BlockSizes> BlockCipherDecClosure for BlocksCtx<'_, '_, BS> {
 impl<BS: BlockSizes> BlockModeEncClosure for BlocksCtx<'_, '_, BS> {
     #[inline(always)]
     fn call<B: BlockModeEncBackend<BlockSize = BS>>(self, backend: &mut B) {
-        if B::ParBlocksSize::USIZE > 1 {
+        let pb = par_blocks::<B>();
+        if pb > 1 && pb == B::ParBlocksSize::USIZE {
             let (chunks, tail) = self.blocks.into_chunks();
             for chunk in chunks {
                 backend.encrypt_par_blocks(chunk);
             }
             backend.encrypt_tail_blocks(tail);
+        } else if pb > 1 {
+            let mut blocks = self.blocks;
+            while !blocks.is_empty() {
+                let n = pb.min(blocks.len());
+                let (chunk, rest) = blocks.split_at(n);
+                backend.encrypt_tail_blocks(chunk);
+                blocks = rest;
+            }
         } else {
             for block in self.blocks {
                 backend.encrypt_block(block);
@@ -105,12 +139,21 @@ impl<BS: BlockSizes> BlockModeEncClosure for BlocksCtx<'_, '_, BS> {
 impl<BS: BlockSizes> BlockModeDecClosure for BlocksCtx<'_, '_, BS> {
     #[inline(always)]
     fn call<B: BlockModeDecBackend<BlockSize = BS>>(self, backend: &mut B) {
-        if B::ParBlocksSize::USIZE > 1 {
+        let pb = par_blocks::<B>();
+        if pb > 1 && pb == B::ParBlocksSize::USIZE {
             let (chunks, tail) = self.blocks.into_chunks();
             for chunk in chunks {
                 backend.decrypt_par_blocks(chunk);
             }
             backend.decrypt_tail_blocks(tail);
+        } else if pb > 1 {
+            let mut blocks = self.blocks;
+            while !blocks.is_empty() {
+                let n = pb.min(blocks.len());
+                let (chunk, rest) = blocks.split_at(n);
+                backend.decrypt_tail_blocks(chunk);
+                blocks = rest;
+            }
         } else {
             for block in self.blocks {
                 backend.decrypt_block(block);
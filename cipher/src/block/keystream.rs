@@ -0,0 +1,88 @@
+//! Lazy generation of keystream blocks in the counter (CTR) mode.
+//!
+//! This is a building block for custom modes of operation. For regular
+//! CTR encryption use the `ctr` crate instead.
+
+use super::BlockCipherEncrypt;
+use core::fmt;
+use crypto_common::Block;
+
+/// Iterator over keystream blocks produced by encrypting an incrementing
+/// counter with the block cipher `C`.
+///
+/// Keystream block with index `i` is the encryption of the initial counter
+/// block plus `i`, where counter blocks are interpreted as big-endian
+/// integers with wrapping addition over the whole block.
+///
+/// # ⚠️ Security Warning
+///
+/// A keystream MUST NOT be reused, i.e. the same combination of key and
+/// initial counter block MUST NOT be used for more than one message.
+/// Use [`seek`][Self::seek] only to move within a single message.
+#[derive(Clone)]
+pub struct BlockKeystream<C: BlockCipherEncrypt> {
+    cipher: C,
+    initial_block: Block<C>,
+    index: u64,
+}
+
+impl<C: BlockCipherEncrypt> BlockKeystream<C> {
+    /// Create new keystream from `cipher` and the initial counter block.
+    #[inline]
+    pub fn new(cipher: C, initial_block: &Block<C>) -> Self {
+        Self {
+            cipher,
+            initial_block: initial_block.clone(),
+            index: 0,
+        }
+    }
+
+    /// Index of the next keystream block returned by the iterator.
+    #[inline]
+    pub fn position(&self) -> u64 {
+        self.index
+    }
+
+    /// Seek to the keystream block with index `block_index`.
+    #[inline]
+    pub fn seek(&mut self, block_index: u64) {
+        self.index = block_index;
+    }
+
+    /// Compute the counter block for the keystream block `index`.
+    fn counter_block(&self, index: u64) -> Block<C> {
+        let mut block = self.initial_block.clone();
+        let mut carry = u128::from(index);
+        for b in block.iter_mut().rev() {
+            if carry == 0 {
+                break;
+            }
+            let sum = u128::from(*b) + (carry & 0xFF);
+            *b = sum as u8;
+            carry = (carry >> 8) + (sum >> 8);
+        }
+        block
+    }
+}
+
+impl<C: BlockCipherEncrypt> Iterator for BlockKeystream<C> {
+    type Item = Block<C>;
+
+    /// Returns `None` once the keystream position reaches `u64::MAX`.
+    #[inline]
+    fn next(&mut self) -> Option<Block<C>> {
+        let next_index = self.index.checked_add(1)?;
+        let mut block = self.counter_block(self.index);
+        self.cipher.encrypt_block(&mut block);
+        self.index = next_index;
+        Some(block)
+    }
+}
+
+impl<C: BlockCipherEncrypt> fmt::Debug for BlockKeystream<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockKeystream")
+            .field("index", &self.index)
+            .finish_non_exhaustive()
+    }
+}
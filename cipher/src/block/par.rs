@@ -0,0 +1,55 @@
+//! Multi-threaded processing of independent blocks using [`rayon`].
+//!
+//! Methods in this module process every block independently, which makes
+//! them equivalent to the ECB "mode". They are only valid for operations
+//! where blocks do not depend on each other, e.g. when each block is
+//! a separate unit of data (like disk sectors processed with a tweak
+//! precomputed by the caller). They MUST NOT be used to implement chaining
+//! modes of operation such as CBC.
+
+use super::{BlockCipherDecrypt, BlockCipherEncrypt};
+use crypto_common::Block;
+use rayon::{iter::ParallelIterator, slice::ParallelSliceMut};
+
+/// Number of blocks processed by a single task.
+const CHUNK_BLOCKS: usize = 1024;
+
+/// Extension trait for processing blocks across threads with
+/// [`BlockCipherEncrypt`].
+///
+/// This trait is blanket-implemented for all [`BlockCipherEncrypt`] types
+/// which are [`Sync`].
+pub trait BlockCipherEncryptPar: BlockCipherEncrypt + Sync {
+    /// Encrypt blocks in-place across multiple threads.
+    ///
+    /// The result is identical to [`BlockCipherEncrypt::encrypt_blocks`].
+    /// See the [module-level documentation][self] for restrictions on use.
+    #[inline]
+    fn encrypt_blocks_par(&self, blocks: &mut [Block<Self>]) {
+        blocks
+            .par_chunks_mut(CHUNK_BLOCKS)
+            .for_each(|chunk| self.encrypt_blocks(chunk));
+    }
+}
+
+impl<T: BlockCipherEncrypt + Sync> BlockCipherEncryptPar for T {}
+
+/// Extension trait for processing blocks across threads with
+/// [`BlockCipherDecrypt`].
+///
+/// This trait is blanket-implemented for all [`BlockCipherDecrypt`] types
+/// which are [`Sync`].
+pub trait BlockCipherDecryptPar: BlockCipherDecrypt + Sync {
+    /// Decrypt blocks in-place across multiple threads.
+    ///
+    /// The result is identical to [`BlockCipherDecrypt::decrypt_blocks`].
+    /// See the [module-level documentation][self] for restrictions on use.
+    #[inline]
+    fn decrypt_blocks_par(&self, blocks: &mut [Block<Self>]) {
+        blocks
+            .par_chunks_mut(CHUNK_BLOCKS)
+            .for_each(|chunk| self.decrypt_blocks(chunk));
+    }
+}
+
+impl<T: BlockCipherDecrypt + Sync> BlockCipherDecryptPar for T {}
@@ -1,2 +1,11 @@
+//! Development-related functionality
+
 mod block;
+#[cfg(feature = "rand_core")]
+mod roundtrip;
 mod stream;
+mod timing;
+
+#[cfg(feature = "rand_core")]
+pub use roundtrip::roundtrip_test;
+pub use timing::timing_invariance_smoke;
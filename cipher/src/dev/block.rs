@@ -1,5 +1,3 @@
-//! Development-related functionality
-
 /// Define block cipher test
 #[macro_export]
 macro_rules! block_cipher_test {
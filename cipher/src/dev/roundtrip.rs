@@ -0,0 +1,47 @@
+use crate::{Block, BlockCipherDecrypt, BlockCipherEncrypt};
+use crypto_common::rand_core::RngCore;
+
+/// Number of random blocks used by [`roundtrip_test`].
+///
+/// Deliberately not a multiple of common parallel block counts, so that
+/// both the parallel and the tail processing paths are exercised.
+const BLOCKS: usize = 37;
+
+/// Check that `cipher` correctly decrypts its own output and that its
+/// multi-block and single-block methods agree, using random blocks.
+///
+/// # Panics
+/// If any of the checks fail.
+pub fn roundtrip_test<C>(cipher: &C, rng: &mut impl RngCore)
+where
+    C: BlockCipherEncrypt + BlockCipherDecrypt,
+{
+    let pt: [Block<C>; BLOCKS] = core::array::from_fn(|_| {
+        let mut block = Block::<C>::default();
+        rng.fill_bytes(&mut block);
+        block
+    });
+
+    let mut blocks = pt.clone();
+    cipher.encrypt_blocks(&mut blocks);
+
+    let mut single = pt.clone();
+    for block in single.iter_mut() {
+        cipher.encrypt_block(block);
+    }
+    assert_eq!(
+        blocks, single,
+        "`encrypt_blocks` and `encrypt_block` results differ"
+    );
+
+    for (i, block) in single.iter_mut().enumerate() {
+        cipher.decrypt_block(block);
+        assert_eq!(*block, pt[i], "block {i} failed to round-trip");
+    }
+
+    cipher.decrypt_blocks(&mut blocks);
+    assert_eq!(
+        blocks, pt,
+        "`decrypt_blocks` and `decrypt_block` results differ"
+    );
+}
@@ -0,0 +1,60 @@
+use crate::{Block, BlockCipherEncrypt};
+
+/// Byte values substituted at every position of the probe block.
+///
+/// Chosen to trigger typical secret-dependent shortcuts: comparisons with
+/// zero or all-ones and branches on the high or low bit.
+const PROBE_BYTES: [u8; 6] = [0x00, 0x01, 0x7F, 0x80, 0xFE, 0xFF];
+
+/// Smoke test for obvious data-dependent behavior of `cipher`.
+///
+/// Encrypts a base block and all blocks which differ from it in a single
+/// byte set to one of several edge-case values. After every encryption
+/// `oracle` is called to observe a property of the encryption which has
+/// just been performed and which should not depend on the block contents,
+/// e.g. the number of branches or table lookups recorded by instrumentation
+/// in a test build of the cipher. The observations for all blocks are
+/// required to be equal, as are the ciphertexts of repeated encryptions of
+/// the same block.
+///
+/// # Limitations
+///
+/// This does not measure timing and can not prove that an implementation
+/// is constant-time. It only catches data-dependent behavior which the
+/// oracle is able to observe, such as a regression introducing an
+/// `if byte == 0` branch into an instrumented code path. Secret-dependent
+/// memory access patterns, compiler-introduced branches and
+/// microarchitectural effects are not detected; use dedicated tools (e.g.
+/// `dudect` or valgrind-based checkers) for those.
+///
+/// # Panics
+/// If the observations differ between blocks or encryption is not
+/// deterministic.
+pub fn timing_invariance_smoke<C: BlockCipherEncrypt>(cipher: &C, mut oracle: impl FnMut() -> u64) {
+    let mut base = Block::<C>::default();
+    for (i, b) in base.iter_mut().enumerate() {
+        *b = (i as u8).wrapping_mul(0x3B).wrapping_add(0x5C);
+    }
+
+    let mut expected = base.clone();
+    cipher.encrypt_block(&mut expected);
+    let reference = oracle();
+
+    let mut repeated = base.clone();
+    cipher.encrypt_block(&mut repeated);
+    assert_eq!(oracle(), reference, "oracle observation is not repeatable");
+    assert_eq!(repeated, expected, "encryption is not deterministic");
+
+    for pos in 0..base.len() {
+        for &val in PROBE_BYTES.iter() {
+            let mut block = base.clone();
+            block[pos] = val;
+            cipher.encrypt_block(&mut block);
+            assert_eq!(
+                oracle(),
+                reference,
+                "oracle observation differs for byte {pos} set to {val:#04x}",
+            );
+        }
+    }
+}
@@ -29,15 +29,23 @@ pub use crypto_common::rand_core;
 pub use inout;
 #[cfg(feature = "block-padding")]
 pub use inout::block_padding;
+#[cfg(feature = "subtle")]
+pub use subtle;
 #[cfg(feature = "zeroize")]
 pub use zeroize;
 
 pub mod block;
 #[cfg(feature = "dev")]
-mod dev;
+pub mod dev;
+pub mod pad;
+#[cfg(feature = "zeroize")]
+mod secure;
 pub mod stream;
+pub mod tweak;
 
 pub use block::*;
+#[cfg(feature = "zeroize")]
+pub use secure::SecureCipher;
 pub use stream::*;
 
 pub use crypto_common::{
@@ -47,3 +55,60 @@ pub use crypto_common::{
     KeyInit, KeyIvInit, KeySizeUser, ParBlocks, ParBlocksSizeUser,
 };
 pub use inout::{InOut, InOutBuf};
+
+/// Compare two keys of `T` in constant time.
+///
+/// This is useful e.g. to check that a rotated key differs from the old one
+/// without leaking information about the key bytes through timing.
+#[cfg(feature = "subtle")]
+#[inline]
+pub fn keys_ct_eq<T: KeySizeUser>(a: &Key<T>, b: &Key<T>) -> subtle::Choice {
+    use subtle::ConstantTimeEq;
+    a.as_slice().ct_eq(b.as_slice())
+}
+
+/// Compute the CMAC subkeys `K1` and `K2` for the given block cipher as
+/// specified in [NIST SP 800-38B] and [RFC 4493].
+///
+/// With `L` being the encryption of the all-zero block, the subkeys are
+/// `K1 = dbl(L)` and `K2 = dbl(K1)`, where `dbl` is doubling in GF(2^n):
+/// the block is interpreted as a big-endian integer and shifted left by one
+/// bit, and if the most significant bit was set before the shift, the last
+/// byte is XORed with the constant `Rb`, which is `0x87` for 128-bit
+/// blocks and `0x1B` for 64-bit blocks. Doubling is performed in constant
+/// time.
+///
+/// Only 64-bit and 128-bit block sizes are supported; other block sizes
+/// result in a compile-time error.
+///
+/// [NIST SP 800-38B]: https://doi.org/10.6028/NIST.SP.800-38B
+/// [RFC 4493]: https://www.rfc-editor.org/rfc/rfc4493#section-2.3
+pub fn cmac_subkeys<C: BlockCipherEncrypt>(cipher: &C) -> (Block<C>, Block<C>) {
+    use typenum::Unsigned;
+
+    let rb = const {
+        match C::BlockSize::USIZE {
+            8 => 0x1B,
+            16 => 0x87,
+            _ => panic!("CMAC is only defined for 64-bit and 128-bit block sizes"),
+        }
+    };
+
+    let dbl = |block: &Block<C>| -> Block<C> {
+        let mut res = Block::<C>::default();
+        let mut carry = 0u8;
+        for (r, b) in res.iter_mut().zip(block.iter()).rev() {
+            *r = (b << 1) | carry;
+            carry = b >> 7;
+        }
+        let last = res.len() - 1;
+        res[last] ^= rb & 0u8.wrapping_sub(carry);
+        res
+    };
+
+    let mut l = Block::<C>::default();
+    cipher.encrypt_block(&mut l);
+    let k1 = dbl(&l);
+    let k2 = dbl(&k1);
+    (k1, k2)
+}
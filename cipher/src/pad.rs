@@ -0,0 +1,90 @@
+//! Standalone PKCS#7 padding helpers.
+//!
+//! These functions operate on plain byte slices and do not require the
+//! `block-padding` feature. The block size is passed at runtime, e.g. as
+//! `C::BlockSize::USIZE` for a block cipher `C`.
+
+use core::fmt;
+
+/// Pad the message stored in the first `msg_len` bytes of `buf` using PKCS#7
+/// with the given block size.
+///
+/// Returns length of the padded message, which is always a non-zero multiple
+/// of `block_size`. A message whose length is already a multiple of
+/// `block_size` (including an empty one) is padded with a full block.
+///
+/// Returns [`PadError`] if `block_size` is not in the `1..=255` range,
+/// if `msg_len` is bigger than length of `buf`, or if `buf` is too small
+/// to store the padded message.
+pub fn pkcs7_pad(buf: &mut [u8], msg_len: usize, block_size: usize) -> Result<usize, PadError> {
+    let pad_len = u8::try_from(block_size)
+        .ok()
+        .filter(|&bs| bs != 0)
+        .map(|bs| bs - (msg_len % block_size) as u8)
+        .ok_or(PadError)?;
+    let padded_len = msg_len.checked_add(pad_len.into()).ok_or(PadError)?;
+    buf.get_mut(msg_len..padded_len)
+        .ok_or(PadError)?
+        .fill(pad_len);
+    Ok(padded_len)
+}
+
+/// Remove PKCS#7 padding with the given block size from `buf`, returning
+/// the unpadded message.
+///
+/// Returns [`UnpadError`] if `block_size` is not in the `1..=255` range,
+/// if length of `buf` is not a non-zero multiple of `block_size`, or if
+/// the padding is malformed.
+///
+/// # Constant-time considerations
+///
+/// Padding bytes are checked without data-dependent branches or early
+/// returns, so the time spent on checking does not depend on the position
+/// of a malformed byte. However, the result itself reveals whether padding
+/// was valid: if unpadding of unauthenticated decrypted data (e.g. in the
+/// CBC mode) is observable by an attacker, this enables padding oracle
+/// attacks. Authenticate ciphertexts before decrypting and unpadding them.
+pub fn pkcs7_unpad(buf: &[u8], block_size: usize) -> Result<&[u8], UnpadError> {
+    if block_size == 0 || block_size > 255 || buf.is_empty() || buf.len() % block_size != 0 {
+        return Err(UnpadError);
+    }
+    let last_block = &buf[buf.len() - block_size..];
+    let pad_len = last_block[block_size - 1];
+
+    // Non-zero if `pad_len` is zero or bigger than the block size
+    let mut bad = u8::from(pad_len == 0) | u8::from(usize::from(pad_len) > block_size);
+    for (i, &b) in last_block.iter().rev().enumerate() {
+        // Mask is `0xFF` for bytes which belong to the padding
+        let in_pad = 0u8.wrapping_sub(u8::from(i < usize::from(pad_len)));
+        bad |= in_pad & (b ^ pad_len);
+    }
+
+    if bad != 0 {
+        return Err(UnpadError);
+    }
+    Ok(&buf[..buf.len() - usize::from(pad_len)])
+}
+
+/// Error returned by [`pkcs7_pad`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PadError;
+
+impl fmt::Display for PadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Padding error")
+    }
+}
+
+impl core::error::Error for PadError {}
+
+/// Error returned by [`pkcs7_unpad`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UnpadError;
+
+impl fmt::Display for UnpadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Unpad error")
+    }
+}
+
+impl core::error::Error for UnpadError {}
@@ -0,0 +1,78 @@
+//! Wrapper which zeroizes ciphers on drop.
+
+use crate::{
+    BlockCipherDecClosure, BlockCipherDecrypt, BlockCipherEncClosure, BlockCipherEncrypt,
+    BlockSizeUser,
+};
+use core::fmt;
+use crypto_common::{InnerInit, InnerUser};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Wrapper around a cipher which zeroizes it on drop, for ciphers which
+/// implement [`Zeroize`] but not [`ZeroizeOnDrop`].
+///
+/// Block cipher traits and [`KeyInit`][crate::KeyInit] are forwarded to the
+/// wrapped cipher.
+///
+/// Only the memory of the wrapped value itself (e.g. an expanded key
+/// schedule stored inline) is guaranteed to be wiped, as far as the
+/// cipher's [`Zeroize`] implementation covers it. Copies made earlier, e.g.
+/// by moving the cipher before wrapping it, are not affected.
+pub struct SecureCipher<C: Zeroize>(C);
+
+impl<C: Zeroize> SecureCipher<C> {
+    /// Wrap the given cipher.
+    #[inline]
+    pub fn new(cipher: C) -> Self {
+        Self(cipher)
+    }
+
+    /// Borrow the wrapped cipher.
+    #[inline]
+    pub fn inner(&self) -> &C {
+        &self.0
+    }
+}
+
+impl<C: Zeroize> InnerUser for SecureCipher<C> {
+    type Inner = C;
+}
+
+impl<C: Zeroize> InnerInit for SecureCipher<C> {
+    #[inline]
+    fn inner_init(inner: C) -> Self {
+        Self(inner)
+    }
+}
+
+impl<C: Zeroize + BlockSizeUser> BlockSizeUser for SecureCipher<C> {
+    type BlockSize = C::BlockSize;
+}
+
+impl<C: Zeroize + BlockCipherEncrypt> BlockCipherEncrypt for SecureCipher<C> {
+    #[inline]
+    fn encrypt_with_backend(&self, f: impl BlockCipherEncClosure<BlockSize = Self::BlockSize>) {
+        self.0.encrypt_with_backend(f);
+    }
+}
+
+impl<C: Zeroize + BlockCipherDecrypt> BlockCipherDecrypt for SecureCipher<C> {
+    #[inline]
+    fn decrypt_with_backend(&self, f: impl BlockCipherDecClosure<BlockSize = Self::BlockSize>) {
+        self.0.decrypt_with_backend(f);
+    }
+}
+
+impl<C: Zeroize> fmt::Debug for SecureCipher<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SecureCipher").finish_non_exhaustive()
+    }
+}
+
+impl<C: Zeroize> Drop for SecureCipher<C> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<C: Zeroize> ZeroizeOnDrop for SecureCipher<C> {}
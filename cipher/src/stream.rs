@@ -12,8 +12,8 @@ mod errors;
 mod wrapper;
 
 pub use core_api::{
-    StreamCipherBackend, StreamCipherClosure, StreamCipherCore, StreamCipherCounter,
-    StreamCipherSeekCore,
+    InnerIvCounterInit, StreamCipherBackend, StreamCipherClosure, StreamCipherCore,
+    StreamCipherCounter, StreamCipherSeekCore,
 };
 pub use errors::{OverflowError, StreamCipherError};
 pub use wrapper::StreamCipherCoreWrapper;
@@ -148,6 +148,38 @@ pub trait StreamCipher {
             .map_err(|_| StreamCipherError)
             .and_then(|buf| self.try_apply_keystream_inout(buf))
     }
+
+    /// Return number of keystream bytes which can be processed before
+    /// the end of the keystream is reached.
+    ///
+    /// Data no longer than the returned value can be safely passed to
+    /// [`try_apply_keystream`][StreamCipher::try_apply_keystream], which allows
+    /// callers to split their work instead of handling [`StreamCipherError`].
+    ///
+    /// Returns `None` if the remaining capacity is unknown or effectively
+    /// unbounded (e.g. it does not fit into `u128`). The default
+    /// implementation always returns `None`.
+    #[inline]
+    fn remaining_capacity(&self) -> Option<u128> {
+        None
+    }
+
+    /// Write raw keystream into `buf` and advance the keystream position.
+    ///
+    /// This is equivalent to applying keystream to an all-zero buffer.
+    ///
+    /// Returns [`StreamCipherError`] if end of the keystream will be reached
+    /// with the given buffer length. In this case `buf` is zeroed.
+    ///
+    /// # ⚠️ Security Warning: Hazmat!
+    ///
+    /// Keystream MUST NOT be reused. Anyone who learns it can decrypt any data
+    /// encrypted at the same keystream position.
+    #[inline]
+    fn write_keystream(&mut self, buf: &mut [u8]) -> Result<(), StreamCipherError> {
+        buf.fill(0);
+        self.try_apply_keystream(buf)
+    }
 }
 
 /// Trait for seekable stream ciphers.
@@ -175,6 +207,24 @@ pub trait StreamCipherSeek {
         self.try_current_pos().unwrap()
     }
 
+    /// Try to get current keystream position as a byte offset.
+    ///
+    /// Shorthand for `try_current_pos::<u64>()`. Returns [`OverflowError`]
+    /// if position can not be represented by `u64`.
+    fn try_position(&self) -> Result<u64, OverflowError> {
+        self.try_current_pos()
+    }
+
+    /// Get current keystream position as a byte offset.
+    ///
+    /// Shorthand for `current_pos::<u64>()`.
+    ///
+    /// # Panics
+    /// If position can not be represented by `u64`
+    fn position(&self) -> u64 {
+        self.current_pos()
+    }
+
     /// Seek to the given position
     ///
     /// # Panics
@@ -184,6 +234,34 @@ pub trait StreamCipherSeek {
     }
 }
 
+/// Extension trait for stream ciphers which support both keystream
+/// application and seeking.
+///
+/// This trait is blanket-implemented for all types which implement
+/// [`StreamCipher`] and [`StreamCipherSeek`].
+pub trait StreamCipherSeekExt: StreamCipher + StreamCipherSeek {
+    /// Seek to the keystream position `pos` and apply keystream to `buf`.
+    ///
+    /// On success the keystream position is left just past the processed
+    /// data, i.e. at `pos + buf.len()`.
+    ///
+    /// Returns [`StreamCipherError`] if `pos` is bigger than keystream length
+    /// or if end of the keystream will be reached with the given data length.
+    /// In the latter case `buf` is not modified, but the keystream position
+    /// is still set to `pos`.
+    #[inline]
+    fn apply_keystream_at<T: SeekNum>(
+        &mut self,
+        pos: T,
+        buf: &mut [u8],
+    ) -> Result<(), StreamCipherError> {
+        self.try_seek(pos)?;
+        self.try_apply_keystream(buf)
+    }
+}
+
+impl<C: StreamCipher + StreamCipherSeek> StreamCipherSeekExt for C {}
+
 impl<C: StreamCipher> StreamCipher for &mut C {
     #[inline]
     fn try_apply_keystream_inout(
@@ -192,6 +270,11 @@ impl<C: StreamCipher> StreamCipher for &mut C {
     ) -> Result<(), StreamCipherError> {
         C::try_apply_keystream_inout(self, buf)
     }
+
+    #[inline]
+    fn remaining_capacity(&self) -> Option<u128> {
+        C::remaining_capacity(self)
+    }
 }
 
 /// Trait implemented for numeric types which can be used with the
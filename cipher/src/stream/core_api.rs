@@ -1,6 +1,8 @@
 use super::StreamCipherError;
-use crate::{array::Array, typenum::Unsigned};
-use crypto_common::{Block, BlockSizeUser, BlockSizes, ParBlocks, ParBlocksSizeUser};
+use crate::{array::Array, block::par_blocks, typenum::Unsigned};
+use crypto_common::{
+    Block, BlockSizeUser, BlockSizes, InnerIvInit, Iv, ParBlocks, ParBlocksSizeUser,
+};
 use inout::{InOut, InOutBuf};
 
 /// Trait implemented by stream cipher backends.
@@ -179,6 +181,31 @@ pub trait StreamCipherSeekCore: StreamCipherCore {
     fn set_block_pos(&mut self, pos: Self::Counter);
 }
 
+/// Stream cipher cores which can be initialized from a block cipher (or
+/// another inner type), IV/nonce, and an initial block counter.
+///
+/// This trait is blanket-implemented for all types which implement
+/// [`InnerIvInit`] and [`StreamCipherSeekCore`]. [`InnerIvInit::inner_iv_init`]
+/// is equivalent to using this trait with the initial counter equal to zero.
+pub trait InnerIvCounterInit: InnerIvInit + StreamCipherSeekCore {
+    /// Initialize value using `inner`, `iv` array, and `counter`.
+    ///
+    /// `counter` is the block position from which keystream generation starts,
+    /// i.e. the result is equivalent to seeking to block `counter` right after
+    /// initialization. It is NOT a byte offset. The encoding of the counter
+    /// into the cipher state (e.g. its endianness and which bytes of the
+    /// counter block it occupies in CTR flavors) is defined by the cipher
+    /// implementation, not by the caller.
+    #[inline]
+    fn inner_iv_counter_init(inner: Self::Inner, iv: &Iv<Self>, counter: Self::Counter) -> Self {
+        let mut cipher = Self::inner_iv_init(inner, iv);
+        cipher.set_block_pos(counter);
+        cipher
+    }
+}
+
+impl<T: InnerIvInit + StreamCipherSeekCore> InnerIvCounterInit for T {}
+
 macro_rules! impl_counter {
     {$($t:ty )*} => {
         $( impl StreamCipherCounter for $t { } )*
@@ -209,12 +236,17 @@ impl<BS: BlockSizes> BlockSizeUser for WriteBlocksCtx<'_, BS> {
 impl<BS: BlockSizes> StreamCipherClosure for WriteBlocksCtx<'_, BS> {
     #[inline(always)]
     fn call<B: StreamCipherBackend<BlockSize = BS>>(self, backend: &mut B) {
-        if B::ParBlocksSize::USIZE > 1 {
+        let pb = par_blocks::<B>();
+        if pb > 1 && pb == B::ParBlocksSize::USIZE {
             let (chunks, tail) = Array::slice_as_chunks_mut(self.blocks);
             for chunk in chunks {
                 backend.gen_par_ks_blocks(chunk);
             }
             backend.gen_tail_blocks(tail);
+        } else if pb > 1 {
+            for chunk in self.blocks.chunks_mut(pb) {
+                backend.gen_tail_blocks(chunk);
+            }
         } else {
             for block in self.blocks {
                 backend.gen_ks_block(block);
@@ -252,7 +284,8 @@ impl<BS: BlockSizes> StreamCipherClosure for ApplyBlocksCtx<'_, '_, BS> {
     #[inline(always)]
     #[allow(clippy::needless_range_loop)]
     fn call<B: StreamCipherBackend<BlockSize = BS>>(self, backend: &mut B) {
-        if B::ParBlocksSize::USIZE > 1 {
+        let pb = par_blocks::<B>();
+        if pb > 1 && pb == B::ParBlocksSize::USIZE {
             let (chunks, mut tail) = self.blocks.into_chunks::<B::ParBlocksSize>();
             for mut chunk in chunks {
                 let mut tmp = Default::default();
@@ -266,6 +299,19 @@ impl<BS: BlockSizes> StreamCipherClosure for ApplyBlocksCtx<'_, '_, BS> {
             for i in 0..n {
                 tail.get(i).xor_in2out(&ks[i]);
             }
+        } else if pb > 1 {
+            let mut blocks = self.blocks;
+            let mut buf = Array::<_, B::ParBlocksSize>::default();
+            while !blocks.is_empty() {
+                let n = pb.min(blocks.len());
+                let (mut chunk, rest) = blocks.split_at(n);
+                let ks = &mut buf[..n];
+                backend.gen_tail_blocks(ks);
+                for i in 0..n {
+                    chunk.get(i).xor_in2out(&ks[i]);
+                }
+                blocks = rest;
+            }
         } else {
             for mut block in self.blocks {
                 let mut t = Default::default();
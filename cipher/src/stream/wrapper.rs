@@ -170,6 +170,14 @@ impl<T: StreamCipherCore> StreamCipher for StreamCipherCoreWrapper<T> {
 
         Ok(())
     }
+
+    #[inline]
+    fn remaining_capacity(&self) -> Option<u128> {
+        let blocks = u128::try_from(self.core.remaining_blocks()?).ok()?;
+        blocks
+            .checked_mul(T::BlockSize::U8.into())?
+            .checked_add(self.remaining().into())
+    }
 }
 
 impl<T: StreamCipherSeekCore> StreamCipherSeek for StreamCipherCoreWrapper<T> {
@@ -0,0 +1,56 @@
+//! Helpers for tweakable block cipher modes used for sector-based
+//! (e.g. disk) encryption.
+
+use crate::block::BlockCipherEncrypt;
+use crypto_common::{Block, InvalidLength};
+
+/// Tweak used by a tweakable mode built over the block cipher `C`.
+///
+/// The tweak has the same size as the cipher block.
+pub type Tweak<C> = Block<C>;
+
+/// Compute the initial tweak for the data unit (sector) with index `sector`.
+///
+/// This follows the XTS convention (IEEE 1619): the sector number is encoded
+/// as a little-endian integer, zero-padded to the block size, and encrypted
+/// with `tweak_cipher` initialized with the tweak key (i.e. the second half
+/// of the XTS key). Tweaks for subsequent blocks inside the sector are then
+/// derived by the mode by multiplying this value by the primitive element in
+/// GF(2^128).
+///
+/// # Panics
+/// If the block size of `C` is smaller than 8 bytes.
+#[inline]
+pub fn tweak_for_sector<C: BlockCipherEncrypt>(tweak_cipher: &C, sector: u64) -> Tweak<C> {
+    let mut tweak = Tweak::<C>::default();
+    tweak[..8].copy_from_slice(&sector.to_le_bytes());
+    tweak_cipher.encrypt_block(&mut tweak);
+    tweak
+}
+
+/// Object-safe interface of a tweakable block cipher.
+///
+/// Block and tweak sizes are exposed as runtime accessors and data is passed
+/// as byte slices, which allows to select a cipher at runtime and store it as
+/// `Box<dyn DynTweakCipher>`.
+pub trait DynTweakCipher {
+    /// Size of the cipher block in bytes.
+    fn block_size(&self) -> usize;
+
+    /// Size of the tweak in bytes.
+    fn tweak_size(&self) -> usize;
+
+    /// Encrypt `block` in-place using the given `tweak`.
+    ///
+    /// Returns [`InvalidLength`] without modifying `block` if length of `tweak`
+    /// is not equal to [`tweak_size`][Self::tweak_size] or length of `block`
+    /// is not equal to [`block_size`][Self::block_size].
+    fn encrypt_block_dyn(&self, tweak: &[u8], block: &mut [u8]) -> Result<(), InvalidLength>;
+
+    /// Decrypt `block` in-place using the given `tweak`.
+    ///
+    /// Returns [`InvalidLength`] without modifying `block` if length of `tweak`
+    /// is not equal to [`tweak_size`][Self::tweak_size] or length of `block`
+    /// is not equal to [`block_size`][Self::block_size].
+    fn decrypt_block_dyn(&self, tweak: &[u8], block: &mut [u8]) -> Result<(), InvalidLength>;
+}
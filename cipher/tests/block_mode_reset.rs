@@ -0,0 +1,147 @@
+//! Tests for processing several messages with one block mode instance.
+
+#![cfg(feature = "block-padding")]
+
+use cipher::{
+    block_padding::Pkcs7,
+    consts::{U1, U16},
+    Block, BlockModeDecBackend, BlockModeDecClosure, BlockModeDecrypt, BlockModeEncBackend,
+    BlockModeEncClosure, BlockModeEncrypt, BlockModeReset, BlockSizeUser, InOut, ParBlocksSizeUser,
+};
+
+const KEY: u128 = 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210;
+const IV: u128 = 0x0F1E_2D3C_4B5A_6978_8796_A5B4_C3D2_E1F0;
+
+/// Toy CBC mode over a block "cipher" which XORs a key into the block and
+/// rotates it.
+struct ToyCbc {
+    key: u128,
+    iv: u128,
+    state: u128,
+}
+
+impl ToyCbc {
+    fn new(key: u128, iv: u128) -> Self {
+        Self { key, iv, state: iv }
+    }
+}
+
+impl BlockSizeUser for ToyCbc {
+    type BlockSize = U16;
+}
+
+impl ParBlocksSizeUser for ToyCbc {
+    type ParBlocksSize = U1;
+}
+
+impl BlockModeEncBackend for ToyCbc {
+    fn encrypt_block(&mut self, mut block: InOut<'_, '_, Block<Self>>) {
+        let x = u128::from_le_bytes((*block.get_in()).into());
+        self.state = (x ^ self.state ^ self.key).rotate_left(17);
+        *block.get_out() = self.state.to_le_bytes().into();
+    }
+}
+
+impl BlockModeDecBackend for ToyCbc {
+    fn decrypt_block(&mut self, mut block: InOut<'_, '_, Block<Self>>) {
+        let y = u128::from_le_bytes((*block.get_in()).into());
+        let x = y.rotate_right(17) ^ self.key ^ self.state;
+        self.state = y;
+        *block.get_out() = x.to_le_bytes().into();
+    }
+}
+
+impl BlockModeEncrypt for ToyCbc {
+    fn encrypt_with_backend(&mut self, f: impl BlockModeEncClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+impl BlockModeDecrypt for ToyCbc {
+    fn decrypt_with_backend(&mut self, f: impl BlockModeDecClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+impl BlockModeReset for ToyCbc {
+    fn reset(&mut self) {
+        self.state = self.iv;
+    }
+}
+
+const MSGS: [&[u8]; 4] = [
+    b"",
+    b"first message",
+    b"second message, which spans several blocks",
+    b"exactly 16 bytes",
+];
+
+#[test]
+fn encrypt_padded_reset_matches_fresh_instances() {
+    let mut mode = ToyCbc::new(KEY, IV);
+    for msg in MSGS {
+        let mut expected = [0u8; 64];
+        expected[..msg.len()].copy_from_slice(msg);
+        let expected = ToyCbc::new(KEY, IV)
+            .encrypt_padded::<Pkcs7>(&mut expected, msg.len())
+            .unwrap();
+
+        let mut buf = [0u8; 64];
+        buf[..msg.len()].copy_from_slice(msg);
+        let ct = mode
+            .encrypt_padded_reset::<Pkcs7>(&mut buf, msg.len())
+            .unwrap();
+        assert_eq!(ct, expected);
+    }
+}
+
+#[test]
+fn decrypt_padded_reset_matches_fresh_instances() {
+    let mut mode = ToyCbc::new(KEY, IV);
+    for msg in MSGS {
+        let mut buf = [0u8; 64];
+        buf[..msg.len()].copy_from_slice(msg);
+        let ct_len = ToyCbc::new(KEY, IV)
+            .encrypt_padded::<Pkcs7>(&mut buf, msg.len())
+            .unwrap()
+            .len();
+
+        let pt = mode
+            .decrypt_padded_reset::<Pkcs7>(&mut buf[..ct_len])
+            .unwrap();
+        assert_eq!(pt, msg);
+    }
+}
+
+#[test]
+fn reset_after_error() {
+    let msg = MSGS[2];
+    let mut expected = [0u8; 64];
+    expected[..msg.len()].copy_from_slice(msg);
+    let expected = ToyCbc::new(KEY, IV)
+        .encrypt_padded::<Pkcs7>(&mut expected, msg.len())
+        .unwrap();
+
+    let mut mode = ToyCbc::new(KEY, IV);
+    // Input length is not multiple of the block size
+    let mut buf = [0u8; 40];
+    assert!(mode.decrypt_padded_reset::<Pkcs7>(&mut buf[..33]).is_err());
+    // Malformed padding, all blocks are processed before the error is detected
+    let mut blocks = [Block::<ToyCbc>::default(); 2];
+    ToyCbc::new(KEY, IV).encrypt_blocks(&mut blocks);
+    let mut buf = [0u8; 32];
+    buf[..16].copy_from_slice(&blocks[0]);
+    buf[16..].copy_from_slice(&blocks[1]);
+    assert!(mode.decrypt_padded_reset::<Pkcs7>(&mut buf).is_err());
+    // Not enough space for padding
+    assert!(mode
+        .encrypt_padded_reset::<Pkcs7>(&mut buf[..16], 16)
+        .is_err());
+
+    let mut buf = [0u8; 64];
+    buf[..msg.len()].copy_from_slice(msg);
+    let ct = mode
+        .encrypt_padded_reset::<Pkcs7>(&mut buf, msg.len())
+        .unwrap();
+    assert_eq!(ct, expected);
+}
@@ -0,0 +1,43 @@
+//! Tests for multi-threaded block processing.
+
+#![cfg(feature = "rayon")]
+
+mod common;
+
+use cipher::{
+    block::par::{BlockCipherDecryptPar, BlockCipherEncryptPar},
+    Block, BlockCipherEncrypt,
+};
+use common::ToyBlockCipher;
+
+fn test_blocks(n: usize) -> Vec<Block<ToyBlockCipher>> {
+    (0..n)
+        .map(|i| {
+            (i as u128)
+                .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+                .to_le_bytes()
+                .into()
+        })
+        .collect()
+}
+
+#[test]
+fn par_matches_sequential() {
+    let cipher = ToyBlockCipher {
+        key: 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+    };
+
+    // Large enough to be split into several chunks, with a partial last chunk
+    for n in [0, 1, 1023, 1024, 10_000] {
+        let pt = test_blocks(n);
+
+        let mut expected = pt.clone();
+        cipher.encrypt_blocks(&mut expected);
+        let mut blocks = pt.clone();
+        cipher.encrypt_blocks_par(&mut blocks);
+        assert_eq!(blocks, expected);
+
+        cipher.decrypt_blocks_par(&mut blocks);
+        assert_eq!(blocks, pt);
+    }
+}
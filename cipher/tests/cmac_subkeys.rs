@@ -0,0 +1,105 @@
+//! Tests for CMAC subkey generation.
+
+use cipher::{
+    cmac_subkeys,
+    consts::{U1, U16, U8},
+    Block, BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, InOut,
+    ParBlocksSizeUser,
+};
+
+/// Mock block cipher which maps every block to a fixed value, so that it
+/// matches a real cipher on the zero block.
+macro_rules! mock_cipher {
+    ($name:ident, $size:ty, $l:expr) => {
+        struct $name;
+
+        impl BlockSizeUser for $name {
+            type BlockSize = $size;
+        }
+
+        impl ParBlocksSizeUser for $name {
+            type ParBlocksSize = U1;
+        }
+
+        impl BlockCipherEncBackend for $name {
+            fn encrypt_block(&self, mut block: InOut<'_, '_, Block<Self>>) {
+                block.get_out().copy_from_slice(&$l);
+            }
+        }
+
+        impl BlockCipherEncrypt for $name {
+            fn encrypt_with_backend(
+                &self,
+                f: impl BlockCipherEncClosure<BlockSize = Self::BlockSize>,
+            ) {
+                f.call(self);
+            }
+        }
+    };
+}
+
+// AES-128 encryption of the zero block under the RFC 4493 example key
+// 2b7e1516 28aed2a6 abf71588 09cf4f3c.
+mock_cipher!(
+    Aes128ZeroBlock,
+    U16,
+    [
+        0x7d, 0xf7, 0x6b, 0x0c, 0x1a, 0xb8, 0x99, 0xb3, 0x3e, 0x42, 0xf0, 0x47, 0xb9, 0x1b, 0x54,
+        0x6f,
+    ]
+);
+
+// 64-bit block with the most significant bit set in `L` and `K1`.
+mock_cipher!(
+    Block64MsbSet,
+    U8,
+    [0xc0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]
+);
+
+// 64-bit block with the most significant bit clear in `L` and `K1`.
+mock_cipher!(
+    Block64MsbClear,
+    U8,
+    [0x12, 0x34, 0x56, 0x78, 0x9a, 0xbc, 0xde, 0xf0]
+);
+
+#[test]
+fn rfc4493_aes128_subkeys() {
+    let (k1, k2) = cmac_subkeys(&Aes128ZeroBlock);
+    assert_eq!(
+        k1.as_slice(),
+        [
+            0xfb, 0xee, 0xd6, 0x18, 0x35, 0x71, 0x33, 0x66, 0x7c, 0x85, 0xe0, 0x8f, 0x72, 0x36,
+            0xa8, 0xde,
+        ]
+    );
+    assert_eq!(
+        k2.as_slice(),
+        [
+            0xf7, 0xdd, 0xac, 0x30, 0x6a, 0xe2, 0x66, 0xcc, 0xf9, 0x0b, 0xc1, 0x1e, 0xe4, 0x6d,
+            0x51, 0x3b,
+        ]
+    );
+}
+
+#[test]
+fn block64_subkeys_with_reduction() {
+    let (k1, k2) = cmac_subkeys(&Block64MsbSet);
+    // L << 1 = 80..02, reduced with Rb = 0x1B
+    assert_eq!(k1.as_slice(), [0x80, 0, 0, 0, 0, 0, 0, 0x02 ^ 0x1b]);
+    // K1 << 1 = 00..32, reduced with Rb = 0x1B
+    assert_eq!(k2.as_slice(), [0x00, 0, 0, 0, 0, 0, 0, 0x32 ^ 0x1b]);
+}
+
+#[test]
+fn block64_subkeys_without_reduction() {
+    let (k1, k2) = cmac_subkeys(&Block64MsbClear);
+    assert_eq!(
+        k1.as_slice(),
+        0x1234_5678_9abc_def0u64.wrapping_shl(1).to_be_bytes()
+    );
+    assert_eq!(
+        k2.as_slice(),
+        0x1234_5678_9abc_def0u64.wrapping_shl(2).to_be_bytes()
+    );
+}
@@ -0,0 +1,64 @@
+//! Toy block cipher shared by the integration tests.
+
+#![allow(dead_code)]
+
+use cipher::{
+    consts::{U1, U16},
+    Block, BlockCipherDecBackend, BlockCipherDecClosure, BlockCipherDecrypt, BlockCipherEncBackend,
+    BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, InOut, Key, KeyInit, KeySizeUser,
+    ParBlocksSizeUser,
+};
+
+/// Toy block cipher which XORs a key into the block and rotates it.
+#[derive(Clone)]
+pub struct ToyBlockCipher {
+    pub key: u128,
+}
+
+impl KeySizeUser for ToyBlockCipher {
+    type KeySize = U16;
+}
+
+impl KeyInit for ToyBlockCipher {
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            key: u128::from_le_bytes((*key).into()),
+        }
+    }
+}
+
+impl BlockSizeUser for ToyBlockCipher {
+    type BlockSize = U16;
+}
+
+impl ParBlocksSizeUser for ToyBlockCipher {
+    type ParBlocksSize = U1;
+}
+
+impl BlockCipherEncBackend for ToyBlockCipher {
+    fn encrypt_block(&self, mut block: InOut<'_, '_, Block<Self>>) {
+        let x = u128::from_le_bytes((*block.get_in()).into());
+        let y = (x ^ self.key).rotate_left(17);
+        *block.get_out() = y.to_le_bytes().into();
+    }
+}
+
+impl BlockCipherDecBackend for ToyBlockCipher {
+    fn decrypt_block(&self, mut block: InOut<'_, '_, Block<Self>>) {
+        let y = u128::from_le_bytes((*block.get_in()).into());
+        let x = y.rotate_right(17) ^ self.key;
+        *block.get_out() = x.to_le_bytes().into();
+    }
+}
+
+impl BlockCipherEncrypt for ToyBlockCipher {
+    fn encrypt_with_backend(&self, f: impl BlockCipherEncClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+impl BlockCipherDecrypt for ToyBlockCipher {
+    fn decrypt_with_backend(&self, f: impl BlockCipherDecClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
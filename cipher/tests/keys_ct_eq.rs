@@ -0,0 +1,23 @@
+//! Tests for constant-time key comparison.
+
+#![cfg(feature = "subtle")]
+
+use cipher::{consts::U16, keys_ct_eq, Key, KeySizeUser};
+
+struct ToyCipher;
+
+impl KeySizeUser for ToyCipher {
+    type KeySize = U16;
+}
+
+#[test]
+fn keys_ct_eq_identical_and_differing() {
+    let a = Key::<ToyCipher>::from([0x42; 16]);
+    assert!(bool::from(keys_ct_eq::<ToyCipher>(&a, &a.clone())));
+
+    for i in [0, 7, 15] {
+        let mut b = a;
+        b[i] ^= 0x01;
+        assert!(!bool::from(keys_ct_eq::<ToyCipher>(&a, &b)));
+    }
+}
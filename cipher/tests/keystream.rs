@@ -0,0 +1,57 @@
+//! Tests for the counter keystream iterator.
+
+mod common;
+
+use cipher::{block::keystream::BlockKeystream, Block, BlockCipherEncrypt};
+use common::ToyBlockCipher;
+
+const CIPHER: ToyBlockCipher = ToyBlockCipher {
+    key: 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+};
+
+fn encrypt_counter(ctr: u128) -> Block<ToyBlockCipher> {
+    let mut block = ctr.to_be_bytes().into();
+    BlockCipherEncrypt::encrypt_block(&CIPHER, &mut block);
+    block
+}
+
+#[test]
+fn matches_manual_counter_encryption() {
+    // Low bytes close to overflow to exercise carry propagation
+    let iv: u128 = 0x0011_2233_4455_6677_8899_AABB_CCDD_FFFE;
+    let ks = BlockKeystream::new(CIPHER, &iv.to_be_bytes().into());
+    let blocks: Vec<_> = ks.take(4).collect();
+    let expected: Vec<_> = (0..4).map(|i| encrypt_counter(iv + i)).collect();
+    assert_eq!(blocks, expected);
+}
+
+#[test]
+fn counter_wraps_around_block() {
+    let iv = u128::MAX - 1;
+    let ks = BlockKeystream::new(CIPHER, &iv.to_be_bytes().into());
+    let blocks: Vec<_> = ks.take(4).collect();
+    let expected: Vec<_> = (0..4)
+        .map(|i| encrypt_counter(iv.wrapping_add(i)))
+        .collect();
+    assert_eq!(blocks, expected);
+}
+
+#[test]
+fn seek() {
+    let iv: u128 = 0x0123_4567;
+    let mut ks = BlockKeystream::new(CIPHER, &iv.to_be_bytes().into());
+    ks.seek(1000);
+    assert_eq!(ks.position(), 1000);
+    assert_eq!(ks.next(), Some(encrypt_counter(iv + 1000)));
+    assert_eq!(ks.position(), 1001);
+
+    ks.seek(0);
+    assert_eq!(ks.next(), Some(encrypt_counter(iv)));
+
+    ks.seek(u64::MAX - 1);
+    assert_eq!(
+        ks.next(),
+        Some(encrypt_counter(iv + u128::from(u64::MAX - 1)))
+    );
+    assert_eq!(ks.next(), None);
+}
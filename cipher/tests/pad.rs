@@ -0,0 +1,82 @@
+//! Tests for the standalone PKCS#7 padding helpers.
+
+use cipher::pad::{pkcs7_pad, pkcs7_unpad, PadError, UnpadError};
+
+#[test]
+fn pad_unpad_round_trip() {
+    for block_size in [1, 8, 16, 255] {
+        for msg_len in 0..2 * block_size + 1 {
+            let mut buf = [0xAAu8; 1024];
+            let msg: Vec<u8> = (0..msg_len).map(|i| i as u8).collect();
+            buf[..msg_len].copy_from_slice(&msg);
+
+            let n = pkcs7_pad(&mut buf, msg_len, block_size).unwrap();
+            assert_eq!(n % block_size, 0);
+            assert!(n > msg_len && n <= msg_len + block_size);
+            let pad_len = (n - msg_len) as u8;
+            assert!(buf[msg_len..n].iter().all(|&b| b == pad_len));
+
+            assert_eq!(pkcs7_unpad(&buf[..n], block_size).unwrap(), msg);
+        }
+    }
+}
+
+#[test]
+fn pad_known_answer() {
+    let mut buf = [0u8; 16];
+    buf[..5].copy_from_slice(b"hello");
+    assert_eq!(pkcs7_pad(&mut buf, 5, 8), Ok(8));
+    assert_eq!(&buf[..8], b"hello\x03\x03\x03");
+}
+
+#[test]
+fn zero_length_message() {
+    let mut buf = [0u8; 16];
+    assert_eq!(pkcs7_pad(&mut buf, 0, 16), Ok(16));
+    assert_eq!(buf, [16; 16]);
+    assert_eq!(pkcs7_unpad(&buf, 16), Ok(&[][..]));
+
+    assert_eq!(pkcs7_unpad(&[], 16), Err(UnpadError));
+}
+
+#[test]
+fn pad_errors() {
+    let mut buf = [0u8; 16];
+    // Not enough space for the padding
+    assert_eq!(pkcs7_pad(&mut buf, 16, 16), Err(PadError));
+    assert_eq!(pkcs7_pad(&mut buf[..15], 8, 8), Err(PadError));
+    // Message length exceeds the buffer
+    assert_eq!(pkcs7_pad(&mut buf, 17, 8), Err(PadError));
+    // Invalid block sizes
+    assert_eq!(pkcs7_pad(&mut buf, 0, 0), Err(PadError));
+    assert_eq!(pkcs7_pad(&mut [0u8; 512], 0, 256), Err(PadError));
+}
+
+#[test]
+fn corrupted_padding() {
+    let mut buf = [0u8; 16];
+    buf[..5].copy_from_slice(b"hello");
+    let n = pkcs7_pad(&mut buf, 5, 8).unwrap();
+    assert_eq!(pkcs7_unpad(&buf[..n], 8), Ok(&b"hello"[..]));
+
+    // Corrupted padding byte
+    let mut bad = buf;
+    bad[5] = 0x02;
+    assert_eq!(pkcs7_unpad(&bad[..n], 8), Err(UnpadError));
+
+    // Zero padding length
+    let mut bad = buf;
+    bad[7] = 0;
+    assert_eq!(pkcs7_unpad(&bad[..n], 8), Err(UnpadError));
+
+    // Padding length bigger than the block size
+    let mut bad = [9u8; 16];
+    assert_eq!(pkcs7_unpad(&bad[..8], 8), Err(UnpadError));
+    bad[15] = 16;
+    assert_eq!(pkcs7_unpad(&bad, 8), Err(UnpadError));
+
+    // Length is not a multiple of the block size
+    assert_eq!(pkcs7_unpad(&buf[..n - 1], 8), Err(UnpadError));
+    // Invalid block size
+    assert_eq!(pkcs7_unpad(&buf[..n], 0), Err(UnpadError));
+}
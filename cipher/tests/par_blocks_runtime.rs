@@ -0,0 +1,223 @@
+//! Tests for runtime selection of the number of blocks processed in parallel.
+
+mod common;
+
+use cipher::{
+    consts::{U16, U4},
+    Block, BlockCipherEncBackend, BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, InOut,
+    InOutBuf, ParBlocks, ParBlocksSizeUser, StreamCipherBackend, StreamCipherClosure,
+    StreamCipherCore,
+};
+use common::ToyBlockCipher;
+use core::cell::RefCell;
+
+/// [`ToyBlockCipher`] which supports processing of 4 blocks in parallel, but
+/// reports `WIDTH` as the runtime number of parallel blocks. Sizes of
+/// processed chunks are recorded in `calls`.
+struct ParToyCipher<const WIDTH: usize> {
+    inner: ToyBlockCipher,
+    calls: RefCell<Vec<usize>>,
+}
+
+impl<const WIDTH: usize> ParToyCipher<WIDTH> {
+    fn new() -> Self {
+        Self {
+            inner: ToyBlockCipher {
+                key: 0x0123_4567_89AB_CDEF_FEDC_BA98_7654_3210,
+            },
+            calls: RefCell::default(),
+        }
+    }
+}
+
+impl<const WIDTH: usize> BlockSizeUser for ParToyCipher<WIDTH> {
+    type BlockSize = U16;
+}
+
+impl<const WIDTH: usize> ParBlocksSizeUser for ParToyCipher<WIDTH> {
+    type ParBlocksSize = U4;
+
+    fn par_blocks_runtime() -> usize {
+        WIDTH
+    }
+}
+
+impl<const WIDTH: usize> BlockCipherEncBackend for ParToyCipher<WIDTH> {
+    fn encrypt_block(&self, block: InOut<'_, '_, Block<Self>>) {
+        BlockCipherEncBackend::encrypt_block(&self.inner, block);
+    }
+
+    fn encrypt_par_blocks(&self, mut blocks: InOut<'_, '_, ParBlocks<Self>>) {
+        self.calls.borrow_mut().push(4);
+        for i in 0..4 {
+            BlockCipherEncBackend::encrypt_block(self, blocks.get(i));
+        }
+    }
+
+    fn encrypt_tail_blocks(&self, blocks: InOutBuf<'_, '_, Block<Self>>) {
+        assert!(blocks.len() < 4);
+        self.calls.borrow_mut().push(blocks.len());
+        for block in blocks {
+            BlockCipherEncBackend::encrypt_block(self, block);
+        }
+    }
+}
+
+impl<const WIDTH: usize> BlockCipherEncrypt for ParToyCipher<WIDTH> {
+    fn encrypt_with_backend(&self, f: impl BlockCipherEncClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+fn test_blocks(n: usize) -> Vec<Block<ToyBlockCipher>> {
+    (0..n)
+        .map(|i| {
+            (i as u128)
+                .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+                .to_le_bytes()
+                .into()
+        })
+        .collect()
+}
+
+fn encrypt<const WIDTH: usize>(blocks: &mut [Block<ToyBlockCipher>]) -> Vec<usize> {
+    let cipher = ParToyCipher::<WIDTH>::new();
+    cipher.encrypt_blocks(blocks);
+    cipher.calls.into_inner()
+}
+
+#[test]
+fn default_runtime_width() {
+    struct Backend;
+    impl BlockSizeUser for Backend {
+        type BlockSize = U16;
+    }
+    impl ParBlocksSizeUser for Backend {
+        type ParBlocksSize = U4;
+    }
+    assert_eq!(Backend::par_blocks_runtime(), 4);
+}
+
+#[test]
+fn reduced_runtime_width() {
+    let pt = test_blocks(11);
+
+    let mut expected = pt.clone();
+    assert_eq!(encrypt::<4>(&mut expected), [4, 4, 3]);
+
+    let mut blocks = pt.clone();
+    assert_eq!(encrypt::<2>(&mut blocks), [2, 2, 2, 2, 2, 1]);
+    assert_eq!(blocks, expected);
+
+    // Block-by-block processing does not use parallel methods
+    let mut blocks = pt.clone();
+    assert!(encrypt::<1>(&mut blocks).is_empty());
+    assert_eq!(blocks, expected);
+    let mut blocks = pt.clone();
+    assert!(encrypt::<0>(&mut blocks).is_empty());
+    assert_eq!(blocks, expected);
+}
+
+#[test]
+fn runtime_width_is_clamped() {
+    let pt = test_blocks(11);
+
+    let mut expected = pt.clone();
+    encrypt::<4>(&mut expected);
+
+    let mut blocks = pt.clone();
+    assert_eq!(encrypt::<8>(&mut blocks), [4, 4, 3]);
+    assert_eq!(blocks, expected);
+}
+
+/// Toy stream cipher core which produces a counter-based keystream.
+///
+/// It supports generation of 4 blocks in parallel, but reports `WIDTH` as
+/// the runtime number of parallel blocks. Sizes of generated chunks are
+/// recorded in `calls`.
+#[derive(Default)]
+struct ToyStreamCore<const WIDTH: usize> {
+    counter: u32,
+    calls: Vec<usize>,
+}
+
+impl<const WIDTH: usize> BlockSizeUser for ToyStreamCore<WIDTH> {
+    type BlockSize = U4;
+}
+
+impl<const WIDTH: usize> ParBlocksSizeUser for ToyStreamCore<WIDTH> {
+    type ParBlocksSize = U4;
+
+    fn par_blocks_runtime() -> usize {
+        WIDTH
+    }
+}
+
+impl<const WIDTH: usize> StreamCipherBackend for ToyStreamCore<WIDTH> {
+    fn gen_ks_block(&mut self, block: &mut Block<Self>) {
+        let ks = self.counter.wrapping_mul(0x9E37_79B9).rotate_left(5);
+        *block = ks.to_le_bytes().into();
+        self.counter += 1;
+    }
+
+    fn gen_par_ks_blocks(&mut self, blocks: &mut ParBlocks<Self>) {
+        self.calls.push(4);
+        for block in blocks {
+            self.gen_ks_block(block);
+        }
+    }
+
+    fn gen_tail_blocks(&mut self, blocks: &mut [Block<Self>]) {
+        assert!(blocks.len() < 4);
+        self.calls.push(blocks.len());
+        for block in blocks {
+            self.gen_ks_block(block);
+        }
+    }
+}
+
+impl<const WIDTH: usize> StreamCipherCore for ToyStreamCore<WIDTH> {
+    fn remaining_blocks(&self) -> Option<usize> {
+        None
+    }
+
+    fn process_with_backend(&mut self, f: impl StreamCipherClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+/// Write and apply 11 keystream blocks, returning the blocks and the chunk
+/// sizes used for each operation.
+fn keystream<const WIDTH: usize>() -> (Vec<Block<ToyStreamCore<4>>>, Vec<usize>, Vec<usize>) {
+    let mut written = vec![Block::<ToyStreamCore<WIDTH>>::default(); 11];
+    let mut core = ToyStreamCore::<WIDTH>::default();
+    core.write_keystream_blocks(&mut written);
+    let write_calls = core.calls;
+
+    let mut applied = vec![Block::<ToyStreamCore<WIDTH>>::default(); 11];
+    let mut core = ToyStreamCore::<WIDTH>::default();
+    core.apply_keystream_blocks(&mut applied);
+    assert_eq!(written, applied);
+
+    (written, write_calls, core.calls)
+}
+
+#[test]
+fn stream_reduced_runtime_width() {
+    let (expected, write_calls, apply_calls) = keystream::<4>();
+    assert_eq!(write_calls, [4, 4, 3]);
+    assert_eq!(apply_calls, [4, 4, 3]);
+
+    let (blocks, write_calls, apply_calls) = keystream::<2>();
+    assert_eq!(write_calls, [2, 2, 2, 2, 2, 1]);
+    assert_eq!(apply_calls, [2, 2, 2, 2, 2, 1]);
+    assert_eq!(blocks, expected);
+
+    let (blocks, write_calls, apply_calls) = keystream::<1>();
+    assert!(write_calls.is_empty() && apply_calls.is_empty());
+    assert_eq!(blocks, expected);
+
+    let (blocks, write_calls, _) = keystream::<8>();
+    assert_eq!(write_calls, [4, 4, 3]);
+    assert_eq!(blocks, expected);
+}
@@ -0,0 +1,126 @@
+//! Tests for the `dev::roundtrip_test` helper.
+
+#![cfg(all(feature = "dev", feature = "rand_core"))]
+
+use cipher::{
+    consts::{U16, U4},
+    dev::roundtrip_test,
+    rand_core::{Error, RngCore},
+    Block, BlockCipherDecBackend, BlockCipherDecClosure, BlockCipherDecrypt, BlockCipherEncBackend,
+    BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, InOut, ParBlocks, ParBlocksSizeUser,
+};
+
+/// Deterministic xorshift RNG.
+struct MockRng(u64);
+
+impl RngCore for MockRng {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let n = chunk.len();
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes()[..n]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Identity block cipher.
+struct IdentityCipher;
+
+impl BlockSizeUser for IdentityCipher {
+    type BlockSize = U16;
+}
+
+impl ParBlocksSizeUser for IdentityCipher {
+    type ParBlocksSize = U4;
+}
+
+impl BlockCipherEncBackend for IdentityCipher {
+    fn encrypt_block(&self, mut block: InOut<'_, '_, Block<Self>>) {
+        *block.get_out() = *block.get_in();
+    }
+}
+
+impl BlockCipherDecBackend for IdentityCipher {
+    fn decrypt_block(&self, mut block: InOut<'_, '_, Block<Self>>) {
+        *block.get_out() = *block.get_in();
+    }
+}
+
+impl BlockCipherEncrypt for IdentityCipher {
+    fn encrypt_with_backend(&self, f: impl BlockCipherEncClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+impl BlockCipherDecrypt for IdentityCipher {
+    fn decrypt_with_backend(&self, f: impl BlockCipherDecClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+/// Identity block cipher with a broken parallel encryption path.
+struct BrokenParCipher;
+
+impl BlockSizeUser for BrokenParCipher {
+    type BlockSize = U16;
+}
+
+impl ParBlocksSizeUser for BrokenParCipher {
+    type ParBlocksSize = U4;
+}
+
+impl BlockCipherEncBackend for BrokenParCipher {
+    fn encrypt_block(&self, mut block: InOut<'_, '_, Block<Self>>) {
+        *block.get_out() = *block.get_in();
+    }
+
+    fn encrypt_par_blocks(&self, mut blocks: InOut<'_, '_, ParBlocks<Self>>) {
+        let mut out = *blocks.get_in();
+        out.swap(0, 1);
+        *blocks.get_out() = out;
+    }
+}
+
+impl BlockCipherDecBackend for BrokenParCipher {
+    fn decrypt_block(&self, mut block: InOut<'_, '_, Block<Self>>) {
+        *block.get_out() = *block.get_in();
+    }
+}
+
+impl BlockCipherEncrypt for BrokenParCipher {
+    fn encrypt_with_backend(&self, f: impl BlockCipherEncClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+impl BlockCipherDecrypt for BrokenParCipher {
+    fn decrypt_with_backend(&self, f: impl BlockCipherDecClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+#[test]
+fn identity_cipher_passes() {
+    roundtrip_test(&IdentityCipher, &mut MockRng(0x0123_4567_89AB_CDEF));
+}
+
+#[test]
+#[should_panic(expected = "`encrypt_blocks` and `encrypt_block` results differ")]
+fn broken_par_path_detected() {
+    roundtrip_test(&BrokenParCipher, &mut MockRng(0x0123_4567_89AB_CDEF));
+}
@@ -0,0 +1,90 @@
+//! Tests for the `SecureCipher` wrapper.
+
+#![cfg(feature = "zeroize")]
+
+mod common;
+
+use cipher::{
+    consts::U16, zeroize::Zeroize, Block, BlockCipherDecClosure, BlockCipherDecrypt,
+    BlockCipherEncClosure, BlockCipherEncrypt, BlockSizeUser, Key, KeyInit, KeySizeUser,
+    SecureCipher,
+};
+use common::ToyBlockCipher;
+use core::cell::Cell;
+
+std::thread_local! {
+    /// Number of zeroized `ObservedCipher` instances on the current thread.
+    static ZEROIZED: Cell<usize> = const { Cell::new(0) };
+}
+
+/// [`ToyBlockCipher`] which counts how often it was zeroized. It doesn't
+/// zeroize itself on drop.
+struct ObservedCipher(ToyBlockCipher);
+
+impl KeySizeUser for ObservedCipher {
+    type KeySize = U16;
+}
+
+impl KeyInit for ObservedCipher {
+    fn new(key: &Key<Self>) -> Self {
+        Self(ToyBlockCipher::new(key))
+    }
+}
+
+impl Zeroize for ObservedCipher {
+    fn zeroize(&mut self) {
+        self.0.key.zeroize();
+        ZEROIZED.with(|n| n.set(n.get() + 1));
+    }
+}
+
+impl BlockSizeUser for ObservedCipher {
+    type BlockSize = U16;
+}
+
+impl BlockCipherEncrypt for ObservedCipher {
+    fn encrypt_with_backend(&self, f: impl BlockCipherEncClosure<BlockSize = Self::BlockSize>) {
+        self.0.encrypt_with_backend(f);
+    }
+}
+
+impl BlockCipherDecrypt for ObservedCipher {
+    fn decrypt_with_backend(&self, f: impl BlockCipherDecClosure<BlockSize = Self::BlockSize>) {
+        self.0.decrypt_with_backend(f);
+    }
+}
+
+const KEY: [u8; 16] = *b"0123456789abcdef";
+
+fn zeroized() -> usize {
+    ZEROIZED.with(Cell::get)
+}
+
+#[test]
+fn drop_zeroizes() {
+    let before = zeroized();
+    let cipher = SecureCipher::<ObservedCipher>::new_from_slice(&KEY).unwrap();
+    assert_ne!(cipher.inner().0.key, 0);
+    assert_eq!(zeroized(), before);
+
+    drop(cipher);
+    assert_eq!(zeroized(), before + 1);
+}
+
+#[test]
+fn forwards_block_cipher() {
+    let plain = ToyBlockCipher::new(&KEY.into());
+    let secure = SecureCipher::new(ObservedCipher::new(&KEY.into()));
+
+    let pt = Block::<ToyBlockCipher>::from(*b"example block 01");
+    let mut expected = pt;
+    BlockCipherEncrypt::encrypt_block(&plain, &mut expected);
+
+    let mut block = pt;
+    secure.encrypt_block(&mut block);
+    assert_eq!(block, expected);
+    secure.decrypt_block(&mut block);
+    assert_eq!(block, pt);
+
+    assert_eq!(format!("{secure:?}"), "SecureCipher { .. }");
+}
@@ -0,0 +1,217 @@
+//! Tests for the slice-based stream cipher traits.
+
+use cipher::{
+    consts::{U1, U4},
+    crypto_common::InnerUser,
+    Block, BlockSizeUser, InnerIvCounterInit, InnerIvInit, Iv, IvSizeUser, ParBlocksSizeUser,
+    StreamCipher, StreamCipherBackend, StreamCipherClosure, StreamCipherCore,
+    StreamCipherCoreWrapper, StreamCipherSeek, StreamCipherSeekCore, StreamCipherSeekExt,
+};
+
+/// Number of keystream blocks produced by [`ToyCore`] before it runs out.
+const MAX_BLOCKS: u32 = 16;
+
+/// Toy "block cipher" used as the inner type of [`ToyCore`].
+#[derive(Clone, Debug, Default)]
+struct ToyBlockCipher(u32);
+
+/// Toy stream cipher core which produces a keystream derived from the
+/// inner key, nonce, and block counter.
+#[derive(Clone, Debug, Default)]
+struct ToyCore {
+    key: u32,
+    nonce: u32,
+    counter: u32,
+}
+
+impl InnerUser for ToyCore {
+    type Inner = ToyBlockCipher;
+}
+
+impl IvSizeUser for ToyCore {
+    type IvSize = U4;
+}
+
+impl InnerIvInit for ToyCore {
+    fn inner_iv_init(inner: ToyBlockCipher, iv: &Iv<Self>) -> Self {
+        Self {
+            key: inner.0,
+            nonce: u32::from_le_bytes(iv.as_slice().try_into().unwrap()),
+            counter: 0,
+        }
+    }
+}
+
+impl BlockSizeUser for ToyCore {
+    type BlockSize = U4;
+}
+
+impl ParBlocksSizeUser for ToyCore {
+    type ParBlocksSize = U1;
+}
+
+impl StreamCipherBackend for ToyCore {
+    fn gen_ks_block(&mut self, block: &mut Block<Self>) {
+        let ks = self.counter.wrapping_mul(0x9E37_79B9) ^ self.key ^ self.nonce.rotate_left(7);
+        block.copy_from_slice(&ks.to_le_bytes());
+        self.counter += 1;
+    }
+}
+
+impl StreamCipherCore for ToyCore {
+    fn remaining_blocks(&self) -> Option<usize> {
+        Some((MAX_BLOCKS - self.counter) as usize)
+    }
+
+    fn process_with_backend(&mut self, f: impl StreamCipherClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+}
+
+impl StreamCipherSeekCore for ToyCore {
+    type Counter = u32;
+
+    fn get_block_pos(&self) -> u32 {
+        self.counter
+    }
+
+    fn set_block_pos(&mut self, pos: u32) {
+        self.counter = pos;
+    }
+}
+
+type ToyCipher = StreamCipherCoreWrapper<ToyCore>;
+
+#[test]
+fn write_keystream_matches_applying_to_zeros() {
+    let mut expected = [0u8; 37];
+    ToyCipher::default().apply_keystream(&mut expected);
+
+    let mut buf = [0xFFu8; 37];
+    ToyCipher::default().write_keystream(&mut buf).unwrap();
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn write_keystream_advances_position() {
+    let mut expected = [0u8; 20];
+    ToyCipher::default().apply_keystream(&mut expected);
+
+    let mut cipher = ToyCipher::default();
+    let mut buf = [0u8; 20];
+    let (head, tail) = buf.split_at_mut(7);
+    cipher.write_keystream(head).unwrap();
+    cipher.write_keystream(tail).unwrap();
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn write_keystream_exhausted() {
+    let mut cipher = ToyCipher::default();
+    let mut buf = [0xFFu8; 4 * MAX_BLOCKS as usize + 1];
+    assert!(cipher.write_keystream(&mut buf).is_err());
+
+    // Position is not advanced on error
+    let mut buf = [0u8; 4];
+    cipher.write_keystream(&mut buf).unwrap();
+    let mut expected = [0u8; 4];
+    ToyCipher::default().apply_keystream(&mut expected);
+    assert_eq!(buf, expected);
+}
+
+#[test]
+fn apply_keystream_at_matches_seek_and_apply() {
+    for pos in [0usize, 1, 3, 4, 9, 31, 60] {
+        for len in [0usize, 1, 4] {
+            let mut expected = [0x42u8; 4];
+            let mut cipher = ToyCipher::default();
+            cipher.seek(pos);
+            cipher.apply_keystream(&mut expected[..len]);
+
+            let mut buf = [0x42u8; 4];
+            let mut cipher = ToyCipher::default();
+            // Move away from the start to check that the position is overwritten
+            cipher.seek(17u32);
+            cipher.apply_keystream_at(pos, &mut buf[..len]).unwrap();
+            assert_eq!(buf, expected);
+            assert_eq!(cipher.current_pos::<usize>(), pos + len);
+        }
+    }
+}
+
+#[test]
+fn apply_keystream_at_past_end() {
+    let end = 4 * MAX_BLOCKS as usize;
+    let mut cipher = ToyCipher::default();
+    let mut buf = [0x42u8; 4];
+    assert!(cipher.apply_keystream_at(end - 2, &mut buf).is_err());
+    assert_eq!(buf, [0x42; 4]);
+    cipher.apply_keystream_at(end - 4, &mut buf).unwrap();
+    assert_eq!(cipher.current_pos::<usize>(), end);
+}
+
+#[test]
+fn inner_iv_counter_init_matches_seek() {
+    let key = ToyBlockCipher(0x0123_4567);
+    let iv = Iv::<ToyCore>::from([1, 2, 3, 4]);
+
+    let core = ToyCore::inner_iv_counter_init(key.clone(), &iv, 0);
+    let mut buf = [0u8; 32];
+    ToyCipher::from_core(core).apply_keystream(&mut buf);
+    let mut expected = [0u8; 32];
+    ToyCipher::from_core(ToyCore::inner_iv_init(key.clone(), &iv)).apply_keystream(&mut expected);
+    assert_eq!(buf, expected);
+
+    for counter in [1u32, 5, 15] {
+        let core = ToyCore::inner_iv_counter_init(key.clone(), &iv, counter);
+        let mut buf = [0u8; 4];
+        ToyCipher::from_core(core).apply_keystream(&mut buf);
+
+        let mut cipher = ToyCipher::from_core(ToyCore::inner_iv_init(key.clone(), &iv));
+        cipher.seek(4 * counter);
+        let mut expected = [0u8; 4];
+        cipher.apply_keystream(&mut expected);
+        assert_eq!(buf, expected);
+    }
+}
+
+#[test]
+fn remaining_capacity_near_keystream_end() {
+    let end = 4 * MAX_BLOCKS as u128;
+    let mut cipher = ToyCipher::default();
+    assert_eq!(cipher.remaining_capacity(), Some(end));
+
+    cipher.apply_keystream(&mut [0u8; 3]);
+    assert_eq!(cipher.remaining_capacity(), Some(end - 3));
+
+    cipher.seek(end as usize - 5);
+    assert_eq!(cipher.remaining_capacity(), Some(5));
+
+    let mut buf = [0u8; 6];
+    assert!(cipher.try_apply_keystream(&mut buf).is_err());
+    assert_eq!(cipher.remaining_capacity(), Some(5));
+
+    cipher.apply_keystream(&mut buf[..5]);
+    assert_eq!(cipher.remaining_capacity(), Some(0));
+    assert!(cipher.try_apply_keystream(&mut buf[..1]).is_err());
+    cipher.apply_keystream(&mut []);
+}
+
+#[test]
+fn position_after_keystream_chunks() {
+    let mut cipher = ToyCipher::default();
+    assert_eq!(cipher.position(), 0);
+    assert_eq!(cipher.try_position().unwrap(), 0);
+
+    let mut offset = 0u64;
+    for len in [1usize, 3, 4, 7, 12] {
+        cipher.apply_keystream(&mut [0u8; 12][..len]);
+        offset += len as u64;
+        assert_eq!(cipher.position(), offset);
+        assert_eq!(cipher.try_position().unwrap(), offset);
+        assert_eq!(cipher.position(), cipher.current_pos::<u64>());
+    }
+
+    cipher.seek(5u64);
+    assert_eq!(cipher.position(), 5);
+}
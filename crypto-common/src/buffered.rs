@@ -0,0 +1,71 @@
+use crate::{Block, BlockSizeUser};
+use core::fmt;
+
+/// Adapter which buffers bytes and passes them to a sink closure in full
+/// blocks of `B`.
+///
+/// This is useful for sinks which only accept complete blocks (e.g.
+/// hardware FIFOs) while the data arrives in arbitrarily sized pieces.
+/// Incoming bytes are accumulated until a block is full, full blocks in
+/// the input are passed to the closure directly. The final partial block
+/// is padded with zeros and passed to the closure by
+/// [`finish`][Self::finish].
+pub struct BlockBufferedWrite<B: BlockSizeUser, F: FnMut(&Block<B>)> {
+    buffer: Block<B>,
+    pos: usize,
+    sink: F,
+}
+
+impl<B: BlockSizeUser, F: FnMut(&Block<B>)> BlockBufferedWrite<B, F> {
+    /// Create a new adapter passing full blocks to `sink`.
+    pub fn new(sink: F) -> Self {
+        Self {
+            buffer: Default::default(),
+            pos: 0,
+            sink,
+        }
+    }
+
+    /// Number of buffered bytes which do not yet form a full block.
+    pub fn buffered_len(&self) -> usize {
+        self.pos
+    }
+
+    /// Buffer `data`, passing every completed block to the sink.
+    pub fn update(&mut self, mut data: &[u8]) {
+        if self.pos != 0 {
+            let n = core::cmp::min(B::BLOCK_SIZE - self.pos, data.len());
+            let (head, tail) = data.split_at(n);
+            self.buffer[self.pos..][..n].copy_from_slice(head);
+            self.pos += n;
+            data = tail;
+            if self.pos < B::BLOCK_SIZE {
+                return;
+            }
+            (self.sink)(&self.buffer);
+            self.pos = 0;
+        }
+
+        let (blocks, tail) = Block::<B>::slice_as_chunks(data);
+        blocks.iter().for_each(&mut self.sink);
+        self.buffer[..tail.len()].copy_from_slice(tail);
+        self.pos = tail.len();
+    }
+
+    /// Pass the buffered partial block padded with zeros to the sink, if
+    /// there is one.
+    pub fn finish(mut self) {
+        if self.pos != 0 {
+            self.buffer[self.pos..].fill(0);
+            (self.sink)(&self.buffer);
+        }
+    }
+}
+
+impl<B: BlockSizeUser, F: FnMut(&Block<B>)> fmt::Debug for BlockBufferedWrite<B, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BlockBufferedWrite")
+            .field("buffered_len", &self.pos)
+            .finish_non_exhaustive()
+    }
+}
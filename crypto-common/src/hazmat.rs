@@ -15,12 +15,57 @@ pub type AddSerializedStateSize<T, S> = Sum<T, <S as SerializableState>::Seriali
 pub type SubSerializedStateSize<T, S> = Diff<T, <S as SerializableState>::SerializedStateSize>;
 
 /// The error type returned when an object cannot be deserialized from the state.
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
-pub struct DeserializeStateError;
+///
+/// The error may record the byte offset into the serialized state of the
+/// field which failed to deserialize, see [`DeserializeStateError::at_offset`].
+///
+/// Note that unlike in earlier versions this is not a unit struct: create
+/// values with [`DeserializeStateError::new`] or
+/// [`DeserializeStateError::at_offset`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct DeserializeStateError {
+    offset: Option<usize>,
+}
+
+impl DeserializeStateError {
+    /// Create a new error which does not record an offset.
+    pub const fn new() -> Self {
+        Self { offset: None }
+    }
+
+    /// Create a new error for the field starting at the byte `offset` of the
+    /// serialized state.
+    pub const fn at_offset(offset: usize) -> Self {
+        Self {
+            offset: Some(offset),
+        }
+    }
+
+    /// Byte offset of the field which failed to deserialize, if recorded.
+    pub const fn offset(&self) -> Option<usize> {
+        self.offset
+    }
+
+    /// Rebase an error returned by a field deserialized from the bytes
+    /// starting at `base` onto the enclosing serialized state.
+    ///
+    /// An error without an offset is attributed to the start of the field.
+    /// Types composing the serialized states of their fields should apply
+    /// this with the running offset of each field.
+    pub const fn offset_by(self, base: usize) -> Self {
+        match self.offset {
+            Some(offset) => Self::at_offset(base + offset),
+            None => Self::at_offset(base),
+        }
+    }
+}
 
 impl fmt::Display for DeserializeStateError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        f.write_str("Deserialization error")
+        match self.offset {
+            Some(offset) => write!(f, "Deserialization error at offset {offset}"),
+            None => f.write_str("Deserialization error"),
+        }
     }
 }
 
@@ -53,6 +98,44 @@ where
     /// Create an object from serialized internal state.
     fn deserialize(serialized_state: &SerializedState<Self>)
         -> Result<Self, DeserializeStateError>;
+
+    /// Take a snapshot of the internal state.
+    ///
+    /// This is a thin wrapper around [`serialize`][Self::serialize] which
+    /// allows to save and later [`restore`][Self::restore] state of types
+    /// which do not implement [`Clone`] (e.g. hardware-backed ones).
+    #[inline]
+    fn snapshot(&self) -> SerializedState<Self> {
+        self.serialize()
+    }
+
+    /// Restore an object from a snapshot created by [`snapshot`][Self::snapshot].
+    ///
+    /// This is a thin wrapper around [`deserialize`][Self::deserialize].
+    #[inline]
+    fn restore(snapshot: &SerializedState<Self>) -> Result<Self, DeserializeStateError> {
+        Self::deserialize(snapshot)
+    }
+
+    /// Serialize internal state and write exactly `SerializedStateSize` bytes
+    /// of it into `writer`.
+    #[cfg(feature = "std")]
+    fn write_state<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.serialize())
+    }
+
+    /// Read exactly `SerializedStateSize` bytes of serialized internal state
+    /// from `reader` and create an object from it.
+    ///
+    /// Deserialization failures are returned as an [`std::io::Error`] of kind
+    /// [`std::io::ErrorKind::InvalidData`].
+    #[cfg(feature = "std")]
+    fn read_state<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut serialized_state = SerializedState::<Self>::default();
+        reader.read_exact(&mut serialized_state)?;
+        Self::deserialize(&serialized_state)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+    }
 }
 
 macro_rules! impl_seializable_state_unsigned {
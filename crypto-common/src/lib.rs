@@ -9,17 +9,31 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms, missing_debug_implementations)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 /// Hazardous materials.
 pub mod hazmat;
 
+mod buffered;
+
+pub use buffered::BlockBufferedWrite;
+
 #[cfg(feature = "getrandom")]
 pub use getrandom;
 #[cfg(feature = "rand_core")]
 pub use rand_core;
+#[cfg(feature = "zeroize")]
+pub use zeroize;
 
 pub use hybrid_array as array;
 pub use hybrid_array::typenum;
 
+#[cfg(feature = "derive")]
+pub use derive::SizeForwarding;
+
 use core::fmt;
 use hybrid_array::{
     typenum::{Diff, Sum, Unsigned},
@@ -58,6 +72,9 @@ pub trait BlockSizeUser {
     /// Size of the block in bytes.
     type BlockSize: BlockSizes;
 
+    /// Size of the block in bytes, usable in `const` contexts.
+    const BLOCK_SIZE: usize = Self::BlockSize::USIZE;
+
     /// Return block size in bytes.
     #[inline(always)]
     fn block_size() -> usize {
@@ -96,6 +113,22 @@ mod sealed {
 pub trait ParBlocksSizeUser: BlockSizeUser {
     /// Number of blocks which can be processed in parallel.
     type ParBlocksSize: ArraySize;
+
+    /// Number of blocks which should be processed in parallel on the current
+    /// target, e.g. depending on SIMD extensions detected at runtime.
+    ///
+    /// Generic drivers consult this value before splitting data into chunks.
+    /// If it is smaller than [`ParBlocksSize`][Self::ParBlocksSize], data is
+    /// processed in chunks of the returned size (where backend supports it)
+    /// or block by block.
+    ///
+    /// The returned value MUST NOT exceed `ParBlocksSize`. Drivers clamp
+    /// bigger values to `ParBlocksSize`. The default implementation returns
+    /// `ParBlocksSize`.
+    #[inline(always)]
+    fn par_blocks_runtime() -> usize {
+        Self::ParBlocksSize::USIZE
+    }
 }
 
 /// Types which return data with the given size.
@@ -103,6 +136,9 @@ pub trait OutputSizeUser {
     /// Size of the output in bytes.
     type OutputSize: ArraySize;
 
+    /// Size of the output in bytes, usable in `const` contexts.
+    const OUTPUT_SIZE: usize = Self::OutputSize::USIZE;
+
     /// Return output size in bytes.
     #[inline(always)]
     fn output_size() -> usize {
@@ -110,6 +146,19 @@ pub trait OutputSizeUser {
     }
 }
 
+impl<T: OutputSizeUser + ?Sized> OutputSizeUser for &T {
+    type OutputSize = T::OutputSize;
+}
+
+impl<T: OutputSizeUser + ?Sized> OutputSizeUser for &mut T {
+    type OutputSize = T::OutputSize;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: OutputSizeUser + ?Sized> OutputSizeUser for alloc::boxed::Box<T> {
+    type OutputSize = T::OutputSize;
+}
+
 /// Types which use key for initialization.
 ///
 /// Generally it's used indirectly via [`KeyInit`] or [`KeyIvInit`].
@@ -122,6 +171,17 @@ pub trait KeySizeUser {
     fn key_size() -> usize {
         Self::KeySize::USIZE
     }
+
+    /// Generate random key material of [`KeySize`][Self::KeySize] bytes
+    /// into a heap buffer which is zeroized on drop, using the provided
+    /// [`CryptoRngCore`].
+    #[cfg(all(feature = "rand_core", feature = "zeroize", feature = "alloc"))]
+    #[inline]
+    fn random_key_zeroizing(
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<zeroize::Zeroizing<alloc::vec::Vec<u8>>, rand_core::Error> {
+        random_zeroizing(rng, Self::KeySize::USIZE)
+    }
 }
 
 /// Types which use initialization vector (nonce) for initialization.
@@ -136,6 +196,27 @@ pub trait IvSizeUser {
     fn iv_size() -> usize {
         Self::IvSize::USIZE
     }
+
+    /// Generate a random IV of [`IvSize`][Self::IvSize] bytes into a heap
+    /// buffer which is zeroized on drop, using the provided [`CryptoRngCore`].
+    #[cfg(all(feature = "rand_core", feature = "zeroize", feature = "alloc"))]
+    #[inline]
+    fn random_iv_zeroizing(
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<zeroize::Zeroizing<alloc::vec::Vec<u8>>, rand_core::Error> {
+        random_zeroizing(rng, Self::IvSize::USIZE)
+    }
+}
+
+/// Fill a zeroizing heap buffer of `len` bytes using `rng`.
+#[cfg(all(feature = "rand_core", feature = "zeroize", feature = "alloc"))]
+fn random_zeroizing(
+    rng: &mut impl CryptoRngCore,
+    len: usize,
+) -> Result<zeroize::Zeroizing<alloc::vec::Vec<u8>>, rand_core::Error> {
+    let mut buf = zeroize::Zeroizing::new(alloc::vec![0u8; len]);
+    rng.try_fill_bytes(&mut buf)?;
+    Ok(buf)
 }
 
 /// Types which use another type for initialization.
@@ -171,6 +252,19 @@ pub trait KeyInit: KeySizeUser + Sized {
             .map_err(|_| InvalidLength)
     }
 
+    /// Create new value from variable size key stored in a [`Zeroizing`][zeroize::Zeroizing]
+    /// buffer.
+    ///
+    /// The buffer is consumed and wiped (including its spare capacity) after
+    /// initialization, regardless of whether it succeeded.
+    #[cfg(all(feature = "alloc", feature = "zeroize"))]
+    #[inline]
+    fn new_from_zeroizing(
+        key: zeroize::Zeroizing<alloc::vec::Vec<u8>>,
+    ) -> Result<Self, InvalidLength> {
+        Self::new_from_slice(&key)
+    }
+
     /// Generate random key using the operating system's secure RNG.
     #[cfg(feature = "getrandom")]
     #[inline]
@@ -190,6 +284,41 @@ pub trait KeyInit: KeySizeUser + Sized {
     }
 }
 
+/// Types which can be fallibly initialized from key, e.g. because not every
+/// byte string of the right length is a valid key.
+pub trait TryKeyInit: KeySizeUser + Sized {
+    /// Create new value from fixed size key.
+    ///
+    /// Returns [`KeyError::InvalidKey`] if the key is structurally invalid.
+    fn new(key: &Key<Self>) -> Result<Self, KeyError>;
+
+    /// Create new value from variable size key.
+    #[inline]
+    fn new_from_slice(key: &[u8]) -> Result<Self, KeyError> {
+        <&Key<Self>>::try_from(key)
+            .map_err(|_| KeyError::InvalidLength)
+            .and_then(Self::new)
+    }
+
+    /// Check if the key is known to be weak.
+    ///
+    /// Returns [`KeyError::WeakKey`] for weak keys. The default
+    /// implementation considers all keys to be strong.
+    #[inline]
+    fn weak_key_test(_key: &Key<Self>) -> Result<(), KeyError> {
+        Ok(())
+    }
+
+    /// Create new value from fixed size key, rejecting both structurally
+    /// invalid and weak keys.
+    #[inline]
+    fn new_checked(key: &Key<Self>) -> Result<Self, KeyError> {
+        let res = Self::new(key)?;
+        Self::weak_key_test(key)?;
+        Ok(res)
+    }
+}
+
 /// Types which can be initialized from key and initialization vector (nonce).
 pub trait KeyIvInit: KeySizeUser + IvSizeUser + Sized {
     /// Create new value from fixed length key and nonce.
@@ -249,6 +378,11 @@ pub trait KeyIvInit: KeySizeUser + IvSizeUser + Sized {
     }
 
     /// Generate random key and IV using the provided [`CryptoRngCore`].
+    ///
+    /// The key and IV are filled by two independent draws from `rng`, key
+    /// first, so the result is equivalent to calling
+    /// [`KeyIvInit::generate_key_with_rng`] followed by
+    /// [`KeyIvInit::generate_iv_with_rng`].
     #[cfg(feature = "rand_core")]
     #[inline]
     fn generate_key_iv_with_rng(
@@ -283,6 +417,18 @@ pub trait InnerIvInit: InnerUser + IvSizeUser + Sized {
         Ok(Self::inner_iv_init(inner, iv))
     }
 
+    /// Initialize value by creating the inner value from `key` slice and
+    /// using it together with `iv` slice.
+    ///
+    /// Returns [`InvalidLength`] if either `key` or `iv` has an invalid length.
+    #[inline]
+    fn inner_key_iv_slice_init(key: &[u8], iv: &[u8]) -> Result<Self, InvalidLength>
+    where
+        Self::Inner: KeyInit,
+    {
+        Self::Inner::new_from_slice(key).and_then(|inner| Self::inner_iv_slice_init(inner, iv))
+    }
+
     /// Generate random IV using the operating system's secure RNG.
     #[cfg(feature = "getrandom")]
     #[inline]
@@ -387,3 +533,41 @@ impl fmt::Display for InvalidLength {
 }
 
 impl core::error::Error for InvalidLength {}
+
+/// Unified error type for key initialization failures.
+///
+/// This allows generic initialization code to use a single error type, while
+/// more specific error types such as [`InvalidLength`] remain in use by the
+/// individual APIs.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+#[non_exhaustive]
+pub enum KeyError {
+    /// Key and/or IV had an invalid length.
+    InvalidLength,
+
+    /// Key is structurally invalid, e.g. it's not a valid encoding.
+    InvalidKey,
+
+    /// Key is known to be weak.
+    WeakKey,
+}
+
+impl fmt::Display for KeyError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str(match self {
+            Self::InvalidLength => "Invalid Length",
+            Self::InvalidKey => "Invalid Key",
+            Self::WeakKey => "Weak Key",
+        })
+    }
+}
+
+impl core::error::Error for KeyError {}
+
+impl From<InvalidLength> for KeyError {
+    #[inline]
+    fn from(_: InvalidLength) -> KeyError {
+        KeyError::InvalidLength
+    }
+}
@@ -0,0 +1,47 @@
+use crypto_common::{typenum::U4, Block, BlockBufferedWrite, BlockSizeUser};
+
+struct Fifo;
+
+impl BlockSizeUser for Fifo {
+    type BlockSize = U4;
+}
+
+/// Feed `chunks` and return all blocks seen by the sink.
+fn collect(chunks: &[&[u8]]) -> Vec<[u8; 4]> {
+    let mut blocks = Vec::new();
+    let mut writer = BlockBufferedWrite::<Fifo, _>::new(|block: &Block<Fifo>| {
+        blocks.push(<[u8; 4]>::from(*block));
+    });
+    for chunk in chunks {
+        writer.update(chunk);
+        assert!(writer.buffered_len() < 4);
+    }
+    writer.finish();
+    blocks
+}
+
+#[test]
+fn odd_sized_chunks() {
+    let data: Vec<u8> = (1..=14).collect();
+    let blocks = collect(&[&data[..1], &data[1..4], &data[4..9], &[], &data[9..14]]);
+    assert_eq!(
+        blocks,
+        [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 0, 0]]
+    );
+}
+
+#[test]
+fn exact_blocks_have_no_tail() {
+    let data: Vec<u8> = (1..=8).collect();
+    assert_eq!(
+        collect(&[&data[..3], &data[3..]]),
+        [[1, 2, 3, 4], [5, 6, 7, 8]]
+    );
+    assert_eq!(collect(&[&data]), [[1, 2, 3, 4], [5, 6, 7, 8]]);
+}
+
+#[test]
+fn single_partial_block() {
+    assert_eq!(collect(&[&[7], &[8]]), [[7, 8, 0, 0]]);
+    assert!(collect(&[]).is_empty());
+}
@@ -0,0 +1,34 @@
+use crypto_common::{typenum::U8, InvalidLength, Key, KeyError, KeyInit, KeySizeUser};
+
+struct DummyKey;
+
+impl KeySizeUser for DummyKey {
+    type KeySize = U8;
+}
+
+impl KeyInit for DummyKey {
+    fn new(_key: &Key<Self>) -> Self {
+        Self
+    }
+}
+
+fn init(key: &[u8]) -> Result<DummyKey, KeyError> {
+    Ok(DummyKey::new_from_slice(key)?)
+}
+
+#[test]
+fn key_error_from_invalid_length() {
+    assert_eq!(KeyError::from(InvalidLength), KeyError::InvalidLength);
+    assert!(init(&[0; 8]).is_ok());
+    assert_eq!(init(&[0; 7]).err(), Some(KeyError::InvalidLength));
+}
+
+#[test]
+fn key_error_display() {
+    assert_eq!(
+        KeyError::InvalidLength.to_string(),
+        InvalidLength.to_string()
+    );
+    assert_eq!(KeyError::InvalidKey.to_string(), "Invalid Key");
+    assert_eq!(KeyError::WeakKey.to_string(), "Weak Key");
+}
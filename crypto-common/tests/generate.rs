@@ -0,0 +1,78 @@
+#![cfg(feature = "rand_core")]
+
+use crypto_common::{
+    rand_core::{impls, CryptoRng, Error, RngCore},
+    typenum::{U4, U8},
+    Iv, IvSizeUser, Key, KeyIvInit, KeySizeUser,
+};
+
+/// RNG which outputs an incrementing byte sequence.
+struct CounterRng(u8);
+
+impl RngCore for CounterRng {
+    fn next_u32(&mut self) -> u32 {
+        impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for byte in dest {
+            *byte = self.0;
+            self.0 = self.0.wrapping_add(1);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl CryptoRng for CounterRng {}
+
+struct DummyCipher {
+    key: Key<Self>,
+    iv: Iv<Self>,
+}
+
+impl KeySizeUser for DummyCipher {
+    type KeySize = U8;
+}
+
+impl IvSizeUser for DummyCipher {
+    type IvSize = U4;
+}
+
+impl KeyIvInit for DummyCipher {
+    fn new(key: &Key<Self>, iv: &Iv<Self>) -> Self {
+        Self { key: *key, iv: *iv }
+    }
+}
+
+#[test]
+fn generate_key_iv_with_rng() {
+    let (key, iv) = DummyCipher::generate_key_iv_with_rng(&mut CounterRng(0)).unwrap();
+    assert_eq!(key, [0u8, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(iv, [8u8, 9, 10, 11]);
+
+    let cipher = DummyCipher::new(&key, &iv);
+    assert_eq!(cipher.key, key);
+    assert_eq!(cipher.iv, iv);
+}
+
+#[cfg(all(feature = "zeroize", feature = "alloc"))]
+#[test]
+fn random_key_iv_zeroizing() {
+    use crypto_common::zeroize::Zeroizing;
+
+    let mut rng = CounterRng(0);
+    let key: Zeroizing<Vec<u8>> = DummyCipher::random_key_zeroizing(&mut rng).unwrap();
+    let iv: Zeroizing<Vec<u8>> = DummyCipher::random_iv_zeroizing(&mut rng).unwrap();
+    assert_eq!(key.len(), DummyCipher::key_size());
+    assert_eq!(iv.len(), DummyCipher::iv_size());
+    assert_eq!(key.as_slice(), [0u8, 1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(iv.as_slice(), [8u8, 9, 10, 11]);
+}
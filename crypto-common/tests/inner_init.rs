@@ -0,0 +1,68 @@
+use crypto_common::{
+    typenum::{U16, U8},
+    InnerIvInit, InnerUser, InvalidLength, Iv, IvSizeUser, Key, KeyInit, KeySizeUser,
+};
+
+/// Dummy block cipher which stores its key.
+struct DummyCipher(Key<Self>);
+
+impl KeySizeUser for DummyCipher {
+    type KeySize = U16;
+}
+
+impl KeyInit for DummyCipher {
+    fn new(key: &Key<Self>) -> Self {
+        Self(*key)
+    }
+}
+
+/// Dummy mode of operation which stores the inner cipher and IV.
+struct DummyMode {
+    cipher: DummyCipher,
+    iv: Iv<Self>,
+}
+
+impl InnerUser for DummyMode {
+    type Inner = DummyCipher;
+}
+
+impl IvSizeUser for DummyMode {
+    type IvSize = U8;
+}
+
+impl InnerIvInit for DummyMode {
+    fn inner_iv_init(cipher: DummyCipher, iv: &Iv<Self>) -> Self {
+        Self { cipher, iv: *iv }
+    }
+}
+
+#[test]
+fn inner_key_iv_slice_init() {
+    let key = [1u8; 16];
+    let iv = [2u8; 8];
+    let mode = DummyMode::inner_key_iv_slice_init(&key, &iv).unwrap();
+    assert_eq!(mode.cipher.0[..], key[..]);
+    assert_eq!(mode.iv[..], iv[..]);
+}
+
+#[test]
+fn inner_key_iv_slice_init_invalid_lengths() {
+    let key = [1u8; 17];
+    let iv = [2u8; 9];
+    assert_eq!(
+        DummyMode::inner_key_iv_slice_init(&key[..15], &iv[..8]).err(),
+        Some(InvalidLength)
+    );
+    assert_eq!(
+        DummyMode::inner_key_iv_slice_init(&key, &iv[..8]).err(),
+        Some(InvalidLength)
+    );
+    assert_eq!(
+        DummyMode::inner_key_iv_slice_init(&key[..16], &iv[..7]).err(),
+        Some(InvalidLength)
+    );
+    assert_eq!(
+        DummyMode::inner_key_iv_slice_init(&key[..16], &iv).err(),
+        Some(InvalidLength)
+    );
+}
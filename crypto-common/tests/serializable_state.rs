@@ -0,0 +1,121 @@
+#![cfg(feature = "std")]
+
+use crypto_common::hazmat::{DeserializeStateError, SerializableState, SerializedState};
+use crypto_common::typenum::U1;
+use std::io::{Cursor, ErrorKind};
+
+#[test]
+fn io_round_trip() {
+    let value = [0x0123_4567u32, 0x89ab_cdef, 0, u32::MAX];
+
+    let mut cursor = Cursor::new(Vec::new());
+    value.write_state(&mut cursor).unwrap();
+    assert_eq!(cursor.get_ref().len(), 16);
+
+    cursor.set_position(0);
+    assert_eq!(<[u32; 4]>::read_state(&mut cursor).unwrap(), value);
+}
+
+#[test]
+fn read_state_truncated() {
+    let mut cursor = Cursor::new(vec![0u8; 7]);
+    let err = u64::read_state(&mut cursor).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+}
+
+/// Boolean which fails to deserialize from bytes other than 0 or 1.
+#[derive(Debug, PartialEq)]
+struct Bool(bool);
+
+impl SerializableState for Bool {
+    type SerializedStateSize = U1;
+
+    fn serialize(&self) -> SerializedState<Self> {
+        [self.0 as u8].into()
+    }
+
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        match serialized_state[0] {
+            0 => Ok(Self(false)),
+            1 => Ok(Self(true)),
+            _ => Err(DeserializeStateError::new()),
+        }
+    }
+}
+
+#[test]
+fn read_state_invalid_data() {
+    assert_eq!(
+        Bool::read_state(&mut Cursor::new([1u8])).unwrap(),
+        Bool(true)
+    );
+
+    let err = Bool::read_state(&mut Cursor::new([2u8])).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidData);
+}
+
+/// Composite state which tags field errors with the running offset.
+#[derive(Debug, PartialEq)]
+struct Flags {
+    counter: u32,
+    first: Bool,
+    second: Bool,
+}
+
+impl SerializableState for Flags {
+    type SerializedStateSize = crypto_common::typenum::U6;
+
+    fn serialize(&self) -> SerializedState<Self> {
+        let mut state = SerializedState::<Self>::default();
+        state[..4].copy_from_slice(&self.counter.serialize());
+        state[4..5].copy_from_slice(&self.first.serialize());
+        state[5..].copy_from_slice(&self.second.serialize());
+        state
+    }
+
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        let (counter, rest) = serialized_state.split_ref::<crypto_common::typenum::U4>();
+        let (first, second) = rest.split_ref::<U1>();
+        Ok(Self {
+            counter: u32::deserialize(counter).map_err(|e| e.offset_by(0))?,
+            first: Bool::deserialize(first).map_err(|e| e.offset_by(4))?,
+            second: Bool::deserialize(second).map_err(|e| e.offset_by(5))?,
+        })
+    }
+}
+
+#[test]
+fn deserialize_error_offset() {
+    let flags = Flags {
+        counter: 7,
+        first: Bool(true),
+        second: Bool(false),
+    };
+    let mut state = flags.serialize();
+    assert_eq!(Flags::deserialize(&state).unwrap(), flags);
+
+    state[5] = 2;
+    let err = Flags::deserialize(&state).unwrap_err();
+    assert_eq!(err.offset(), Some(5));
+    assert_eq!(err.to_string(), "Deserialization error at offset 5");
+
+    state[4] = 3;
+    assert_eq!(Flags::deserialize(&state).unwrap_err().offset(), Some(4));
+}
+
+#[test]
+fn deserialize_error_without_offset() {
+    let err = DeserializeStateError::new();
+    assert_eq!(err.offset(), None);
+    assert_eq!(err, DeserializeStateError::default());
+    assert_eq!(err.to_string(), "Deserialization error");
+    assert_eq!(err.offset_by(3), DeserializeStateError::at_offset(3));
+    assert_eq!(
+        DeserializeStateError::at_offset(2).offset_by(3).offset(),
+        Some(5)
+    );
+}
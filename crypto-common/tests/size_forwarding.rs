@@ -0,0 +1,44 @@
+//! Tests for the `SizeForwarding` derive macro.
+
+#![cfg(feature = "derive")]
+
+use crypto_common::{
+    typenum::{U16, U32, U8},
+    BlockSizeUser, KeySizeUser, OutputSizeUser, SizeForwarding,
+};
+
+struct Inner;
+
+impl BlockSizeUser for Inner {
+    type BlockSize = U16;
+}
+
+impl OutputSizeUser for Inner {
+    type OutputSize = U8;
+}
+
+impl KeySizeUser for Inner {
+    type KeySize = U32;
+}
+
+#[derive(SizeForwarding)]
+#[allow(dead_code)]
+struct Wrapper(Inner);
+
+#[derive(SizeForwarding)]
+#[allow(dead_code)]
+struct GenericWrapper<T> {
+    #[size_source(block, key)]
+    inner: T,
+    counter: u64,
+}
+
+#[test]
+fn forwarded_sizes() {
+    assert_eq!(Wrapper::block_size(), 16);
+    assert_eq!(Wrapper::output_size(), 8);
+    assert_eq!(Wrapper::key_size(), 32);
+
+    assert_eq!(GenericWrapper::<Inner>::block_size(), 16);
+    assert_eq!(GenericWrapper::<Inner>::key_size(), 32);
+}
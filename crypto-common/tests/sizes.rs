@@ -0,0 +1,48 @@
+use crypto_common::{
+    typenum::{U16, U32},
+    BlockSizeUser, Output, OutputSizeUser,
+};
+
+struct Dummy;
+
+impl BlockSizeUser for Dummy {
+    type BlockSize = U16;
+}
+
+impl OutputSizeUser for Dummy {
+    type OutputSize = U32;
+}
+
+const BLOCK_SIZE: usize = Dummy::BLOCK_SIZE;
+const OUTPUT_SIZE: usize = <Dummy as OutputSizeUser>::OUTPUT_SIZE;
+
+#[test]
+fn const_sizes() {
+    let block = [0u8; BLOCK_SIZE];
+    let output = [0u8; OUTPUT_SIZE];
+    assert_eq!(block.len(), Dummy::block_size());
+    assert_eq!(output.len(), Dummy::output_size());
+    assert_eq!(<&Dummy as BlockSizeUser>::BLOCK_SIZE, 16);
+}
+
+fn out_buf<T: OutputSizeUser>(_: &T) -> Output<T> {
+    Output::<T>::default()
+}
+
+#[test]
+fn output_size_through_references() {
+    let mut dummy = Dummy;
+    assert_eq!(<&Dummy as OutputSizeUser>::output_size(), 32);
+    assert_eq!(<&mut Dummy as OutputSizeUser>::output_size(), 32);
+    assert_eq!(<&mut Dummy as OutputSizeUser>::OUTPUT_SIZE, 32);
+    assert_eq!(out_buf(&&dummy).len(), 32);
+    assert_eq!(out_buf(&&mut dummy).len(), 32);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn output_size_through_box() {
+    let boxed = Box::new(Dummy);
+    assert_eq!(<Box<Dummy> as OutputSizeUser>::output_size(), 32);
+    assert_eq!(out_buf(&boxed).len(), 32);
+}
@@ -0,0 +1,86 @@
+use crypto_common::{typenum::U4, Key, KeyError, KeySizeUser, TryKeyInit};
+
+/// Mock key which must have its top bit cleared, and is weak if all of its
+/// bytes are equal.
+#[derive(Debug)]
+struct MockKey([u8; 4]);
+
+impl KeySizeUser for MockKey {
+    type KeySize = U4;
+}
+
+impl TryKeyInit for MockKey {
+    fn new(key: &Key<Self>) -> Result<Self, KeyError> {
+        if key[0] & 0x80 != 0 {
+            return Err(KeyError::InvalidKey);
+        }
+        Ok(Self(key.0))
+    }
+
+    fn weak_key_test(key: &Key<Self>) -> Result<(), KeyError> {
+        if key.iter().all(|&b| b == key[0]) {
+            Err(KeyError::WeakKey)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Key type relying on the default `weak_key_test`.
+#[derive(Debug)]
+struct NoWeakKeys;
+
+impl KeySizeUser for NoWeakKeys {
+    type KeySize = U4;
+}
+
+impl TryKeyInit for NoWeakKeys {
+    fn new(_key: &Key<Self>) -> Result<Self, KeyError> {
+        Ok(Self)
+    }
+}
+
+#[test]
+fn new_checked_accepts_valid_key() {
+    let key = Key::<MockKey>::from([1, 2, 3, 4]);
+    assert_eq!(MockKey::new_checked(&key).unwrap().0, [1, 2, 3, 4]);
+}
+
+#[test]
+fn new_checked_rejects_invalid_key() {
+    let key = Key::<MockKey>::from([0x81, 2, 3, 4]);
+    assert_eq!(MockKey::new(&key).err(), Some(KeyError::InvalidKey));
+    assert_eq!(MockKey::new_checked(&key).err(), Some(KeyError::InvalidKey));
+
+    // Structural validation takes precedence over the weakness check.
+    let key = Key::<MockKey>::from([0x81; 4]);
+    assert_eq!(MockKey::new_checked(&key).err(), Some(KeyError::InvalidKey));
+}
+
+#[test]
+fn new_checked_rejects_weak_key() {
+    let key = Key::<MockKey>::from([7; 4]);
+    assert!(MockKey::new(&key).is_ok());
+    assert_eq!(MockKey::weak_key_test(&key), Err(KeyError::WeakKey));
+    assert_eq!(MockKey::new_checked(&key).err(), Some(KeyError::WeakKey));
+}
+
+#[test]
+fn new_from_slice() {
+    assert!(MockKey::new_from_slice(&[1, 2, 3, 4]).is_ok());
+    assert_eq!(
+        MockKey::new_from_slice(&[1, 2, 3]).err(),
+        Some(KeyError::InvalidLength)
+    );
+    assert_eq!(
+        MockKey::new_from_slice(&[0xff, 2, 3, 4]).err(),
+        Some(KeyError::InvalidKey)
+    );
+}
+
+#[test]
+fn default_weak_key_test() {
+    let key = Key::<NoWeakKeys>::from([0; 4]);
+    assert_eq!(NoWeakKeys::weak_key_test(&key), Ok(()));
+    assert!(NoWeakKeys::new_checked(&key).is_ok());
+}
@@ -0,0 +1,80 @@
+#![cfg(all(feature = "alloc", feature = "zeroize"))]
+
+use crypto_common::{typenum::U16, zeroize::Zeroizing, InvalidLength, Key, KeyInit, KeySizeUser};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Capacity of key buffers tracked by [`CheckingAlloc`].
+const TRACKED_CAP: usize = 997;
+
+static TRACKED_FREED: AtomicUsize = AtomicUsize::new(0);
+static TRACKED_FREED_DIRTY: AtomicUsize = AtomicUsize::new(0);
+
+/// Allocator which checks whether tracked buffers are zeroed on deallocation.
+struct CheckingAlloc;
+
+unsafe impl GlobalAlloc for CheckingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if layout.size() == TRACKED_CAP {
+            let buf = core::slice::from_raw_parts(ptr, layout.size());
+            TRACKED_FREED.fetch_add(1, Ordering::SeqCst);
+            if buf.iter().any(|&b| b != 0) {
+                TRACKED_FREED_DIRTY.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static ALLOC: CheckingAlloc = CheckingAlloc;
+
+/// Dummy block cipher which stores its key.
+struct DummyCipher(Key<Self>);
+
+impl KeySizeUser for DummyCipher {
+    type KeySize = U16;
+}
+
+impl KeyInit for DummyCipher {
+    fn new(key: &Key<Self>) -> Self {
+        Self(*key)
+    }
+}
+
+fn key_buf(len: usize) -> Zeroizing<Vec<u8>> {
+    let mut buf = Vec::with_capacity(TRACKED_CAP);
+    buf.extend((1..=len).map(|i| i as u8));
+    // Fill spare capacity with non-zero bytes as well
+    buf.extend(core::iter::repeat(0xFF).take(TRACKED_CAP - len));
+    buf.truncate(len);
+    Zeroizing::new(buf)
+}
+
+// Single test function, so the allocation counters are not shared between threads
+#[test]
+fn new_from_zeroizing_wipes_buffer() {
+    let cipher = DummyCipher::new_from_zeroizing(key_buf(16)).unwrap();
+    let expected: [u8; 16] = core::array::from_fn(|i| i as u8 + 1);
+    assert_eq!(cipher.0, Key::<DummyCipher>::from(expected));
+    assert_eq!(TRACKED_FREED.load(Ordering::SeqCst), 1);
+    assert_eq!(TRACKED_FREED_DIRTY.load(Ordering::SeqCst), 0);
+
+    let res = DummyCipher::new_from_zeroizing(key_buf(15));
+    assert!(matches!(res, Err(InvalidLength)));
+    assert_eq!(TRACKED_FREED.load(Ordering::SeqCst), 2);
+    assert_eq!(TRACKED_FREED_DIRTY.load(Ordering::SeqCst), 0);
+
+    // Sanity check that the allocator detects non-wiped buffers
+    let mut buf = Vec::with_capacity(TRACKED_CAP);
+    buf.resize(TRACKED_CAP, 0x42u8);
+    drop(buf);
+    assert_eq!(TRACKED_FREED.load(Ordering::SeqCst), 3);
+    assert_eq!(TRACKED_FREED_DIRTY.load(Ordering::SeqCst), 1);
+}
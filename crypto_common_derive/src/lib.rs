@@ -0,0 +1,284 @@
+#![crate_type = "proc-macro"]
+#![doc = include_str!("../README.md")]
+#![forbid(unsafe_code)]
+#![warn(
+    clippy::unwrap_used,
+    rust_2018_idioms,
+    trivial_casts,
+    unused_import_braces,
+    missing_debug_implementations,
+    unused_qualifications
+)]
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Field, Ident, Result, Type};
+
+/// Name of the attribute which marks the field sizes are forwarded to.
+const ATTR_NAME: &str = "size_source";
+
+/// Derive the [`BlockSizeUser`], [`OutputSizeUser`], and [`KeySizeUser`]
+/// traits for a wrapper type by forwarding them to one of its fields.
+///
+/// The field is selected using the `#[size_source]` attribute. It may be
+/// omitted for structs with a single field. By default all three traits
+/// are forwarded, a subset can be selected by listing it in the attribute,
+/// e.g. `#[size_source(block, key)]`.
+///
+/// ```ignore
+/// #[derive(SizeForwarding)]
+/// struct Wrapper(Inner);
+///
+/// #[derive(SizeForwarding)]
+/// struct KeyedWrapper<C> {
+///     #[size_source(block, key)]
+///     cipher: C,
+///     counter: u64,
+/// }
+/// ```
+///
+/// [`BlockSizeUser`]: https://docs.rs/crypto-common/latest/crypto_common/trait.BlockSizeUser.html
+/// [`OutputSizeUser`]: https://docs.rs/crypto-common/latest/crypto_common/trait.OutputSizeUser.html
+/// [`KeySizeUser`]: https://docs.rs/crypto-common/latest/crypto_common/trait.KeySizeUser.html
+#[proc_macro_derive(SizeForwarding, attributes(size_source))]
+pub fn derive_size_forwarding(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    emit_size_forwarding_impls(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn emit_size_forwarding_impls(input: DeriveInput) -> Result<TokenStream2> {
+    let (field_ty, traits) = find_size_source(&input)?;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut impls = TokenStream2::new();
+    for size_trait in traits {
+        let (trait_name, assoc_type) = size_trait.idents();
+        let mut where_clause = where_clause.cloned().unwrap_or_else(|| parse_quote!(where));
+        where_clause
+            .predicates
+            .push(parse_quote!(#field_ty: ::crypto_common::#trait_name));
+
+        impls.extend(quote! {
+            impl #impl_generics ::crypto_common::#trait_name for #name #ty_generics
+            #where_clause
+            {
+                type #assoc_type = <#field_ty as ::crypto_common::#trait_name>::#assoc_type;
+            }
+        });
+    }
+    Ok(impls)
+}
+
+/// Size traits which can be forwarded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SizeTrait {
+    Block,
+    Output,
+    Key,
+}
+
+impl SizeTrait {
+    const ALL: [Self; 3] = [Self::Block, Self::Output, Self::Key];
+
+    /// Names of the trait and its associated type.
+    fn idents(self) -> (Ident, Ident) {
+        let (trait_name, assoc_type) = match self {
+            Self::Block => ("BlockSizeUser", "BlockSize"),
+            Self::Output => ("OutputSizeUser", "OutputSize"),
+            Self::Key => ("KeySizeUser", "KeySize"),
+        };
+        let span = proc_macro2::Span::call_site();
+        (Ident::new(trait_name, span), Ident::new(assoc_type, span))
+    }
+}
+
+/// Find type of the field sizes are forwarded to and the list of forwarded traits.
+fn find_size_source(input: &DeriveInput) -> Result<(&Type, Vec<SizeTrait>)> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "`SizeForwarding` can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut annotated = fields.iter().filter(|field| {
+        field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident(ATTR_NAME))
+    });
+    let field = match (annotated.next(), annotated.next()) {
+        (Some(field), None) => field,
+        (Some(_), Some(second)) => {
+            return Err(Error::new_spanned(
+                second,
+                "only one field can be annotated with `#[size_source]`",
+            ))
+        }
+        (None, _) if fields.len() == 1 => {
+            let field = fields.iter().next().expect("struct has one field");
+            return Ok((&field.ty, SizeTrait::ALL.to_vec()));
+        }
+        (None, _) => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "expected a field annotated with `#[size_source]`",
+            ))
+        }
+    };
+
+    Ok((&field.ty, parse_size_traits(field)?))
+}
+
+/// Parse list of forwarded traits from the `#[size_source]` attribute.
+fn parse_size_traits(field: &Field) -> Result<Vec<SizeTrait>> {
+    let mut traits = Vec::new();
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident(ATTR_NAME)) {
+        if let syn::Meta::Path(_) = attr.meta {
+            traits.extend(SizeTrait::ALL);
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let size_trait = if meta.path.is_ident("block") {
+                SizeTrait::Block
+            } else if meta.path.is_ident("output") {
+                SizeTrait::Output
+            } else if meta.path.is_ident("key") {
+                SizeTrait::Key
+            } else {
+                return Err(meta.error("expected one of `block`, `output`, or `key`"));
+            };
+            traits.push(size_trait);
+            Ok(())
+        })?;
+    }
+
+    // Keep a stable order and remove duplicates
+    Ok(SizeTrait::ALL
+        .into_iter()
+        .filter(|t| traits.contains(t))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn tuple_struct() {
+        let input = parse_quote! {
+            #[derive(SizeForwarding)]
+            struct Wrapper(Inner);
+        };
+
+        let output = emit_size_forwarding_impls(input).expect("valid input");
+
+        assert_eq!(
+            output.to_string(),
+            quote! {
+                impl ::crypto_common::BlockSizeUser for Wrapper
+                where
+                    Inner: ::crypto_common::BlockSizeUser
+                {
+                    type BlockSize = <Inner as ::crypto_common::BlockSizeUser>::BlockSize;
+                }
+                impl ::crypto_common::OutputSizeUser for Wrapper
+                where
+                    Inner: ::crypto_common::OutputSizeUser
+                {
+                    type OutputSize = <Inner as ::crypto_common::OutputSizeUser>::OutputSize;
+                }
+                impl ::crypto_common::KeySizeUser for Wrapper
+                where
+                    Inner: ::crypto_common::KeySizeUser
+                {
+                    type KeySize = <Inner as ::crypto_common::KeySizeUser>::KeySize;
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn generic_struct_with_selected_traits() {
+        let input = parse_quote! {
+            #[derive(SizeForwarding)]
+            struct Wrapper<C>
+            where
+                C: Clone
+            {
+                #[size_source(key, block)]
+                cipher: C,
+                counter: u64,
+            }
+        };
+
+        let output = emit_size_forwarding_impls(input).expect("valid input");
+
+        assert_eq!(
+            output.to_string(),
+            quote! {
+                impl<C> ::crypto_common::BlockSizeUser for Wrapper<C>
+                where
+                    C: Clone,
+                    C: ::crypto_common::BlockSizeUser
+                {
+                    type BlockSize = <C as ::crypto_common::BlockSizeUser>::BlockSize;
+                }
+                impl<C> ::crypto_common::KeySizeUser for Wrapper<C>
+                where
+                    C: Clone,
+                    C: ::crypto_common::KeySizeUser
+                {
+                    type KeySize = <C as ::crypto_common::KeySizeUser>::KeySize;
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn missing_size_source() {
+        let input = parse_quote! {
+            struct Wrapper {
+                a: A,
+                b: B,
+            }
+        };
+        assert!(emit_size_forwarding_impls(input).is_err());
+    }
+
+    #[test]
+    fn multiple_size_sources() {
+        let input = parse_quote! {
+            struct Wrapper(#[size_source] A, #[size_source] B);
+        };
+        assert!(emit_size_forwarding_impls(input).is_err());
+    }
+
+    #[test]
+    fn unknown_trait() {
+        let input = parse_quote! {
+            struct Wrapper(#[size_source(iv)] A);
+        };
+        assert!(emit_size_forwarding_impls(input).is_err());
+    }
+
+    #[test]
+    fn enum_input() {
+        let input = parse_quote! {
+            enum Wrapper {
+                A(Inner),
+            }
+        };
+        assert!(emit_size_forwarding_impls(input).is_err());
+    }
+}
@@ -15,7 +15,7 @@ use core::{
 use crypto_common::{
     array::{Array, ArraySize},
     hazmat::{DeserializeStateError, SerializableState, SerializedState, SubSerializedStateSize},
-    typenum::{IsLess, IsLessOrEqual, Le, LeEq, NonZero, Sum, U1, U256},
+    typenum::{IsLess, IsLessOrEqual, Le, LeEq, NonZero, Sum, Unsigned, U1, U256},
     Block, BlockSizeUser, OutputSizeUser,
 };
 
@@ -233,7 +233,9 @@ where
             serialized_state.split_ref::<T::SerializedStateSize>();
 
         if serialized_outsize[0] != OutSize::U8 {
-            return Err(DeserializeStateError);
+            return Err(DeserializeStateError::at_offset(
+                T::SerializedStateSize::USIZE,
+            ));
         }
 
         Ok(Self {
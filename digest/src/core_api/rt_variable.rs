@@ -179,7 +179,7 @@ where
         Ok(Self {
             core: T::deserialize(serialized_core)?,
             buffer: BlockBuffer::try_new(&serialized_data[..serialized_pos[0].into()])
-                .map_err(|_| DeserializeStateError)?,
+                .map_err(|_| DeserializeStateError::at_offset(T::SerializedStateSize::USIZE))?,
             output_size: serialized_output_size[0],
         })
     }
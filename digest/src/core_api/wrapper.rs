@@ -15,7 +15,7 @@ use core::{
 use crypto_common::{
     array::{Array, ArraySize},
     hazmat::{DeserializeStateError, SerializableState, SerializedState, SubSerializedStateSize},
-    typenum::{Diff, IsLess, Le, NonZero, Sum, U1, U256},
+    typenum::{Diff, IsLess, Le, NonZero, Sum, Unsigned, U1, U256},
     BlockSizeUser, InvalidLength, Key, KeyInit, KeySizeUser, Output,
 };
 
@@ -215,7 +215,7 @@ where
         Ok(Self {
             core: T::deserialize(serialized_core)?,
             buffer: BlockBuffer::try_new(&serialized_data[..serialized_pos[0].into()])
-                .map_err(|_| DeserializeStateError)?,
+                .map_err(|_| DeserializeStateError::at_offset(T::SerializedStateSize::USIZE))?,
         })
     }
 }
@@ -240,6 +240,26 @@ impl<T: BufferKindUser + UpdateCore> std::io::Write for CoreWrapper<T> {
 pub trait CoreProxy: sealed::Sealed {
     /// Type wrapped by [`CoreWrapper`].
     type Core;
+
+    /// Get reference to the wrapped core.
+    ///
+    /// Note that the core state does not include input bytes which are
+    /// currently buffered by the wrapper.
+    fn core_ref(&self) -> &Self::Core;
+
+    /// Create an independent copy of the hasher state.
+    ///
+    /// This is useful for branching several computations from a common
+    /// prefix, e.g. in tree hashing. Forking duplicates both the core state
+    /// and the buffered input bytes, so each branch behaves as if it had
+    /// processed the common prefix on its own.
+    #[inline]
+    fn fork(&self) -> Self
+    where
+        Self: Clone,
+    {
+        self.clone()
+    }
 }
 
 mod sealed {
@@ -250,4 +270,9 @@ impl<T: BufferKindUser> sealed::Sealed for CoreWrapper<T> {}
 
 impl<T: BufferKindUser> CoreProxy for CoreWrapper<T> {
     type Core = T;
+
+    #[inline]
+    fn core_ref(&self) -> &T {
+        &self.core
+    }
 }
@@ -1,10 +1,16 @@
 use super::{FixedOutput, FixedOutputReset, InvalidBufferSize, Reset, Update};
 use crypto_common::{typenum::Unsigned, Output, OutputSizeUser};
 
-#[cfg(feature = "alloc")]
-use alloc::boxed::Box;
+#[cfg(feature = "subtle")]
+use {super::MismatchError, subtle::ConstantTimeEq};
+
 #[cfg(feature = "const-oid")]
 use const_oid::DynAssociatedOid;
+#[cfg(feature = "alloc")]
+use {
+    alloc::{boxed::Box, vec::Vec},
+    crypto_common::hazmat::{DeserializeStateError, SerializableState, SerializedState},
+};
 
 /// Marker trait for cryptographic hash functions.
 pub trait HashMarker {}
@@ -54,6 +60,53 @@ pub trait Digest: OutputSizeUser {
 
     /// Compute hash of `data`.
     fn digest(data: impl AsRef<[u8]>) -> Output<Self>;
+
+    /// Process `data`, retrieve the result and reset hasher instance.
+    ///
+    /// Any data processed before the call is included in the result. This
+    /// allows reusing a single hasher to hash many independent inputs
+    /// without constructing a new instance for each of them.
+    fn digest_reset(&mut self, data: impl AsRef<[u8]>) -> Output<Self>
+    where
+        Self: FixedOutputReset + Default;
+
+    /// Serialize the full hasher state into a checkpoint, e.g. to store it
+    /// and later continue hashing with [`Digest::resume`] without
+    /// reprocessing the data hashed so far.
+    ///
+    /// The checkpoint is only meaningful for the same hasher type and
+    /// implementation version it was created with.
+    #[cfg(feature = "alloc")]
+    fn checkpoint(&self) -> Vec<u8>
+    where
+        Self: SerializableState;
+
+    /// Restore a hasher from a checkpoint created by [`Digest::checkpoint`].
+    ///
+    /// Returns [`DeserializeStateError`] if `state` has the wrong length or
+    /// the hasher rejects it.
+    #[cfg(feature = "alloc")]
+    fn resume(state: &[u8]) -> Result<Self, DeserializeStateError>
+    where
+        Self: SerializableState;
+
+    /// Consume hasher instance and check that its output is equal to
+    /// `expected` in constant time.
+    ///
+    /// Note that plain `==` is sufficient for comparison with public values
+    /// (e.g. a published checksum of a file). Constant-time comparison is
+    /// needed when the expected value must remain secret, e.g. in keyed
+    /// constructions.
+    #[cfg(feature = "subtle")]
+    fn verify(self, expected: &Output<Self>) -> Result<(), MismatchError>;
+
+    /// Consume hasher instance and check that its output is equal to
+    /// `expected` in constant time.
+    ///
+    /// Returns [`MismatchError`] if length of `expected` is not equal to
+    /// the output size. The length itself is not treated as secret.
+    #[cfg(feature = "subtle")]
+    fn verify_slice(self, expected: &[u8]) -> Result<(), MismatchError>;
 }
 
 impl<D: FixedOutput + Default + Update + HashMarker> Digest for D {
@@ -128,6 +181,52 @@ impl<D: FixedOutput + Default + Update + HashMarker> Digest for D {
         hasher.update(data.as_ref());
         hasher.finalize()
     }
+
+    #[inline]
+    fn digest_reset(&mut self, data: impl AsRef<[u8]>) -> Output<Self>
+    where
+        Self: FixedOutputReset + Default,
+    {
+        Update::update(self, data.as_ref());
+        FixedOutputReset::finalize_fixed_reset(self)
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn checkpoint(&self) -> Vec<u8>
+    where
+        Self: SerializableState,
+    {
+        self.serialize().to_vec()
+    }
+
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn resume(state: &[u8]) -> Result<Self, DeserializeStateError>
+    where
+        Self: SerializableState,
+    {
+        let state =
+            SerializedState::<Self>::try_from(state).map_err(|_| DeserializeStateError::new())?;
+        Self::deserialize(&state)
+    }
+
+    #[cfg(feature = "subtle")]
+    #[inline]
+    fn verify(self, expected: &Output<Self>) -> Result<(), MismatchError> {
+        if self.finalize().ct_eq(expected).into() {
+            Ok(())
+        } else {
+            Err(MismatchError)
+        }
+    }
+
+    #[cfg(feature = "subtle")]
+    #[inline]
+    fn verify_slice(self, expected: &[u8]) -> Result<(), MismatchError> {
+        let expected = expected.try_into().map_err(|_| MismatchError)?;
+        self.verify(expected)
+    }
 }
 
 /// Modification of the [`Digest`] trait suitable for trait objects.
@@ -0,0 +1,55 @@
+//! Formatting helpers for hash outputs.
+//!
+//! Hash outputs are usually public values, so the helpers in this module are
+//! not constant-time. Use [`Mac::verify`][crate::Mac::verify] or
+//! [`CtOutput`][crate::CtOutput] to compare secret values such as MAC tags.
+
+use core::fmt;
+use crypto_common::array::{Array, ArraySize};
+
+/// Wrapper around a hash output which implements [`Display`][fmt::Display]
+/// as lowercase hex.
+#[derive(Clone, Copy, Debug)]
+pub struct HexDisplay<'a, N: ArraySize>(pub &'a Array<u8, N>);
+
+impl<N: ArraySize> fmt::Display for HexDisplay<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
+impl<N: ArraySize> fmt::LowerHex for HexDisplay<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))
+    }
+}
+
+impl<N: ArraySize> fmt::UpperHex for HexDisplay<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|b| write!(f, "{b:02X}"))
+    }
+}
+
+/// Check whether a hash output is equal to the given hex string.
+///
+/// Both lowercase and uppercase hex digits are accepted. Returns `false` if
+/// `hex` has the wrong length or contains non-hex characters.
+pub fn output_eq_hex<N: ArraySize>(output: &Array<u8, N>, hex: &str) -> bool {
+    fn nibble(c: u8) -> Option<u8> {
+        match c {
+            b'0'..=b'9' => Some(c - b'0'),
+            b'a'..=b'f' => Some(c - b'a' + 10),
+            b'A'..=b'F' => Some(c - b'A' + 10),
+            _ => None,
+        }
+    }
+
+    let hex = hex.as_bytes();
+    hex.len() == 2 * output.len()
+        && hex.chunks_exact(2).zip(output.iter()).all(|(pair, &b)| {
+            match (nibble(pair[0]), nibble(pair[1])) {
+                (Some(hi), Some(lo)) => (hi << 4) | lo == b,
+                _ => false,
+            }
+        })
+}
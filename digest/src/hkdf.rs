@@ -0,0 +1,41 @@
+//! HKDF key derivation built on [`Mac`].
+//!
+//! Implements the HMAC-based Extract-and-Expand Key Derivation Function as
+//! specified in [RFC 5869], using caller-provided output buffers so that no
+//! allocation is required. Instantiating `M` with HMAC (e.g. `Hmac<Sha256>`)
+//! results in HKDF as defined by the RFC; other MACs are not covered by it.
+//!
+//! [RFC 5869]: https://www.rfc-editor.org/rfc/rfc5869
+
+use crate::{prf::hkdf_expand_blocks, InvalidLength, KeyInit, Mac};
+use crypto_common::Output;
+
+/// HKDF-Extract: derive a pseudorandom key from input keying material `ikm`
+/// and an optional `salt`.
+///
+/// An empty `salt` is equivalent to a salt of `HashLen` zero bytes for HMAC.
+/// Returns [`InvalidLength`] if `M` doesn't accept `salt` as a key.
+pub fn extract<M: Mac + KeyInit>(salt: &[u8], ikm: &[u8]) -> Result<Output<M>, InvalidLength> {
+    let mac = <M as KeyInit>::new_from_slice(salt)?;
+    Ok(mac.chain_update(ikm).finalize().into_bytes())
+}
+
+/// HKDF-Expand: fill `out` with output keying material derived from the
+/// pseudorandom key `prk` and context `info`.
+///
+/// Returns [`InvalidLength`] if `out` is longer than `255 * HashLen` bytes
+/// or if `M` doesn't accept `prk` as a key.
+pub fn expand<M: Mac + KeyInit + Clone>(
+    prk: &[u8],
+    info: &[u8],
+    out: &mut [u8],
+) -> Result<(), InvalidLength> {
+    let mac = <M as KeyInit>::new_from_slice(prk)?;
+    hkdf_expand_blocks(info, out, |parts| {
+        let mut block_mac = mac.clone();
+        for part in parts {
+            block_mac.update(part);
+        }
+        block_mac.finalize().into_bytes()
+    })
+}
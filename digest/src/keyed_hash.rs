@@ -0,0 +1,61 @@
+use crate::{Digest, FixedOutput, MacMarker, Update};
+use core::fmt;
+use crypto_common::{Key, KeyInit, KeySizeUser, Output, OutputSizeUser};
+
+/// Simple keyed hash which computes `H(key || message)` using the digest `D`.
+///
+/// This is intended for quick prototyping with sponge-based hashes or XOFs
+/// (e.g. SHA-3 or BLAKE3) for which this construction is a secure MAC.
+///
+/// # ⚠️ Warning: this is NOT HMAC
+/// For Merkle–Damgård hashes such as SHA-1 or SHA-2 this construction is
+/// susceptible to length-extension attacks: given the tag for a message, an
+/// attacker can compute a valid tag for an extension of that message without
+/// knowing the key. Use the [`hmac`] crate with such hashes instead.
+///
+/// The key size is equal to the output size of `D`.
+///
+/// [`hmac`]: https://docs.rs/hmac
+#[derive(Clone)]
+pub struct SimpleKeyedHash<D: Digest> {
+    hasher: D,
+}
+
+impl<D: Digest> KeySizeUser for SimpleKeyedHash<D> {
+    type KeySize = D::OutputSize;
+}
+
+impl<D: Digest> OutputSizeUser for SimpleKeyedHash<D> {
+    type OutputSize = D::OutputSize;
+}
+
+impl<D: Digest> KeyInit for SimpleKeyedHash<D> {
+    #[inline]
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            hasher: D::new_with_prefix(key),
+        }
+    }
+}
+
+impl<D: Digest> Update for SimpleKeyedHash<D> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.hasher, data);
+    }
+}
+
+impl<D: Digest> FixedOutput for SimpleKeyedHash<D> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        Digest::finalize_into(self.hasher, out);
+    }
+}
+
+impl<D: Digest> MacMarker for SimpleKeyedHash<D> {}
+
+impl<D: Digest> fmt::Debug for SimpleKeyedHash<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SimpleKeyedHash { ... }")
+    }
+}
@@ -20,7 +20,8 @@
 //! Additionally hash functions implement traits from the standard library:
 //! [`Default`], [`Clone`], [`Write`][std::io::Write]. The latter is
 //! feature-gated behind `std` feature, which is usually enabled by default
-//! by hash implementation crates.
+//! by hash implementation crates. Other [`Update`] types can be used as a
+//! writer via the `WriteHasher` adapter.
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
@@ -53,25 +54,49 @@ pub mod dev;
 #[cfg(feature = "core-api")]
 pub mod core_api;
 mod digest;
+pub mod fmt;
+#[cfg(feature = "mac")]
+pub mod hkdf;
+#[cfg(feature = "mac")]
+mod keyed_hash;
 #[cfg(feature = "mac")]
 mod mac;
+pub mod merkle;
+mod prf;
+mod security;
+mod snapshot;
+#[cfg(feature = "zeroize")]
+mod stretch;
+mod tee;
+#[cfg(feature = "mac")]
+mod truncated_mac;
 
 #[cfg(feature = "core-api")]
 pub use block_buffer;
 #[cfg(feature = "oid")]
 pub use const_oid;
 pub use crypto_common;
+#[cfg(feature = "inout")]
+pub use inout;
 
 #[cfg(feature = "const-oid")]
 pub use crate::digest::DynDigestWithOid;
 pub use crate::digest::{Digest, DynDigest, HashMarker};
+pub use crate::prf::{HmacPrf, Prf};
+pub use crate::security::{CollisionResistant, PreimageResistant};
+pub use crate::snapshot::SnapshotFinalize;
+#[cfg(feature = "zeroize")]
+pub use crate::stretch::StretchKey;
+pub use crate::tee::Tee;
 pub use crypto_common::{array, typenum, typenum::consts, Output, OutputSizeUser, Reset};
 #[cfg(feature = "mac")]
 pub use crypto_common::{InnerInit, InvalidLength, Key, KeyInit};
 #[cfg(feature = "mac")]
+pub use keyed_hash::SimpleKeyedHash;
+#[cfg(feature = "mac")]
 pub use mac::{CtOutput, Mac, MacError, MacMarker};
-
-use core::fmt;
+#[cfg(feature = "mac")]
+pub use truncated_mac::TruncatedMac;
 
 /// Types which consume data with byte granularity.
 pub trait Update {
@@ -87,6 +112,58 @@ pub trait Update {
         self.update(data.as_ref());
         self
     }
+
+    /// Update state using length-prefixed `data`.
+    ///
+    /// The canonical encoding is the length of `data` in bytes as a big-endian
+    /// `u64` followed by `data` itself. Unlike plain concatenation, feeding
+    /// several variable-length values this way is unambiguous, e.g. `"a", "bc"`
+    /// and `"ab", "c"` result in different inputs.
+    fn update_prefixed(&mut self, data: &[u8]) {
+        self.update(&(data.len() as u64).to_be_bytes());
+        self.update(data);
+    }
+
+    /// Update state using all data read from `reader` until EOF.
+    ///
+    /// Data is read in fixed-size chunks into a stack buffer. Reads failed with
+    /// [`ErrorKind::Interrupted`][std::io::ErrorKind::Interrupted] are retried.
+    /// Returns the total number of processed bytes.
+    #[cfg(feature = "std")]
+    fn update_from_reader<R: std::io::Read + ?Sized>(
+        &mut self,
+        reader: &mut R,
+    ) -> std::io::Result<u64>
+    where
+        Self: Sized,
+    {
+        let mut buf = [0u8; 8192];
+        let mut total = 0u64;
+        loop {
+            let n = match reader.read(&mut buf) {
+                Ok(0) => return Ok(total),
+                Ok(n) => n,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            self.update(&buf[..n]);
+            total += n as u64;
+        }
+    }
+}
+
+/// Forwarding impl which allows passing `&mut hasher` (including MACs
+/// embedded in larger structs) to helpers generic over [`Update`].
+///
+/// Only absorbing data is forwarded: traits with consuming methods such as
+/// [`FixedOutput`], [`Digest`] and `Mac` are not implemented for `&mut T`,
+/// since finalization would have to move the referenced value. Finalize the
+/// owner instead, or use the `*_reset` methods through the reference.
+impl<T: Update + ?Sized> Update for &mut T {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        T::update(self, data)
+    }
 }
 
 /// Trait for hash functions with fixed-size output.
@@ -101,6 +178,16 @@ pub trait FixedOutput: Update + OutputSizeUser + Sized {
         self.finalize_into(&mut out);
         out
     }
+
+    /// Retrieve result wrapped into [`Zeroizing`][zeroize::Zeroizing] and
+    /// consume the hasher instance.
+    ///
+    /// This is useful when the output is itself secret, e.g. a derived key.
+    #[cfg(feature = "zeroize")]
+    #[inline]
+    fn finalize_zeroizing(self) -> zeroize::Zeroizing<Output<Self>> {
+        zeroize::Zeroizing::new(self.finalize_fixed())
+    }
 }
 
 /// Trait for hash functions with fixed-size output able to reset themselves.
@@ -123,6 +210,44 @@ pub trait XofReader {
     /// Read output into the `buffer`. Can be called an unlimited number of times.
     fn read(&mut self, buffer: &mut [u8]);
 
+    /// Skip `n` bytes of output.
+    ///
+    /// Subsequent output is equal to the output which would be produced after
+    /// reading and discarding `n` bytes.
+    ///
+    /// The default implementation reads into a small scratch buffer in a
+    /// loop. Readers which can advance their position more cheaply should
+    /// override it.
+    fn skip(&mut self, mut n: usize) {
+        let mut scratch = [0u8; 64];
+        while n > 0 {
+            let len = n.min(scratch.len());
+            self.read(&mut scratch[..len]);
+            n -= len;
+        }
+    }
+
+    /// XOR output with data behind the input half of `buf` and write the
+    /// result into its output half.
+    ///
+    /// This can be used to mask data with the XOF output (i.e. to use the XOF
+    /// as a stream cipher). Applying it twice with readers in the same state
+    /// restores the original data.
+    ///
+    /// The default implementation reads output into a small scratch buffer
+    /// in a loop.
+    #[cfg(feature = "inout")]
+    fn read_inout(&mut self, mut buf: inout::InOutBuf<'_, '_, u8>) {
+        let mut scratch = [0u8; 64];
+        while !buf.is_empty() {
+            let len = buf.len().min(scratch.len());
+            let (mut head, tail) = buf.split_at(len);
+            self.read(&mut scratch[..len]);
+            head.xor_in2out(&scratch[..len]);
+            buf = tail;
+        }
+    }
+
     /// Read output into a boxed slice of the specified size.
     ///
     /// Can be called an unlimited number of times in combination with `read`.
@@ -171,6 +296,18 @@ pub trait ExtendableOutput: Sized + Update {
         self.finalize_xof().read(&mut buf);
         buf
     }
+
+    /// Compute hash of `input` and return `output_size` bytes of the result
+    /// in a boxed slice.
+    #[cfg(feature = "alloc")]
+    fn digest_xof_boxed(input: impl AsRef<[u8]>, output_size: usize) -> Box<[u8]>
+    where
+        Self: Default,
+    {
+        let mut hasher = Self::default();
+        hasher.update(input.as_ref());
+        hasher.finalize_boxed(output_size)
+    }
 }
 
 /// Trait for hash functions with extendable-output (XOF) able to reset themselves.
@@ -268,6 +405,24 @@ pub trait VariableOutputReset: VariableOutput + Reset {
     }
 }
 
+/// Builder for hash functions with variable-size output which allows to
+/// configure algorithm parameters (e.g. key, salt, or personalization)
+/// before the output size is chosen.
+///
+/// Parameters are set using chained setters defined by the implementing type.
+/// [`VariableOutput::new`] remains the simple path for the default parameters.
+pub trait VariableOutputBuilder: Sized {
+    /// Hasher type created by this builder.
+    type Output: VariableOutput;
+
+    /// Create hasher instance with the configured parameters and
+    /// the given output size in bytes.
+    ///
+    /// It will return `Err(InvalidOutputSize)` in case if hasher can not return
+    /// hash of the specified output size.
+    fn build(self, output_size: usize) -> Result<Self::Output, InvalidOutputSize>;
+}
+
 /// Trait for hash functions with customization string for domain separation.
 pub trait CustomizedInit: Sized {
     /// Create new hasher instance with the given customization string.
@@ -278,8 +433,8 @@ pub trait CustomizedInit: Sized {
 #[derive(Clone, Copy, Debug, Default)]
 pub struct InvalidOutputSize;
 
-impl fmt::Display for InvalidOutputSize {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl core::fmt::Display for InvalidOutputSize {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("invalid output size")
     }
 }
@@ -290,14 +445,26 @@ impl core::error::Error for InvalidOutputSize {}
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
 pub struct InvalidBufferSize;
 
-impl fmt::Display for InvalidBufferSize {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl core::fmt::Display for InvalidBufferSize {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("invalid buffer length")
     }
 }
 
 impl core::error::Error for InvalidBufferSize {}
 
+/// Hash output is not equal to the expected value.
+#[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
+pub struct MismatchError;
+
+impl core::fmt::Display for MismatchError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("hash output mismatch")
+    }
+}
+
+impl core::error::Error for MismatchError {}
+
 #[cfg(feature = "std")]
 mod hashwriter;
 #[cfg(feature = "std")]
@@ -306,3 +473,7 @@ pub use hashwriter::HashWriter;
 mod hashreader;
 #[cfg(feature = "std")]
 pub use hashreader::HashReader;
+#[cfg(feature = "std")]
+mod write_hasher;
+#[cfg(feature = "std")]
+pub use write_hasher::WriteHasher;
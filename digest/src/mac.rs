@@ -12,6 +12,10 @@ pub trait MacMarker {}
 ///
 /// This trait wraps [`Update`], [`FixedOutput`], and [`MacMarker`] traits
 /// and provides additional convenience methods.
+///
+/// `Mac` is not implemented for `&mut M` since [`Mac::finalize`] and
+/// [`Mac::verify`] consume the instance. A `&mut M` can still be passed to
+/// helpers bounded on [`Update`] to feed it data.
 pub trait Mac: OutputSizeUser + Sized {
     /// Update state using the provided data.
     fn update(&mut self, data: &[u8]);
@@ -40,6 +44,10 @@ pub trait Mac: OutputSizeUser + Sized {
 
     /// Check if tag/code value is correct for the processed input and reset
     /// [`Mac`] instance.
+    ///
+    /// Unlike [`Mac::verify`], this doesn't consume the instance, so it can be
+    /// used to verify a running MAC at a checkpoint and then continue with
+    /// the next segment.
     fn verify_reset(&mut self, tag: &Output<Self>) -> Result<(), MacError>
     where
         Self: FixedOutputReset;
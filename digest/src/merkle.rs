@@ -0,0 +1,41 @@
+//! Domain-separated hashing of Merkle tree leaves and internal nodes.
+//!
+//! Leaf and internal node hashes are computed using distinct one-byte
+//! prefixes following the conventions of [RFC 6962 § 2.1], which prevents
+//! second-preimage attacks where an internal node is passed off as a leaf
+//! (or vice versa):
+//!
+//! ```text
+//! leaf = H(0x00 || data)
+//! node = H(0x01 || left || right)
+//! ```
+//!
+//! [RFC 6962 § 2.1]: https://www.rfc-editor.org/rfc/rfc6962#section-2.1
+
+use crate::{Digest, Output};
+
+/// Prefix for leaf hashes.
+pub const LEAF_PREFIX: u8 = 0x00;
+
+/// Prefix for internal node hashes.
+pub const NODE_PREFIX: u8 = 0x01;
+
+/// Compute the hash of a Merkle tree leaf containing `data`.
+#[inline]
+pub fn hash_leaf<D: Digest>(data: impl AsRef<[u8]>) -> Output<D> {
+    D::new()
+        .chain_update([LEAF_PREFIX])
+        .chain_update(data)
+        .finalize()
+}
+
+/// Compute the hash of an internal Merkle tree node from the hashes of its
+/// `left` and `right` children.
+#[inline]
+pub fn hash_node<D: Digest>(left: impl AsRef<[u8]>, right: impl AsRef<[u8]>) -> Output<D> {
+    D::new()
+        .chain_update([NODE_PREFIX])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+}
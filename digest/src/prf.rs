@@ -0,0 +1,154 @@
+use crate::Digest;
+use core::{fmt, marker::PhantomData};
+use crypto_common::{array::Array, typenum::Unsigned, BlockSizeUser, Output};
+
+#[cfg(any(feature = "mac", feature = "zeroize"))]
+use crypto_common::{array::ArraySize, InvalidLength};
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Pseudorandom function keyed with a byte slice.
+pub trait Prf {
+    /// Compute the PRF of `msg` under `key` and write the result into `out`.
+    ///
+    /// # Panics
+    /// If `out` is longer than the output supported by the implementation.
+    fn prf(key: &[u8], msg: &[u8], out: &mut [u8]);
+}
+
+/// HMAC ([RFC 2104]) instantiated with the hash function `D`, used as a [`Prf`].
+///
+/// With `B` being the block size of `D` in bytes, the key is first brought
+/// to exactly `B` bytes: keys longer than `B` are replaced by `D(key)`, then
+/// the key is padded with zero bytes. The output is
+///
+/// ```text
+/// D((key ^ opad) || D((key ^ ipad) || msg))
+/// ```
+///
+/// where `ipad` and `opad` are `B` repetitions of the bytes `0x36` and
+/// `0x5c` respectively. [`Prf::prf`] writes the first `out.len()` bytes of
+/// the result, so `out` must not be longer than the output size of `D`.
+///
+/// For use as a [`Mac`][crate::Mac] prefer a dedicated HMAC implementation,
+/// e.g. the `hmac` crate, which also provides constant-time verification.
+///
+/// [RFC 2104]: https://www.rfc-editor.org/rfc/rfc2104
+pub struct HmacPrf<D>(PhantomData<D>);
+
+impl<D: Digest + BlockSizeUser> Prf for HmacPrf<D> {
+    fn prf(key: &[u8], msg: &[u8], out: &mut [u8]) {
+        #[allow(unused_mut)]
+        let mut tag = hmac::<D>(key, &[msg]);
+        out.copy_from_slice(&tag[..out.len()]);
+        #[cfg(feature = "zeroize")]
+        tag.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<D: Digest + BlockSizeUser> HmacPrf<D> {
+    /// HKDF-Expand ([RFC 5869 § 2.3]) with HMAC-`D`: fill `out` with output
+    /// keying material derived from the pseudorandom key `prk` and context
+    /// `info`.
+    ///
+    /// ```text
+    /// T(0) = empty
+    /// T(i) = HMAC-D(prk, T(i - 1) || info || u8(i))
+    /// out = first out.len() bytes of T(1) || T(2) || ...
+    /// ```
+    ///
+    /// Unlike [`hkdf::expand`][crate::hkdf::expand] this only requires a
+    /// [`Digest`], not a [`Mac`][crate::Mac] implementation. The
+    /// intermediate blocks `T(i)` are zeroized.
+    ///
+    /// Returns [`InvalidLength`] if `out` is longer than 255 times the
+    /// output size of `D`.
+    ///
+    /// [RFC 5869 § 2.3]: https://www.rfc-editor.org/rfc/rfc5869#section-2.3
+    pub fn hkdf_expand(prk: &[u8], info: &[u8], out: &mut [u8]) -> Result<(), InvalidLength> {
+        hkdf_expand_blocks(info, out, |parts| hmac::<D>(prk, parts))
+    }
+}
+
+/// HKDF-Expand ([RFC 5869 § 2.3]) loop shared by
+/// [`hkdf::expand`][crate::hkdf::expand] and [`HmacPrf::hkdf_expand`]: fill
+/// `out` with the blocks `T(i) = prf(&[T(i - 1), info, &[i]])`.
+///
+/// With the `zeroize` feature enabled the intermediate blocks are wiped
+/// before returning.
+///
+/// [RFC 5869 § 2.3]: https://www.rfc-editor.org/rfc/rfc5869#section-2.3
+#[cfg(any(feature = "mac", feature = "zeroize"))]
+pub(crate) fn hkdf_expand_blocks<S: ArraySize>(
+    info: &[u8],
+    out: &mut [u8],
+    mut prf: impl FnMut(&[&[u8]]) -> Array<u8, S>,
+) -> Result<(), InvalidLength> {
+    let hash_len = S::USIZE;
+    if out.len() > 255 * hash_len {
+        return Err(InvalidLength);
+    }
+
+    #[allow(unused_mut)]
+    let mut prev = Array::<u8, S>::default();
+    for (i, chunk) in out.chunks_mut(hash_len).enumerate() {
+        // `i < 255` due to the length check above
+        let counter = [i as u8 + 1];
+        let prev_block: &[u8] = if i == 0 { &[] } else { &prev };
+        #[allow(unused_mut)]
+        let mut block = prf(&[prev_block, info, &counter]);
+        chunk.copy_from_slice(&block[..chunk.len()]);
+        prev.copy_from_slice(&block);
+        #[cfg(feature = "zeroize")]
+        block.zeroize();
+    }
+
+    #[cfg(feature = "zeroize")]
+    prev.zeroize();
+    Ok(())
+}
+
+/// Compute HMAC-`D` of the concatenation of `msg_parts` under `key`.
+///
+/// With the `zeroize` feature enabled the padded key block and the inner
+/// hash are wiped before returning.
+pub(crate) fn hmac<D: Digest + BlockSizeUser>(key: &[u8], msg_parts: &[&[u8]]) -> Output<D> {
+    let mut block = Array::<u8, D::BlockSize>::default();
+    if key.len() > D::BlockSize::USIZE {
+        #[allow(unused_mut)]
+        let mut hashed = D::digest(key);
+        block[..hashed.len()].copy_from_slice(&hashed);
+        #[cfg(feature = "zeroize")]
+        hashed.zeroize();
+    } else {
+        block[..key.len()].copy_from_slice(key);
+    }
+
+    block.iter_mut().for_each(|b| *b ^= 0x36);
+    let mut inner = D::new().chain_update(&block);
+    for part in msg_parts {
+        inner.update(part);
+    }
+    #[allow(unused_mut)]
+    let mut inner = inner.finalize();
+
+    block.iter_mut().for_each(|b| *b ^= 0x36 ^ 0x5c);
+    let tag = D::new()
+        .chain_update(&block)
+        .chain_update(&inner)
+        .finalize();
+
+    #[cfg(feature = "zeroize")]
+    {
+        block.zeroize();
+        inner.zeroize();
+    }
+    tag
+}
+
+impl<D> fmt::Debug for HmacPrf<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("HmacPrf { ... }")
+    }
+}
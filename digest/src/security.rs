@@ -0,0 +1,62 @@
+use crate::Digest;
+
+/// Marker trait for hash functions which are believed to be collision
+/// resistant, i.e. it is computationally infeasible to find two distinct
+/// inputs `m1` and `m2` such that `H(m1) == H(m2)`.
+///
+/// Generic constructions which rely on this property (e.g. commitments or
+/// committing AEADs) should bound their hash parameter on this trait instead
+/// of [`Digest`] alone, so that hash functions with known collision attacks
+/// can not be used with them. For example, a "hash" which XORs all input
+/// bytes together is rejected:
+///
+/// ```compile_fail
+/// use digest::{
+///     consts::U1, CollisionResistant, FixedOutput, HashMarker, Output, OutputSizeUser, Update,
+/// };
+///
+/// /// Trivially broken hash function: `[1, 2]` and `[3]` collide.
+/// #[derive(Default)]
+/// struct XorHash(u8);
+///
+/// impl HashMarker for XorHash {}
+///
+/// impl Update for XorHash {
+///     fn update(&mut self, data: &[u8]) {
+///         self.0 = data.iter().fold(self.0, |acc, b| acc ^ b);
+///     }
+/// }
+///
+/// impl OutputSizeUser for XorHash {
+///     type OutputSize = U1;
+/// }
+///
+/// impl FixedOutput for XorHash {
+///     fn finalize_into(self, out: &mut Output<Self>) {
+///         out[0] = self.0;
+///     }
+/// }
+///
+/// fn commit<H: CollisionResistant>(data: &[u8]) -> Output<H> {
+///     H::digest(data)
+/// }
+///
+/// // `XorHash` does not implement `CollisionResistant`, so it is rejected
+/// let _ = commit::<XorHash>(b"data");
+/// ```
+///
+/// For example, `aead::CommittingAead::commit_tag` is bounded on this trait.
+///
+/// Implementing this trait is a claim about the security of the algorithm,
+/// so it should only be implemented for hash functions without known
+/// attacks on this property (and with an output size of at least 256 bits).
+pub trait CollisionResistant: Digest {}
+
+/// Marker trait for hash functions which are believed to be preimage
+/// resistant, i.e. given an output `h` it is computationally infeasible to
+/// find an input `m` such that `H(m) == h`.
+///
+/// Implementing this trait is a claim about the security of the algorithm,
+/// so it should only be implemented for hash functions without known
+/// attacks on this property.
+pub trait PreimageResistant: Digest {}
@@ -0,0 +1,28 @@
+use crate::{FixedOutput, Output};
+use crypto_common::hazmat::SerializableState;
+
+/// Finalization of hashers and MACs which keeps their state without
+/// relying on [`Clone`].
+///
+/// This trait is blanket-implemented for all [`FixedOutput`] types which
+/// implement [`SerializableState`], e.g. hardware-backed states which can
+/// not be cloned.
+pub trait SnapshotFinalize: FixedOutput + SerializableState {
+    /// Retrieve result of the current state without consuming it.
+    ///
+    /// The state is serialized, finalized, and then restored from the
+    /// snapshot, so `self` is left exactly as it was before the call and can
+    /// be updated further. The result is equal to `self.clone().finalize_fixed()`
+    /// for types which implement [`Clone`].
+    ///
+    /// # Panics
+    /// If the type fails to deserialize the state it has just serialized.
+    #[inline]
+    fn finalize_reset_via_snapshot(&mut self) -> Output<Self> {
+        let snapshot = self.snapshot();
+        let restored = Self::restore(&snapshot).expect("snapshot is valid");
+        core::mem::replace(self, restored).finalize_fixed()
+    }
+}
+
+impl<T: FixedOutput + SerializableState> SnapshotFinalize for T {}
@@ -0,0 +1,43 @@
+use crate::{prf::hmac, Digest, HmacPrf};
+use crypto_common::{BlockSizeUser, Key, KeyInit};
+use zeroize::Zeroizing;
+
+/// Adaptation of arbitrary-length input key material to the key size of
+/// `Self`.
+///
+/// This trait is blanket-implemented for all [`KeyInit`] types.
+pub trait StretchKey: KeyInit {
+    /// Derive a key for `Self` from input key material `ikm` of any length
+    /// and context `info`.
+    ///
+    /// The derivation is HKDF ([RFC 5869]) instantiated with HMAC-`D`, an
+    /// empty salt and an output length of `KeySize` bytes:
+    ///
+    /// ```text
+    /// PRK = HMAC-D(salt = empty, ikm)
+    /// T(0) = empty
+    /// T(i) = HMAC-D(PRK, T(i - 1) || info || u8(i))
+    /// key = first KeySize bytes of T(1) || T(2) || ...
+    /// ```
+    ///
+    /// Use it instead of truncating or padding keys of the wrong length.
+    /// The result is deterministic per `(ikm, info)` and different `info`
+    /// values yield independent keys. Note that this does not strengthen
+    /// low-entropy inputs: passwords must first be processed with a password
+    /// hashing function. The intermediate `PRK` and `T(i)` values are
+    /// zeroized.
+    ///
+    /// # Panics
+    ///
+    /// If the key size of `Self` exceeds 255 times the output size of `D`.
+    ///
+    /// [RFC 5869]: https://www.rfc-editor.org/rfc/rfc5869
+    fn stretch_key<D: Digest + BlockSizeUser>(ikm: &[u8], info: &[u8]) -> Key<Self> {
+        let prk = Zeroizing::new(hmac::<D>(&[], &[ikm]));
+        let mut key = Key::<Self>::default();
+        HmacPrf::<D>::hkdf_expand(&prk, info, &mut key).expect("key size is too large for HKDF");
+        key
+    }
+}
+
+impl<T: KeyInit> StretchKey for T {}
@@ -0,0 +1,37 @@
+use crate::{FixedOutput, Output, Update};
+
+/// Adapter which forwards input to two hashers simultaneously, e.g. to hash
+/// the same stream under an old and a new algorithm during a migration.
+#[derive(Clone, Debug, Default)]
+pub struct Tee<A, B> {
+    a: A,
+    b: B,
+}
+
+impl<A: Update + FixedOutput, B: Update + FixedOutput> Tee<A, B> {
+    /// Create a new [`Tee`] from two hashers.
+    #[inline]
+    pub fn new(a: A, b: B) -> Self {
+        Self { a, b }
+    }
+
+    /// Retrieve the results of both hashers and consume the [`Tee`].
+    #[inline]
+    pub fn finalize(self) -> (Output<A>, Output<B>) {
+        (self.a.finalize_fixed(), self.b.finalize_fixed())
+    }
+
+    /// Decompose the [`Tee`] into its inner hashers.
+    #[inline]
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A: Update, B: Update> Update for Tee<A, B> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.a.update(data);
+        self.b.update(data);
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{FixedOutput, FixedOutputReset, MacMarker, Reset, Update};
+use core::{fmt, marker::PhantomData};
+use crypto_common::{
+    array::ArraySize,
+    typenum::{IsLessOrEqual, LeEq, NonZero},
+    InvalidLength, Key, KeyInit, KeySizeUser, Output, OutputSizeUser,
+};
+
+/// MAC with output truncated to `N` bytes.
+///
+/// The inner MAC `M` is computed as usual and only the leftmost `N` bytes
+/// of its output are kept, as done by protocols such as SSH and IPsec
+/// (e.g. HMAC-SHA-256-96). `N` must not be bigger than the output size of
+/// `M`, which is checked at compile time.
+#[derive(Clone)]
+pub struct TruncatedMac<M, N>
+where
+    M: Update + FixedOutput + MacMarker,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+    inner: M,
+    _out: PhantomData<N>,
+}
+
+impl<M, N> KeySizeUser for TruncatedMac<M, N>
+where
+    M: Update + FixedOutput + MacMarker + KeySizeUser,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+    type KeySize = M::KeySize;
+}
+
+impl<M, N> KeyInit for TruncatedMac<M, N>
+where
+    M: Update + FixedOutput + MacMarker + KeyInit,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+    #[inline]
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            inner: M::new(key),
+            _out: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn new_from_slice(key: &[u8]) -> Result<Self, InvalidLength> {
+        M::new_from_slice(key).map(|inner| Self {
+            inner,
+            _out: PhantomData,
+        })
+    }
+}
+
+impl<M, N> OutputSizeUser for TruncatedMac<M, N>
+where
+    M: Update + FixedOutput + MacMarker,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+    type OutputSize = N;
+}
+
+impl<M, N> Update for TruncatedMac<M, N>
+where
+    M: Update + FixedOutput + MacMarker,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+}
+
+impl<M, N> FixedOutput for TruncatedMac<M, N>
+where
+    M: Update + FixedOutput + MacMarker,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let full = self.inner.finalize_fixed();
+        out.copy_from_slice(&full[..N::USIZE]);
+    }
+}
+
+impl<M, N> Reset for TruncatedMac<M, N>
+where
+    M: Update + FixedOutput + MacMarker + Reset,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+}
+
+impl<M, N> FixedOutputReset for TruncatedMac<M, N>
+where
+    M: Update + FixedOutputReset + MacMarker,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+    #[inline]
+    fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+        let full = self.inner.finalize_fixed_reset();
+        out.copy_from_slice(&full[..N::USIZE]);
+    }
+}
+
+impl<M, N> MacMarker for TruncatedMac<M, N>
+where
+    M: Update + FixedOutput + MacMarker,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+}
+
+impl<M, N> fmt::Debug for TruncatedMac<M, N>
+where
+    M: Update + FixedOutput + MacMarker,
+    N: ArraySize + IsLessOrEqual<M::OutputSize>,
+    LeEq<N, M::OutputSize>: NonZero,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("TruncatedMac { ... }")
+    }
+}
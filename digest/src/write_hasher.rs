@@ -0,0 +1,67 @@
+//! Adapter implementing `io::Write` for any `Update` type
+use super::{FixedOutput, Output, Update};
+use std::io;
+
+/// Adapter which implements [`io::Write`] for any [`Update`] type.
+///
+/// Hashers built on [`CoreWrapper`][crate::core_api::CoreWrapper] already
+/// implement [`io::Write`]. This wrapper covers all other [`Update`] types,
+/// e.g. [`Tee`][crate::Tee] or third-party MACs, so they can be used with
+/// functions like [`io::copy`]. A blanket impl is not possible since
+/// [`io::Write`] is a foreign trait.
+///
+/// Writes pass all bytes to [`Update::update`] and never fail; flushing is
+/// a no-op.
+#[derive(Clone, Debug, Default)]
+pub struct WriteHasher<T> {
+    inner: T,
+}
+
+impl<T: Update> WriteHasher<T> {
+    /// Wrap the given [`Update`] type.
+    pub fn new(inner: T) -> Self {
+        Self { inner }
+    }
+
+    /// Gets a reference to the wrapped value.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the wrapped value.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Consume the wrapper, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: FixedOutput> WriteHasher<T> {
+    /// Retrieve the result of the wrapped hasher and consume the wrapper.
+    pub fn finalize(self) -> Output<T> {
+        self.inner.finalize_fixed()
+    }
+}
+
+impl<T: Update> Update for WriteHasher<T> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+}
+
+impl<T: Update> io::Write for WriteHasher<T> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "alloc")]
+
+use digest::Digest;
+use sha2::Sha256;
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn checkpoint_resume_matches_one_shot() {
+    let (head, tail) = DATA.split_at(DATA.len() / 2);
+    let mut hasher = Sha256::new();
+    hasher.update(head);
+    let checkpoint = hasher.checkpoint();
+    drop(hasher);
+
+    let mut resumed = Sha256::resume(&checkpoint).unwrap();
+    resumed.update(tail);
+    assert_eq!(resumed.finalize(), Sha256::digest(DATA));
+}
+
+#[test]
+fn resume_rejects_wrong_length() {
+    let checkpoint = Sha256::new_with_prefix(DATA).checkpoint();
+    assert!(Sha256::resume(&checkpoint[1..]).is_err());
+    assert!(Sha256::resume(&[checkpoint.as_slice(), &[0]].concat()).is_err());
+    assert!(Sha256::resume(&[]).is_err());
+}
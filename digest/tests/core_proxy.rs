@@ -0,0 +1,50 @@
+#![cfg(feature = "core-api")]
+
+use digest::{core_api::CoreProxy, crypto_common::hazmat::SerializableState, Digest};
+use sha2::Sha256;
+
+const PREFIX: &[u8] = b"common prefix shared by all subtree hashers";
+
+#[test]
+fn fork_mid_stream() {
+    let mut hasher = Sha256::new();
+    // Leave part of the prefix in the buffer
+    hasher.update(PREFIX);
+
+    let mut branch = hasher.fork();
+    hasher.update(b"left");
+    branch.update(b"right");
+
+    assert_eq!(
+        hasher.finalize(),
+        Sha256::digest([PREFIX, b"left"].concat())
+    );
+    assert_eq!(
+        branch.finalize(),
+        Sha256::digest([PREFIX, b"right"].concat())
+    );
+}
+
+#[test]
+fn fork_is_independent() {
+    let mut hasher = Sha256::new_with_prefix(PREFIX);
+    let branch = hasher.fork();
+    hasher.update(b"more data");
+    assert_eq!(branch.finalize(), Sha256::digest(PREFIX));
+}
+
+#[test]
+fn core_ref_excludes_buffered_bytes() {
+    let data = [0x5Au8; 64 + 3];
+
+    let full_block = Sha256::new_with_prefix(&data[..64]);
+    let partial = Sha256::new_with_prefix(data);
+    let fresh = Sha256::new();
+
+    // The trailing 3 bytes are buffered and not yet processed by the core
+    assert_eq!(
+        partial.core_ref().serialize(),
+        full_block.core_ref().serialize()
+    );
+    assert_ne!(partial.core_ref().serialize(), fresh.core_ref().serialize());
+}
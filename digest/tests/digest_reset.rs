@@ -0,0 +1,22 @@
+use digest::Digest;
+use sha2::Sha256;
+
+const INPUTS: &[&[u8]] = &[b"", b"abc", b"hello, world", &[0xAA; 200]];
+
+#[test]
+fn digest_reset_matches_digest() {
+    let mut hasher = Sha256::new();
+    for input in INPUTS {
+        assert_eq!(hasher.digest_reset(input), Sha256::digest(input));
+    }
+}
+
+#[test]
+fn digest_reset_includes_pending_data() {
+    let mut hasher = Sha256::new_with_prefix(b"hello, ");
+    assert_eq!(
+        hasher.digest_reset(b"world"),
+        Sha256::digest(b"hello, world")
+    );
+    assert_eq!(hasher.digest_reset(b"abc"), Sha256::digest(b"abc"));
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "alloc")]
+
+use digest::{ExtendableOutput, Update};
+use sha3::{Shake128, Shake256};
+
+#[test]
+fn digest_xof_boxed_matches_finalize_boxed() {
+    for n in [0, 1, 32, 168, 500] {
+        let expected = Shake128::default().chain(b"input").finalize_boxed(n);
+        let out = Shake128::digest_xof_boxed(b"input", n);
+        assert_eq!(out.len(), n);
+        assert_eq!(out, expected);
+    }
+}
+
+#[test]
+fn digest_xof_boxed_matches_digest_xof() {
+    let mut expected = [0u8; 64];
+    Shake256::digest_xof(b"input", &mut expected);
+    assert_eq!(&*Shake256::digest_xof_boxed(b"input", 64), &expected);
+}
@@ -0,0 +1,13 @@
+#![cfg(feature = "zeroize")]
+
+use digest::{Digest, FixedOutput};
+use sha2::Sha256;
+
+#[test]
+fn finalize_zeroizing_matches_finalize_fixed() {
+    let hasher = Sha256::new_with_prefix(b"derived key material");
+    let expected = hasher.clone().finalize_fixed();
+    let out = hasher.finalize_zeroizing();
+    assert_eq!(*out, expected);
+    assert_eq!(&out[..], &expected[..]);
+}
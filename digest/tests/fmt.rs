@@ -0,0 +1,38 @@
+//! Tests for the hash output formatting helpers.
+
+use digest::{
+    fmt::{output_eq_hex, HexDisplay},
+    Digest,
+};
+use sha2::Sha256;
+
+const ABC_HEX: &str = "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+#[test]
+fn display_sha256() {
+    let hash = Sha256::digest(b"abc");
+    assert_eq!(HexDisplay(&hash).to_string(), ABC_HEX);
+    assert_eq!(format!("{:x}", HexDisplay(&hash)), ABC_HEX);
+    assert_eq!(
+        format!("{:X}", HexDisplay(&hash)),
+        ABC_HEX.to_ascii_uppercase()
+    );
+}
+
+#[test]
+fn eq_hex_sha256() {
+    let hash = Sha256::digest(b"abc");
+    assert!(output_eq_hex(&hash, ABC_HEX));
+    assert!(output_eq_hex(&hash, &ABC_HEX.to_ascii_uppercase()));
+}
+
+#[test]
+fn eq_hex_mismatch() {
+    let hash = Sha256::digest(b"abc");
+    let other = Sha256::digest(b"abd");
+    assert!(!output_eq_hex(&other, ABC_HEX));
+    assert!(!output_eq_hex(&hash, &ABC_HEX[..62]));
+    assert!(!output_eq_hex(&hash, &format!("{ABC_HEX}00")));
+    assert!(!output_eq_hex(&hash, &ABC_HEX.replace("ba", "zz")));
+    assert!(!output_eq_hex(&hash, ""));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "mac")]
+
+use digest::{hkdf, InvalidLength};
+use hex_literal::hex;
+use hmac::Hmac;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+// RFC 5869, Appendix A.1: Test Case 1
+const IKM: [u8; 22] = hex!("0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b0b");
+const SALT: [u8; 13] = hex!("000102030405060708090a0b0c");
+const INFO: [u8; 10] = hex!("f0f1f2f3f4f5f6f7f8f9");
+const PRK: [u8; 32] = hex!("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5");
+const OKM: [u8; 42] = hex!(
+    "3cb25f25faacd57a90434f64d0362f2a"
+    "2d2d0a90cf1a5a4c5db02d56ecc4c5bf"
+    "34007208d5b887185865"
+);
+
+#[test]
+fn rfc5869_test_case_1() {
+    let prk = hkdf::extract::<HmacSha256>(&SALT, &IKM).unwrap();
+    assert_eq!(prk[..], PRK);
+
+    let mut okm = [0u8; 42];
+    hkdf::expand::<HmacSha256>(&prk, &INFO, &mut okm).unwrap();
+    assert_eq!(okm, OKM);
+}
+
+#[test]
+fn expand_prefix() {
+    // Shorter outputs are prefixes of longer ones
+    let mut okm = [0u8; 7];
+    hkdf::expand::<HmacSha256>(&PRK, &INFO, &mut okm).unwrap();
+    assert_eq!(okm, OKM[..7]);
+
+    let mut okm = [];
+    hkdf::expand::<HmacSha256>(&PRK, &INFO, &mut okm).unwrap();
+}
+
+#[test]
+fn expand_max_len() {
+    let mut okm = [0u8; 255 * 32 + 1];
+    assert_eq!(
+        hkdf::expand::<HmacSha256>(&PRK, &INFO, &mut okm),
+        Err(InvalidLength)
+    );
+    assert!(hkdf::expand::<HmacSha256>(&PRK, &INFO, &mut okm[..255 * 32]).is_ok());
+}
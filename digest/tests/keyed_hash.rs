@@ -0,0 +1,37 @@
+#![cfg(feature = "mac")]
+
+use digest::{KeyInit, Mac, SimpleKeyedHash};
+use sha3::Sha3_256;
+
+type KeyedSha3 = SimpleKeyedHash<Sha3_256>;
+
+#[test]
+fn keyed_hash_differs_per_key() {
+    let msg = b"hello world";
+
+    let tag1 = KeyedSha3::new(&[1; 32].into()).chain_update(msg).finalize();
+    let tag2 = KeyedSha3::new(&[2; 32].into()).chain_update(msg).finalize();
+    assert_ne!(tag1, tag2);
+
+    let tag3 = KeyedSha3::new(&[1; 32].into()).chain_update(msg).finalize();
+    assert_eq!(tag1, tag3);
+}
+
+#[test]
+fn keyed_hash_verify() {
+    let key = [1; 32];
+    let tag = KeyedSha3::new(&key.into())
+        .chain_update(b"hello world")
+        .finalize()
+        .into_bytes();
+
+    assert!(KeyedSha3::new(&key.into())
+        .chain_update(b"hello world")
+        .verify(&tag)
+        .is_ok());
+    assert!(KeyedSha3::new(&key.into())
+        .chain_update(b"hello world!")
+        .verify(&tag)
+        .is_err());
+    assert!(KeyedSha3::new_from_slice(&key[..16]).is_err());
+}
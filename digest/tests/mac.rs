@@ -0,0 +1,34 @@
+#![cfg(feature = "mac")]
+
+use digest::{KeyInit, Mac};
+use hmac::Hmac;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+#[test]
+fn verify_reset_segments() {
+    let key = b"segment key";
+    let tag1 = HmacSha256::new_from_slice(key)
+        .unwrap()
+        .chain_update(b"segment one")
+        .finalize()
+        .into_bytes();
+    let tag2 = HmacSha256::new_from_slice(key)
+        .unwrap()
+        .chain_update(b"segment two")
+        .finalize()
+        .into_bytes();
+
+    let mut mac = HmacSha256::new_from_slice(key).unwrap();
+    mac.update(b"segment one");
+    assert!(mac.verify_reset(&tag1).is_ok());
+    mac.update(b"segment two");
+    assert!(mac.verify_reset(&tag2).is_ok());
+
+    // A failed verification also resets the state
+    mac.update(b"segment two");
+    assert!(mac.verify_reset(&tag1).is_err());
+    mac.update(b"segment one");
+    assert!(mac.verify_reset(&tag1).is_ok());
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "mac")]
+
+use digest::{KeyInit, Mac, Update};
+use hmac::Hmac;
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const KEY: &[u8] = b"mac ref key";
+
+/// Struct embedding a MAC which is only accessible via `&mut`.
+struct Transcript {
+    mac: HmacSha256,
+}
+
+fn absorb(mut mac: impl Update, records: &[&[u8]]) {
+    for record in records {
+        mac.update_prefixed(record);
+    }
+}
+
+#[test]
+fn update_through_mut_mac_ref() {
+    let mut transcript = Transcript {
+        mac: HmacSha256::new_from_slice(KEY).unwrap(),
+    };
+    absorb(&mut transcript.mac, &[b"hello", b"world"]);
+    absorb(&mut transcript.mac, &[b"!"]);
+
+    let mut expected = HmacSha256::new_from_slice(KEY).unwrap();
+    absorb(&mut expected, &[b"hello", b"world", b"!"]);
+
+    assert_eq!(transcript.mac.finalize(), expected.finalize());
+}
+
+#[test]
+fn finalize_reset_through_mut_mac_ref() {
+    let mut mac = HmacSha256::new_from_slice(KEY).unwrap();
+    let mac_ref = &mut mac;
+
+    absorb(&mut *mac_ref, &[b"first"]);
+    let first = mac_ref.finalize_reset();
+    absorb(&mut *mac_ref, &[b"first"]);
+    assert_eq!(mac_ref.finalize_reset(), first);
+}
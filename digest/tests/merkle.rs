@@ -0,0 +1,29 @@
+use digest::merkle::{hash_leaf, hash_node};
+use hex_literal::hex;
+use sha2::Sha256;
+
+#[test]
+fn rfc6962_empty_leaf() {
+    assert_eq!(
+        hash_leaf::<Sha256>([])[..],
+        hex!("6e340b9cffb37a989ca544e6bb780a2c78901d3fb33738768511a30617afa01d")
+    );
+}
+
+#[test]
+fn leaf_and_node_are_domain_separated() {
+    // A leaf and a node over the same bytes must hash differently
+    let data = [0x42u8];
+    assert_ne!(hash_leaf::<Sha256>(data), hash_node::<Sha256>(data, []));
+    assert_ne!(hash_leaf::<Sha256>(data), hash_node::<Sha256>([], data));
+}
+
+#[test]
+fn node_is_ordered() {
+    let left = hash_leaf::<Sha256>(b"left");
+    let right = hash_leaf::<Sha256>(b"right");
+    assert_ne!(
+        hash_node::<Sha256>(left, right),
+        hash_node::<Sha256>(right, left)
+    );
+}
@@ -0,0 +1,69 @@
+use digest::{HmacPrf, Prf};
+use hex_literal::hex;
+use sha2::Sha256;
+
+type HmacSha256Prf = HmacPrf<Sha256>;
+
+#[test]
+fn hmac_sha256_rfc4231_case2() {
+    let mut out = [0u8; 32];
+    HmacSha256Prf::prf(b"Jefe", b"what do ya want for nothing?", &mut out);
+    assert_eq!(
+        out,
+        hex!("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+    );
+}
+
+#[test]
+fn hmac_sha256_rfc4231_case5_truncated() {
+    let mut out = [0u8; 16];
+    HmacSha256Prf::prf(&[0x0c; 20], b"Test With Truncation", &mut out);
+    assert_eq!(out, hex!("a3b6167473100ee06e0c796c2955552b"));
+}
+
+#[test]
+fn hmac_sha256_rfc4231_case6_long_key() {
+    let mut out = [0u8; 32];
+    HmacSha256Prf::prf(
+        &[0xaa; 131],
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+        &mut out,
+    );
+    assert_eq!(
+        out,
+        hex!("60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54")
+    );
+}
+
+#[test]
+#[should_panic]
+fn output_too_long() {
+    let mut out = [0u8; 33];
+    HmacSha256Prf::prf(b"key", b"msg", &mut out);
+}
+
+// RFC 5869, Appendix A.1: Test Case 1
+#[cfg(feature = "zeroize")]
+#[test]
+fn hkdf_expand_rfc5869_case1() {
+    let prk = hex!("077709362c2e32df0ddc3f0dc47bba6390b6c73bb50f9c3122ec844ad7c2b3e5");
+    let info = hex!("f0f1f2f3f4f5f6f7f8f9");
+    let mut okm = [0u8; 42];
+    HmacSha256Prf::hkdf_expand(&prk, &info, &mut okm).unwrap();
+    assert_eq!(
+        okm,
+        hex!(
+            "3cb25f25faacd57a90434f64d0362f2a"
+            "2d2d0a90cf1a5a4c5db02d56ecc4c5bf"
+            "34007208d5b887185865"
+        )
+    );
+}
+
+#[cfg(feature = "zeroize")]
+#[test]
+fn hkdf_expand_output_too_long() {
+    let mut okm = [0u8; 255 * 32 + 1];
+    assert!(HmacSha256Prf::hkdf_expand(&[0; 32], b"", &mut okm).is_err());
+    assert!(HmacSha256Prf::hkdf_expand(&[0; 32], b"", &mut okm[..255 * 32]).is_ok());
+}
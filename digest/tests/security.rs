@@ -0,0 +1,42 @@
+use digest::{
+    consts::U32, CollisionResistant, Digest, FixedOutput, HashMarker, Output, OutputSizeUser,
+    PreimageResistant, Update,
+};
+use sha2::Sha256;
+
+/// Wrapper which marks SHA-256 as collision and preimage resistant.
+#[derive(Clone, Default)]
+struct MarkedSha256(Sha256);
+
+impl OutputSizeUser for MarkedSha256 {
+    type OutputSize = U32;
+}
+
+impl Update for MarkedSha256 {
+    fn update(&mut self, data: &[u8]) {
+        Digest::update(&mut self.0, data);
+    }
+}
+
+impl FixedOutput for MarkedSha256 {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        Digest::finalize_into(self.0, out);
+    }
+}
+
+impl HashMarker for MarkedSha256 {}
+impl CollisionResistant for MarkedSha256 {}
+impl PreimageResistant for MarkedSha256 {}
+
+/// Construction which requires both properties of its hash function.
+fn commit<H: CollisionResistant + PreimageResistant>(data: &[u8]) -> Output<H> {
+    H::digest(data)
+}
+
+#[test]
+fn marked_hash_is_accepted() {
+    assert_eq!(
+        commit::<MarkedSha256>(b"data")[..],
+        Sha256::digest(b"data")[..]
+    );
+}
@@ -0,0 +1,85 @@
+use digest::{
+    consts::{U4, U8},
+    crypto_common::hazmat::{DeserializeStateError, SerializableState, SerializedState},
+    FixedOutput, Output, OutputSizeUser, SnapshotFinalize, Update,
+};
+
+/// Mock hasher with serializable state.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct MockHasher {
+    state: u32,
+    len: u32,
+}
+
+impl Update for MockHasher {
+    fn update(&mut self, data: &[u8]) {
+        for &b in data {
+            self.state = (self.state ^ u32::from(b)).wrapping_mul(0x0100_0193);
+            self.len += 1;
+        }
+    }
+}
+
+impl OutputSizeUser for MockHasher {
+    type OutputSize = U4;
+}
+
+impl FixedOutput for MockHasher {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let res = self.state ^ self.len.rotate_left(16);
+        out.copy_from_slice(&res.to_be_bytes());
+    }
+}
+
+impl SerializableState for MockHasher {
+    type SerializedStateSize = U8;
+
+    fn serialize(&self) -> SerializedState<Self> {
+        let mut buf = SerializedState::<Self>::default();
+        buf[..4].copy_from_slice(&self.state.to_le_bytes());
+        buf[4..].copy_from_slice(&self.len.to_le_bytes());
+        buf
+    }
+
+    fn deserialize(
+        serialized_state: &SerializedState<Self>,
+    ) -> Result<Self, DeserializeStateError> {
+        let (state, len) = serialized_state.split_at(4);
+        Ok(Self {
+            state: u32::from_le_bytes(state.try_into().unwrap()),
+            len: u32::from_le_bytes(len.try_into().unwrap()),
+        })
+    }
+}
+
+#[test]
+fn snapshot_restore_round_trip() {
+    let hasher = MockHasher::default().chain(b"abc");
+    let snapshot = hasher.snapshot();
+    assert_eq!(MockHasher::restore(&snapshot).unwrap(), hasher);
+}
+
+#[test]
+fn finalize_via_snapshot_matches_clone() {
+    let mut hasher = MockHasher::default();
+    for msg in [&b""[..], b"a", b"bc", b"hello world"] {
+        hasher.update(msg);
+        let expected = hasher.clone().finalize_fixed();
+        let before = hasher.clone();
+        assert_eq!(hasher.finalize_reset_via_snapshot(), expected);
+        assert_eq!(hasher, before);
+    }
+}
+
+#[test]
+fn deserialize_error_reports_buffer_position_offset() {
+    use sha2::Sha256;
+
+    let mut state = Sha256::default().chain(b"abc").serialize();
+    // The state ends with the buffer position byte followed by the
+    // 64-byte block buffer.
+    let pos_offset = state.len() - 65;
+    state[pos_offset] = 64;
+    let err = Sha256::deserialize(&state).unwrap_err();
+    assert_eq!(err.offset(), Some(pos_offset));
+}
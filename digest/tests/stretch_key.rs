@@ -0,0 +1,68 @@
+#![cfg(feature = "zeroize")]
+
+use digest::{
+    consts::{U16, U42},
+    crypto_common::{Key, KeyInit, KeySizeUser},
+    StretchKey,
+};
+use hex_literal::hex;
+use sha2::Sha256;
+
+/// Mock key holder with a 16-byte key.
+struct Key16(Key<Self>);
+
+impl KeySizeUser for Key16 {
+    type KeySize = U16;
+}
+
+impl KeyInit for Key16 {
+    fn new(key: &Key<Self>) -> Self {
+        Self(*key)
+    }
+}
+
+/// Mock key holder with a 42-byte key, i.e. spanning two HMAC blocks.
+struct Key42;
+
+impl KeySizeUser for Key42 {
+    type KeySize = U42;
+}
+
+impl KeyInit for Key42 {
+    fn new(_key: &Key<Self>) -> Self {
+        Self
+    }
+}
+
+// RFC 5869, Appendix A.3: Test Case 3 (empty salt and info)
+#[test]
+fn rfc5869_test_case_3() {
+    let ikm = [0x0b; 22];
+    let okm = Key42::stretch_key::<Sha256>(&ikm, &[]);
+    assert_eq!(
+        okm[..],
+        hex!(
+            "8da4e775a563c18f715f802a063c5a31"
+            "b8a11f5c5ee1879ec3454e5f3c738d2d"
+            "9d201395faa4b61a96c8"
+        )
+    );
+}
+
+#[test]
+fn output_length_equals_key_size() {
+    for ikm in [&b""[..], b"short", &[0xAA; 200]] {
+        let key = Key16::stretch_key::<Sha256>(ikm, b"info");
+        assert_eq!(key.len(), 16);
+        let cipher = Key16::new(&key);
+        assert_eq!(cipher.0, key);
+    }
+}
+
+#[test]
+fn deterministic_per_ikm_and_info() {
+    let a = Key16::stretch_key::<Sha256>(b"password-derived", b"enc");
+    assert_eq!(a, Key16::stretch_key::<Sha256>(b"password-derived", b"enc"));
+    assert_ne!(a, Key16::stretch_key::<Sha256>(b"password-derived", b"mac"));
+    assert_ne!(a, Key16::stretch_key::<Sha256>(b"other", b"enc"));
+}
@@ -0,0 +1,17 @@
+use digest::{Digest, Tee, Update};
+use sha2::Sha256;
+use sha3::Sha3_256;
+
+#[test]
+fn tee_matches_standalone() {
+    let data = b"the quick brown fox jumps over the lazy dog";
+
+    let mut tee = Tee::<Sha256, Sha3_256>::default();
+    for chunk in data.chunks(7) {
+        tee.update(chunk);
+    }
+    let (a, b) = tee.finalize();
+
+    assert_eq!(a, Sha256::digest(data));
+    assert_eq!(b, Sha3_256::digest(data));
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "mac")]
+
+use digest::{consts::U12, KeyInit, Mac, TruncatedMac};
+use hex_literal::hex;
+use hmac::Hmac;
+use sha2::Sha256;
+
+type HmacSha256_96 = TruncatedMac<Hmac<Sha256>, U12>;
+
+/// RFC 4231 test case 5 (truncated to 96 bits).
+const KEY: [u8; 20] = [0x0c; 20];
+const DATA: &[u8] = b"Test With Truncation";
+const TAG: [u8; 12] = hex!("a3b6167473100ee06e0c796c");
+
+#[test]
+fn hmac_sha256_96() {
+    let mut mac = HmacSha256_96::new_from_slice(&KEY).unwrap();
+    mac.update(DATA);
+    assert_eq!(mac.finalize().into_bytes()[..], TAG[..]);
+}
+
+#[test]
+fn matches_inner_prefix() {
+    let full = Hmac::<Sha256>::new_from_slice(&KEY)
+        .unwrap()
+        .chain_update(DATA)
+        .finalize()
+        .into_bytes();
+    let truncated = HmacSha256_96::new_from_slice(&KEY)
+        .unwrap()
+        .chain_update(DATA)
+        .finalize()
+        .into_bytes();
+    assert_eq!(truncated[..], full[..12]);
+}
+
+#[test]
+fn verify_round_trip() {
+    let mac = HmacSha256_96::new_from_slice(&KEY)
+        .unwrap()
+        .chain_update(DATA);
+    assert!(mac.verify_slice(&TAG).is_ok());
+}
+
+#[test]
+fn verify_rejects_forgery() {
+    let mut forged = TAG;
+    forged[11] ^= 1;
+    let mac = HmacSha256_96::new_from_slice(&KEY)
+        .unwrap()
+        .chain_update(DATA);
+    assert!(mac.verify_slice(&forged).is_err());
+
+    let mac = HmacSha256_96::new_from_slice(&KEY)
+        .unwrap()
+        .chain_update(b"Test With Truncation!");
+    assert!(mac.verify_slice(&TAG).is_err());
+
+    // Tags of a different length are rejected as well
+    let mac = HmacSha256_96::new_from_slice(&KEY)
+        .unwrap()
+        .chain_update(DATA);
+    assert!(mac.verify_slice(&TAG[..11]).is_err());
+}
+
+#[test]
+fn verify_reset() {
+    let mut mac = HmacSha256_96::new_from_slice(&KEY).unwrap();
+    mac.update(DATA);
+    assert!(mac.verify_reset(&TAG.into()).is_ok());
+    mac.update(DATA);
+    assert!(mac.verify_reset(&TAG.into()).is_ok());
+}
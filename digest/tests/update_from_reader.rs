@@ -0,0 +1,66 @@
+#![cfg(feature = "std")]
+
+use digest::{Digest, Update};
+use sha2::Sha256;
+use std::io::{self, Cursor, Read};
+
+fn test_data() -> Vec<u8> {
+    (0..20_000u32).map(|i| (i % 251) as u8).collect()
+}
+
+#[test]
+fn update_from_reader_matches_digest() {
+    let data = test_data();
+    for len in [0, 1, 8192, data.len()] {
+        let mut hasher = Sha256::new();
+        let n = Update::update_from_reader(&mut hasher, &mut Cursor::new(&data[..len])).unwrap();
+        assert_eq!(n, len as u64);
+        assert_eq!(hasher.finalize(), Sha256::digest(&data[..len]));
+    }
+}
+
+/// Reader which returns `Interrupted` before every successful read.
+struct InterruptingReader<R> {
+    inner: R,
+    interrupt: bool,
+}
+
+impl<R: Read> Read for InterruptingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupt = !self.interrupt;
+        if self.interrupt {
+            Err(io::ErrorKind::Interrupted.into())
+        } else {
+            self.inner.read(buf)
+        }
+    }
+}
+
+#[test]
+fn update_from_reader_retries_interrupted() {
+    let data = test_data();
+    let mut reader = InterruptingReader {
+        inner: Cursor::new(&data),
+        interrupt: false,
+    };
+    let mut hasher = Sha256::new();
+    let n = Update::update_from_reader(&mut hasher, &mut reader).unwrap();
+    assert_eq!(n, data.len() as u64);
+    assert_eq!(hasher.finalize(), Sha256::digest(&data));
+}
+
+/// Reader which always fails.
+struct FailingReader;
+
+impl Read for FailingReader {
+    fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+        Err(io::ErrorKind::BrokenPipe.into())
+    }
+}
+
+#[test]
+fn update_from_reader_propagates_errors() {
+    let mut hasher = Sha256::new();
+    let err = Update::update_from_reader(&mut hasher, &mut FailingReader).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::BrokenPipe);
+}
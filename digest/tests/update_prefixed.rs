@@ -0,0 +1,50 @@
+use digest::{Digest, Update};
+use sha2::Sha256;
+
+#[test]
+fn update_prefixed_encoding() {
+    let mut h1 = Sha256::new();
+    Update::update_prefixed(&mut h1, b"abc");
+
+    let mut h2 = Sha256::new();
+    Digest::update(&mut h2, [0, 0, 0, 0, 0, 0, 0, 3]);
+    Digest::update(&mut h2, b"abc");
+    assert_eq!(h1.finalize(), h2.finalize());
+}
+
+#[test]
+fn update_prefixed_is_unambiguous() {
+    let mut h1 = Sha256::new();
+    h1.update_prefixed(b"a");
+    h1.update_prefixed(b"bc");
+
+    let mut h2 = Sha256::new();
+    h2.update_prefixed(b"ab");
+    h2.update_prefixed(b"c");
+
+    assert_ne!(h1.finalize(), h2.finalize());
+
+    // Plain concatenation is ambiguous
+    assert_eq!(
+        Sha256::new()
+            .chain_update("a")
+            .chain_update("bc")
+            .finalize(),
+        Sha256::new()
+            .chain_update("ab")
+            .chain_update("c")
+            .finalize(),
+    );
+}
+
+#[test]
+fn update_prefixed_empty() {
+    let mut h1 = Sha256::new();
+    h1.update_prefixed(b"");
+    h1.update_prefixed(b"a");
+
+    let mut h2 = Sha256::new();
+    h2.update_prefixed(b"a");
+
+    assert_ne!(h1.finalize(), h2.finalize());
+}
@@ -0,0 +1,39 @@
+use digest::{Digest, Update};
+use sha2::Sha256;
+
+fn feed(mut hasher: impl Update) {
+    hasher.update(b"hello ");
+    hasher.update_prefixed(b"world");
+}
+
+#[test]
+fn update_through_mut_ref() {
+    let mut hasher = Sha256::new();
+    feed(&mut hasher);
+    feed(&mut hasher);
+
+    let mut expected = Sha256::new();
+    for _ in 0..2 {
+        Update::update(&mut expected, b"hello ");
+        expected.update_prefixed(b"world");
+    }
+    assert_eq!(hasher.finalize(), expected.finalize());
+}
+
+#[test]
+fn update_through_dyn_ref() {
+    let mut hasher = Sha256::new();
+    let dyn_ref: &mut dyn Update = &mut hasher;
+    feed(dyn_ref);
+
+    let mut expected = Sha256::new();
+    feed(&mut expected);
+    assert_eq!(hasher.finalize(), expected.finalize());
+}
+
+#[test]
+fn chain_through_mut_ref() {
+    let mut hasher = Sha256::new();
+    let _ = Update::chain(&mut hasher, b"abc").chain(b"def");
+    assert_eq!(hasher.finalize(), Sha256::digest(b"abcdef"));
+}
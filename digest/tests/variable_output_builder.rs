@@ -0,0 +1,100 @@
+use digest::{InvalidBufferSize, InvalidOutputSize, Update, VariableOutput, VariableOutputBuilder};
+
+/// Toy variable-output hash with a personalization parameter.
+#[derive(Clone, Debug)]
+struct ToyVarHash {
+    state: u64,
+    output_size: usize,
+}
+
+impl ToyVarHash {
+    fn new_with_params(personal: u64, output_size: usize) -> Result<Self, InvalidOutputSize> {
+        if output_size == 0 || output_size > Self::MAX_OUTPUT_SIZE {
+            return Err(InvalidOutputSize);
+        }
+        let state = personal ^ (output_size as u64).rotate_left(32);
+        Ok(Self { state, output_size })
+    }
+}
+
+impl Update for ToyVarHash {
+    fn update(&mut self, data: &[u8]) {
+        for &b in data {
+            self.state = (self.state ^ u64::from(b)).wrapping_mul(0x0100_0000_01B3);
+        }
+    }
+}
+
+impl VariableOutput for ToyVarHash {
+    const MAX_OUTPUT_SIZE: usize = 8;
+
+    fn new(output_size: usize) -> Result<Self, InvalidOutputSize> {
+        Self::new_with_params(0, output_size)
+    }
+
+    fn output_size(&self) -> usize {
+        self.output_size
+    }
+
+    fn finalize_variable(self, out: &mut [u8]) -> Result<(), InvalidBufferSize> {
+        if out.len() != self.output_size {
+            return Err(InvalidBufferSize);
+        }
+        out.copy_from_slice(&self.state.to_le_bytes()[..self.output_size]);
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct ToyVarHashBuilder {
+    personal: u64,
+}
+
+impl ToyVarHashBuilder {
+    fn personal(mut self, personal: u64) -> Self {
+        self.personal = personal;
+        self
+    }
+}
+
+impl VariableOutputBuilder for ToyVarHashBuilder {
+    type Output = ToyVarHash;
+
+    fn build(self, output_size: usize) -> Result<ToyVarHash, InvalidOutputSize> {
+        ToyVarHash::new_with_params(self.personal, output_size)
+    }
+}
+
+fn hash(mut hasher: ToyVarHash, msg: &[u8]) -> Vec<u8> {
+    hasher.update(msg);
+    let mut out = vec![0u8; hasher.output_size()];
+    hasher.finalize_variable(&mut out).unwrap();
+    out
+}
+
+#[test]
+fn build_sized() {
+    let hasher = ToyVarHashBuilder::default().build(5).unwrap();
+    assert_eq!(hasher.output_size(), 5);
+    assert_eq!(
+        hash(hasher, b"abc"),
+        hash(ToyVarHash::new(5).unwrap(), b"abc")
+    );
+
+    let hasher = ToyVarHashBuilder::default().personal(42).build(5).unwrap();
+    assert_eq!(hasher.output_size(), 5);
+    assert_ne!(
+        hash(hasher, b"abc"),
+        hash(ToyVarHash::new(5).unwrap(), b"abc")
+    );
+}
+
+#[test]
+fn build_rejects_over_max_size() {
+    let max = ToyVarHash::MAX_OUTPUT_SIZE;
+    assert!(ToyVarHashBuilder::default().personal(1).build(max).is_ok());
+    assert!(ToyVarHashBuilder::default()
+        .personal(1)
+        .build(max + 1)
+        .is_err());
+}
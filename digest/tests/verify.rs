@@ -0,0 +1,53 @@
+#![cfg(feature = "subtle")]
+
+use digest::{Digest, MismatchError};
+use hex_literal::hex;
+use sha2::Sha256;
+
+const MSG: &[u8] = b"abc";
+const EXPECTED: [u8; 32] = hex!("ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+
+#[test]
+fn verify_matching() {
+    assert_eq!(
+        Sha256::new_with_prefix(MSG).verify(&EXPECTED.into()),
+        Ok(())
+    );
+    assert_eq!(Sha256::new_with_prefix(MSG).verify_slice(&EXPECTED), Ok(()));
+}
+
+#[test]
+fn verify_mismatching() {
+    let mut bad = EXPECTED;
+    bad[31] ^= 1;
+    assert_eq!(
+        Sha256::new_with_prefix(MSG).verify(&bad.into()),
+        Err(MismatchError)
+    );
+    assert_eq!(
+        Sha256::new_with_prefix(MSG).verify_slice(&bad),
+        Err(MismatchError)
+    );
+    assert_eq!(
+        Sha256::new_with_prefix(b"abd").verify(&EXPECTED.into()),
+        Err(MismatchError)
+    );
+}
+
+#[test]
+fn verify_slice_wrong_length() {
+    assert_eq!(
+        Sha256::new_with_prefix(MSG).verify_slice(&EXPECTED[..31]),
+        Err(MismatchError)
+    );
+    let mut long = [0u8; 33];
+    long[..32].copy_from_slice(&EXPECTED);
+    assert_eq!(
+        Sha256::new_with_prefix(MSG).verify_slice(&long),
+        Err(MismatchError)
+    );
+    assert_eq!(
+        Sha256::new_with_prefix(MSG).verify_slice(&[]),
+        Err(MismatchError)
+    );
+}
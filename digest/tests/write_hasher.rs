@@ -0,0 +1,28 @@
+#![cfg(feature = "std")]
+
+use digest::{Digest, Tee, WriteHasher};
+use sha2::{Sha256, Sha512};
+use std::io::{self, Cursor, Write};
+
+const DATA: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn io_copy_into_write_hasher() {
+    let mut reader = Cursor::new(DATA);
+    let mut writer = WriteHasher::new(Tee::new(Sha256::new(), Sha512::new()));
+    let n = io::copy(&mut reader, &mut writer).unwrap();
+    assert_eq!(n, DATA.len() as u64);
+
+    let (a, b) = writer.into_inner().finalize();
+    assert_eq!(a, Sha256::digest(DATA));
+    assert_eq!(b, Sha512::digest(DATA));
+}
+
+#[test]
+fn write_and_finalize() {
+    let mut writer = WriteHasher::new(Sha256::new());
+    writer.write_all(&DATA[..10]).unwrap();
+    writer.write_all(&DATA[10..]).unwrap();
+    writer.flush().unwrap();
+    assert_eq!(writer.finalize(), Sha256::digest(DATA));
+}
@@ -0,0 +1,41 @@
+use digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake128;
+
+#[test]
+fn skip_matches_read() {
+    for n in [0, 7, 64, 65, 200] {
+        let mut reader1 = Shake128::default().chain(b"input").finalize_xof();
+        let mut reader2 = Shake128::default().chain(b"input").finalize_xof();
+
+        reader1.skip(n);
+        let mut discard = vec![0u8; n];
+        reader2.read(&mut discard);
+
+        let (mut buf1, mut buf2) = ([0u8; 32], [0u8; 32]);
+        reader1.read(&mut buf1);
+        reader2.read(&mut buf2);
+        assert_eq!(buf1, buf2);
+    }
+}
+
+#[cfg(feature = "inout")]
+#[test]
+fn read_inout_mask_unmask() {
+    use digest::inout::InOutBuf;
+
+    let data: Vec<u8> = (0..200u8).collect();
+    let new_reader = || Shake128::default().chain(b"seed").finalize_xof();
+
+    let mut buf = data.clone();
+    new_reader().read_inout(InOutBuf::from(&mut buf[..]));
+    assert_ne!(buf, data);
+
+    let mut keystream = vec![0u8; data.len()];
+    new_reader().read(&mut keystream);
+    let expected: Vec<u8> = data.iter().zip(&keystream).map(|(a, b)| a ^ b).collect();
+    assert_eq!(buf, expected);
+
+    let mut unmasked = vec![0u8; data.len()];
+    new_reader().read_inout(InOutBuf::new(&buf, &mut unmasked).unwrap());
+    assert_eq!(unmasked, data);
+}
@@ -71,6 +71,7 @@ impl Curve for MockCurve {
 
     const ORDER: U256 =
         U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+    const ORDER_BITS: u32 = 256;
 }
 
 impl PrimeCurve for MockCurve {}
@@ -426,7 +427,10 @@ impl AffineCoordinates for AffinePoint {
     type FieldRepr = FieldBytes;
 
     fn x(&self) -> FieldBytes {
-        unimplemented!();
+        match self {
+            Self::FixedBaseOutput(scalar) => scalar.to_repr(),
+            _ => unimplemented!(),
+        }
     }
 
     fn y_is_odd(&self) -> Choice {
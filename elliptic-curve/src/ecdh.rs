@@ -27,8 +27,8 @@
 //! [SIGMA]: https://webee.technion.ac.il/~hugo/sigma-pdf.pdf
 
 use crate::{
-    point::AffineCoordinates, AffinePoint, Curve, CurveArithmetic, FieldBytes, NonZeroScalar,
-    ProjectivePoint, PublicKey,
+    point::AffineCoordinates, AffinePoint, Curve, CurveArithmetic, Error, FieldBytes,
+    NonZeroScalar, ProjectivePoint, PublicKey, Result, SecretKey,
 };
 use core::{borrow::Borrow, fmt};
 use digest::{crypto_common::BlockSizeUser, Digest};
@@ -69,6 +69,38 @@ where
     SharedSecret::new(secret_point)
 }
 
+impl<C> SecretKey<C>
+where
+    C: CurveArithmetic,
+{
+    /// Compute the ECDH [`SharedSecret`] of this static secret key with each
+    /// of the given `peers`, writing the results into the corresponding
+    /// elements of `out`.
+    ///
+    /// This is equivalent to calling [`diffie_hellman`] for every peer, but
+    /// converts the secret key into a scalar only once. It is intended for
+    /// servers performing ECDH with many clients using a single static key.
+    ///
+    /// Returns [`Error`] if `peers` and `out` have different lengths, in
+    /// which case `out` is left unmodified.
+    pub fn diffie_hellman_batch(
+        &self,
+        peers: &[PublicKey<C>],
+        out: &mut [SharedSecret<C>],
+    ) -> Result<()> {
+        if peers.len() != out.len() {
+            return Err(Error);
+        }
+
+        let scalar = self.to_nonzero_scalar();
+        for (peer, shared_secret) in peers.iter().zip(out) {
+            *shared_secret = diffie_hellman(scalar, peer.as_affine());
+        }
+
+        Ok(())
+    }
+}
+
 /// Ephemeral Diffie-Hellman Secret.
 ///
 /// These are ephemeral "secret key" values which are deliberately designed
@@ -194,6 +194,18 @@ pub trait Curve: 'static + Copy + Clone + Debug + Default + Eq + Ord + Send + Sy
     /// Order of this elliptic curve, i.e. number of elements in the scalar
     /// field.
     const ORDER: Self::Uint;
+
+    /// Bit length of [`Curve::ORDER`], e.g. for sizing nonces or
+    /// implementing RFC6979's `bits2int`.
+    ///
+    /// This is the bit length of the order, which may be less than the bit
+    /// length of the field modulus or of [`Curve::Uint`] (e.g. P-521 with a
+    /// 576-bit `Uint`).
+    const ORDER_BITS: u32;
+
+    /// Length of [`Curve::ORDER`] in bytes, i.e. [`Curve::ORDER_BITS`]
+    /// rounded up to a whole number of bytes.
+    const ORDER_BYTES: usize = Self::ORDER_BITS.div_ceil(8) as usize;
 }
 
 /// Marker trait for elliptic curves with prime order.
@@ -52,3 +52,29 @@ pub trait IsHigh {
     /// Is this scalar greater than n / 2?
     fn is_high(&self) -> Choice;
 }
+
+/// Clamp little endian scalar bytes as specified for the Curve25519 family
+/// (X25519 and Ed25519).
+///
+/// This clears the three least significant bits of the first byte, making
+/// the scalar a multiple of the cofactor 8, and clears the most significant
+/// bit and sets the second most significant bit of the last byte, fixing
+/// the position of the highest set bit. For a 32-byte input this is the
+/// `decodeScalar25519` function of [RFC 7748 § 5].
+///
+/// ⚠️ WARNING!
+///
+/// This clamping is specific to Curve25519: other curves, including
+/// Curve448, use different rules.
+///
+/// # Panics
+///
+/// If `bytes` is empty.
+///
+/// [RFC 7748 § 5]: https://www.rfc-editor.org/rfc/rfc7748#section-5
+pub fn clamp_integer(bytes: &mut [u8]) {
+    let last = bytes.len() - 1;
+    bytes[0] &= 0b1111_1000;
+    bytes[last] &= 0b0111_1111;
+    bytes[last] |= 0b0100_0000;
+}
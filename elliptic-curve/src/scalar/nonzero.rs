@@ -15,7 +15,7 @@ use crypto_bigint::{ArrayEncoding, Integer};
 use ff::{Field, PrimeField};
 use rand_core::CryptoRngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
-use zeroize::Zeroize;
+use zeroize::{Zeroize, Zeroizing};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
@@ -70,6 +70,20 @@ where
         Scalar::<C>::from_repr(repr).and_then(Self::new)
     }
 
+    /// Decode a [`NonZeroScalar`] from fixed-width big endian bytes.
+    ///
+    /// Returns none if the bytes encode zero or a value which is not
+    /// reduced modulo the curve order.
+    pub fn from_be_bytes(bytes: &FieldBytes<C>) -> CtOption<Self> {
+        Self::from_repr(bytes.clone())
+    }
+
+    /// Serialize this scalar as fixed-width big endian bytes which are
+    /// zeroized on drop, e.g. for storage.
+    pub fn to_be_bytes_zeroizing(&self) -> Zeroizing<FieldBytes<C>> {
+        Zeroizing::new(self.scalar.to_repr())
+    }
+
     /// Create a [`NonZeroScalar`] from a `C::Uint`.
     pub fn from_uint(uint: C::Uint) -> CtOption<Self> {
         ScalarPrimitive::new(uint).and_then(|scalar| Self::new(scalar.into()))
@@ -390,6 +404,7 @@ mod tests {
     use crate::dev::{NonZeroScalar, Scalar};
     use ff::{Field, PrimeField};
     use hex_literal::hex;
+    use subtle::{Choice, ConditionallySelectable};
     use zeroize::Zeroize;
 
     #[test]
@@ -405,4 +420,15 @@ mod tests {
         scalar.zeroize();
         assert_eq!(*scalar, Scalar::ONE);
     }
+
+    #[test]
+    fn conditional_select() {
+        let a = NonZeroScalar::new(Scalar::from(42u64)).unwrap();
+        let b = NonZeroScalar::new(Scalar::from(1337u64)).unwrap();
+
+        let selected = NonZeroScalar::conditional_select(&a, &b, Choice::from(0));
+        assert_eq!(*selected, *a);
+        let selected = NonZeroScalar::conditional_select(&a, &b, Choice::from(1));
+        assert_eq!(*selected, *b);
+    }
 }
@@ -121,6 +121,34 @@ where
     pub fn to_uint(&self) -> C::Uint {
         self.inner
     }
+
+    /// Compute `self + rhs mod n` in constant time.
+    ///
+    /// Unlike scalar field arithmetic this does not require the `arithmetic`
+    /// feature. Also available via the [`Add`] operator.
+    pub fn add_mod(&self, rhs: &Self) -> Self {
+        Self {
+            inner: self.inner.add_mod(&rhs.inner, &Self::MODULUS),
+        }
+    }
+
+    /// Compute `self - rhs mod n` in constant time.
+    ///
+    /// Also available via the [`Sub`] operator.
+    pub fn sub_mod(&self, rhs: &Self) -> Self {
+        Self {
+            inner: self.inner.sub_mod(&rhs.inner, &Self::MODULUS),
+        }
+    }
+
+    /// Compute `-self mod n` in constant time.
+    ///
+    /// Also available via the [`Neg`] operator.
+    pub fn neg_mod(&self) -> Self {
+        Self {
+            inner: self.inner.neg_mod(&Self::MODULUS),
+        }
+    }
 }
 
 impl<C> FromUintUnchecked for ScalarPrimitive<C>
@@ -253,9 +281,7 @@ where
     type Output = Self;
 
     fn add(self, other: &Self) -> Self {
-        Self {
-            inner: self.inner.add_mod(&other.inner, &Self::MODULUS),
-        }
+        self.add_mod(other)
     }
 }
 
@@ -295,9 +321,7 @@ where
     type Output = Self;
 
     fn sub(self, other: &Self) -> Self {
-        Self {
-            inner: self.inner.sub_mod(&other.inner, &Self::MODULUS),
-        }
+        self.sub_mod(other)
     }
 }
 
@@ -326,9 +350,7 @@ where
     type Output = Self;
 
     fn neg(self) -> Self {
-        Self {
-            inner: self.inner.neg_mod(&Self::MODULUS),
-        }
+        self.neg_mod()
     }
 }
 
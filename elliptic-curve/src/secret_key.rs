@@ -11,7 +11,7 @@ mod pkcs8;
 use crate::{Curve, Error, FieldBytes, Result, ScalarPrimitive};
 use core::fmt::{self, Debug};
 use hybrid_array::typenum::Unsigned;
-use subtle::{Choice, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 #[cfg(feature = "arithmetic")]
@@ -74,6 +74,21 @@ use {crate::pkcs8::DecodePrivateKey, core::str::FromStr};
 ///
 /// When the `pem` feature of this crate (or a specific RustCrypto elliptic
 /// curve crate) is enabled, a [`FromStr`] impl is also available.
+///
+/// # Equality
+///
+/// Comparing secret keys with `==` is constant time: the [`PartialEq`] impl
+/// is implemented in terms of [`ConstantTimeEq`], which compares every limb
+/// of the underlying scalars without exiting early, so the time taken does
+/// not reveal how many leading bytes of two keys match.
+///
+/// # Zeroization
+///
+/// The scalar is wiped when a [`SecretKey`] is dropped (see
+/// [`ZeroizeOnDrop`]). This applies to every clone individually, so no
+/// wrapper is needed to erase copies, e.g. old keys during key rotation.
+/// [`Zeroize`] is deliberately not implemented: zeroizing a key in place
+/// would leave behind a zero scalar, which is not a valid secret key.
 #[derive(Clone)]
 pub struct SecretKey<C: Curve> {
     /// Scalar value
@@ -116,6 +131,17 @@ where
         &self.inner
     }
 
+    /// Select between two secret keys in constant time, returning `a` if
+    /// `choice` is `0` and `b` if `choice` is `1`.
+    ///
+    /// This is provided as an inherent method since [`SecretKey`] is not
+    /// [`Copy`] and therefore can not implement [`ConditionallySelectable`].
+    pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            inner: ScalarPrimitive::conditional_select(&a.inner, &b.inner, choice),
+        }
+    }
+
     /// Get the secret [`NonZeroScalar`] value for this key.
     ///
     /// # ⚠️ Warning
@@ -333,6 +359,7 @@ where
 
 impl<C: Curve> Eq for SecretKey<C> {}
 
+/// Constant-time equality, see [`ConstantTimeEq`].
 impl<C> PartialEq for SecretKey<C>
 where
     C: Curve,
@@ -0,0 +1,48 @@
+//! Curve25519 scalar clamping tests
+
+use elliptic_curve::scalar::clamp_integer;
+use hex_literal::hex;
+
+/// Reference clamping from RFC 7748 § 5.
+fn decode_scalar_25519(mut k: [u8; 32]) -> [u8; 32] {
+    k[0] &= 248;
+    k[31] &= 127;
+    k[31] |= 64;
+    k
+}
+
+#[test]
+fn clamp_matches_reference() {
+    for input in [
+        [0x00; 32],
+        [0xFF; 32],
+        [0x55; 32],
+        hex!("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4"),
+    ] {
+        let mut bytes = input;
+        clamp_integer(&mut bytes);
+        assert_eq!(bytes, decode_scalar_25519(input));
+    }
+}
+
+#[test]
+fn clamp_rfc7748_vector() {
+    // Scalar from RFC 7748 § 5.2, first test vector.
+    let mut bytes = hex!("a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4");
+    clamp_integer(&mut bytes);
+    assert_eq!(
+        bytes,
+        hex!("a046e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449a44")
+    );
+}
+
+#[test]
+fn clamp_is_idempotent() {
+    let mut bytes = [0xA7; 32];
+    clamp_integer(&mut bytes);
+    let once = bytes;
+    clamp_integer(&mut bytes);
+    assert_eq!(bytes, once);
+    assert_eq!(bytes[0] & 0b111, 0);
+    assert_eq!(bytes[31] >> 6, 0b01);
+}
@@ -0,0 +1,53 @@
+//! ECDH tests
+
+#![cfg(all(feature = "dev", feature = "ecdh"))]
+
+use elliptic_curve::{
+    dev::{AffinePoint, MockCurve, PublicKey, SecretKey},
+    ecdh::{diffie_hellman, SharedSecret},
+    FieldBytes,
+};
+
+fn peers() -> [PublicKey; 3] {
+    [PublicKey::from_affine(AffinePoint::Generator).unwrap(); 3]
+}
+
+fn empty_out<const N: usize>() -> [SharedSecret<MockCurve>; N] {
+    core::array::from_fn(|_| SharedSecret::from(FieldBytes::<MockCurve>::default()))
+}
+
+#[test]
+fn diffie_hellman_batch_matches_individual() {
+    let secret_key = SecretKey::from_slice(&[7u8; 32]).unwrap();
+    let peers = peers();
+    let mut out = empty_out::<3>();
+    secret_key.diffie_hellman_batch(&peers, &mut out).unwrap();
+
+    for (peer, shared_secret) in peers.iter().zip(&out) {
+        let expected = diffie_hellman(secret_key.to_nonzero_scalar(), peer.as_affine());
+        assert_eq!(
+            shared_secret.raw_secret_bytes(),
+            expected.raw_secret_bytes()
+        );
+        assert_ne!(
+            shared_secret.raw_secret_bytes(),
+            &FieldBytes::<MockCurve>::default()
+        );
+    }
+}
+
+#[test]
+fn diffie_hellman_batch_length_mismatch() {
+    let secret_key = SecretKey::from_slice(&[7u8; 32]).unwrap();
+    let mut out = empty_out::<2>();
+    assert!(secret_key.diffie_hellman_batch(&peers(), &mut out).is_err());
+    assert!(out
+        .iter()
+        .all(|s| s.raw_secret_bytes() == &FieldBytes::<MockCurve>::default()));
+}
+
+#[test]
+fn diffie_hellman_batch_empty() {
+    let secret_key = SecretKey::from_slice(&[7u8; 32]).unwrap();
+    assert!(secret_key.diffie_hellman_batch(&[], &mut []).is_ok());
+}
@@ -0,0 +1,37 @@
+//! `NonZeroScalar` byte serialization tests
+
+#![cfg(feature = "dev")]
+
+use elliptic_curve::dev::{FieldBytes, MockCurve, NonZeroScalar, Scalar};
+use elliptic_curve::Curve;
+use hex_literal::hex;
+
+#[test]
+fn be_bytes_round_trip() {
+    let bytes = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+    let scalar = NonZeroScalar::from_be_bytes(&bytes.into()).unwrap();
+    let serialized = scalar.to_be_bytes_zeroizing();
+    assert_eq!(serialized.as_slice(), bytes);
+    assert_eq!(*NonZeroScalar::from_be_bytes(&serialized).unwrap(), *scalar);
+}
+
+#[test]
+fn be_bytes_are_big_endian() {
+    let scalar = NonZeroScalar::new(Scalar::from(0x0102u64)).unwrap();
+    let bytes = scalar.to_be_bytes_zeroizing();
+    assert_eq!(bytes[30..], [0x01, 0x02]);
+    assert!(bytes[..30].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn from_be_bytes_rejects_zero() {
+    assert!(bool::from(
+        NonZeroScalar::from_be_bytes(&FieldBytes::default()).is_none()
+    ));
+}
+
+#[test]
+fn from_be_bytes_rejects_order() {
+    let order = FieldBytes::from(MockCurve::ORDER.to_be_bytes());
+    assert!(bool::from(NonZeroScalar::from_be_bytes(&order).is_none()));
+}
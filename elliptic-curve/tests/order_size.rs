@@ -0,0 +1,16 @@
+//! Tests for the order size constants of `Curve`
+
+#![cfg(feature = "dev")]
+
+use elliptic_curve::{dev::MockCurve, Curve};
+
+#[test]
+fn mock_curve_order_size() {
+    assert_eq!(MockCurve::ORDER_BITS, 256);
+    assert_eq!(MockCurve::ORDER_BYTES, 32);
+}
+
+#[test]
+fn order_bits_matches_order() {
+    assert_eq!(MockCurve::ORDER_BITS, MockCurve::ORDER.bits());
+}
@@ -0,0 +1,58 @@
+//! `ScalarPrimitive` modular arithmetic tests
+
+#![cfg(feature = "dev")]
+
+use elliptic_curve::{
+    bigint::U256,
+    dev::{MockCurve, ScalarPrimitive},
+    Curve,
+};
+
+fn scalar(n: U256) -> ScalarPrimitive {
+    ScalarPrimitive::new(n).unwrap()
+}
+
+#[test]
+fn add_then_sub_round_trip() {
+    let max = scalar(MockCurve::ORDER.wrapping_sub(&U256::ONE));
+    let values = [
+        ScalarPrimitive::ZERO,
+        ScalarPrimitive::ONE,
+        ScalarPrimitive::from(0xDEAD_BEEF),
+        max,
+    ];
+
+    for a in &values {
+        for b in &values {
+            assert_eq!(a.add_mod(b).sub_mod(b), *a);
+            assert_eq!(a.sub_mod(b).add_mod(b), *a);
+        }
+    }
+}
+
+#[test]
+fn add_wraps_around_order() {
+    let max = scalar(MockCurve::ORDER.wrapping_sub(&U256::ONE));
+    assert_eq!(max.add_mod(&ScalarPrimitive::ONE), ScalarPrimitive::ZERO);
+    assert_eq!(ScalarPrimitive::ZERO.sub_mod(&ScalarPrimitive::ONE), max);
+}
+
+#[test]
+fn neg_mod() {
+    let a = ScalarPrimitive::from(42);
+    assert_eq!(a.add_mod(&a.neg_mod()), ScalarPrimitive::ZERO);
+    assert_eq!(ScalarPrimitive::ZERO.neg_mod(), ScalarPrimitive::ZERO);
+    assert_eq!(
+        ScalarPrimitive::ONE.neg_mod(),
+        scalar(MockCurve::ORDER.wrapping_sub(&U256::ONE))
+    );
+}
+
+#[test]
+fn methods_match_operators() {
+    let a = ScalarPrimitive::from(1234);
+    let b = ScalarPrimitive::from(98765);
+    assert_eq!(a.add_mod(&b), a + b);
+    assert_eq!(a.sub_mod(&b), a - b);
+    assert_eq!(a.neg_mod(), -a);
+}
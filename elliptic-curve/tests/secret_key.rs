@@ -26,3 +26,64 @@ fn from_slice_too_short() {
     let bytes = [1u8; 23]; // min 24-bytes
     assert!(SecretKey::from_slice(&bytes).is_err());
 }
+
+#[test]
+fn conditional_select() {
+    use elliptic_curve::subtle::Choice;
+
+    let a = SecretKey::from_slice(&[1u8; 32]).unwrap();
+    let b = SecretKey::from_slice(&[2u8; 32]).unwrap();
+
+    assert_eq!(SecretKey::conditional_select(&a, &b, Choice::from(0)), a);
+    assert_eq!(SecretKey::conditional_select(&a, &b, Choice::from(1)), b);
+}
+
+#[test]
+fn ct_eq_equal_keys() {
+    use elliptic_curve::subtle::ConstantTimeEq;
+
+    let a = SecretKey::from_slice(&[7u8; 32]).unwrap();
+    let b = SecretKey::from_slice(&[7u8; 32]).unwrap();
+
+    assert!(bool::from(a.ct_eq(&b)));
+    assert!(a == b);
+}
+
+#[test]
+fn ct_eq_differing_keys() {
+    use elliptic_curve::subtle::ConstantTimeEq;
+
+    let a = SecretKey::from_slice(&[7u8; 32]).unwrap();
+
+    // Differ in the first and in the last byte respectively.
+    let mut bytes = [7u8; 32];
+    bytes[0] = 8;
+    let b = SecretKey::from_slice(&bytes).unwrap();
+    let mut bytes = [7u8; 32];
+    bytes[31] = 8;
+    let c = SecretKey::from_slice(&bytes).unwrap();
+
+    for other in [&b, &c] {
+        assert!(!bool::from(a.ct_eq(other)));
+        assert!(a != *other);
+    }
+}
+
+#[test]
+fn clone_zeroizes_on_drop() {
+    use core::{mem::MaybeUninit, ptr};
+
+    let old = SecretKey::from_slice(&[7u8; 32]).unwrap();
+    let mut slot = MaybeUninit::new(old.clone());
+
+    // SAFETY: `slot` is initialized and dropped exactly once. The memory is
+    // not deallocated, so its bytes can be inspected after the drop.
+    let is_zero = unsafe {
+        ptr::drop_in_place(slot.as_mut_ptr());
+        bool::from((*slot.as_ptr()).as_scalar_primitive().is_zero())
+    };
+    assert!(is_zero);
+
+    // The original key is unaffected.
+    assert_eq!(old.to_bytes().as_slice(), &[7u8; 32]);
+}
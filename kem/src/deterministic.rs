@@ -0,0 +1,120 @@
+//! Deterministic encapsulation for known-answer tests.
+
+use crate::Encapsulate;
+use core::{fmt::Debug, num::NonZeroU32};
+use rand_core::{CryptoRng, RngCore};
+
+/// Deterministic encapsulation from fixed randomness.
+///
+/// ⚠️ Hazmat: this is intended for testing against known-answer test (KAT)
+/// vectors only. Using a fixed or predictable seed in production completely
+/// breaks the security of the KEM.
+///
+/// KEMs whose specification defines a deterministic encapsulation function
+/// (e.g. ML-KEM's `Encaps_internal` taking the 32-byte message `m`) should
+/// implement this trait directly, interpreting `seed` in the format required
+/// by their KAT vectors. For other KEMs [`SeededEncapsulator`] provides an
+/// opt-in implementation which feeds the seed to [`Encapsulate`] as RNG
+/// output.
+pub trait EncapsulateDeterministic<EK, SS> {
+    /// Encapsulation error
+    type Error: Debug;
+
+    /// Deterministically encapsulates a shared secret derived from `seed`.
+    fn encapsulate_deterministic(&self, seed: &[u8]) -> Result<(EK, SS), Self::Error>;
+}
+
+/// Adapter implementing [`EncapsulateDeterministic`] for an [`Encapsulate`]
+/// type by supplying the seed bytes verbatim as the output of the RNG passed
+/// to [`Encapsulate::encapsulate`].
+///
+/// ⚠️ Hazmat: for testing only, see [`EncapsulateDeterministic`].
+///
+/// The results only match KAT vectors which were generated by feeding the
+/// same bytes to the RNG of the encapsulation. If the seed is exhausted
+/// during encapsulation, [`SeedError::SeedExhausted`] is returned.
+#[derive(Clone, Debug)]
+pub struct SeededEncapsulator<T>(T);
+
+impl<T> SeededEncapsulator<T> {
+    /// Wrap the given encapsulator.
+    pub fn new(encapsulator: T) -> Self {
+        Self(encapsulator)
+    }
+
+    /// Borrow the wrapped encapsulator.
+    pub fn inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Unwrap the wrapped encapsulator.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<EK, SS, T: Encapsulate<EK, SS>> EncapsulateDeterministic<EK, SS> for SeededEncapsulator<T> {
+    type Error = SeedError<T::Error>;
+
+    fn encapsulate_deterministic(&self, seed: &[u8]) -> Result<(EK, SS), Self::Error> {
+        let mut rng = SeedRng {
+            seed,
+            exhausted: false,
+        };
+        let result = self.0.encapsulate(&mut rng);
+        if rng.exhausted {
+            return Err(SeedError::SeedExhausted);
+        }
+        result.map_err(SeedError::Encapsulate)
+    }
+}
+
+/// Error returned by [`SeededEncapsulator`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SeedError<E> {
+    /// Error from the wrapped encapsulator.
+    Encapsulate(E),
+
+    /// Encapsulation consumed more randomness than provided by the seed.
+    SeedExhausted,
+}
+
+/// RNG which outputs the bytes of a fixed seed.
+///
+/// Once the seed is exhausted, [`RngCore::try_fill_bytes`] returns an error
+/// and [`RngCore::fill_bytes`] outputs zeros; both record the exhaustion so
+/// that the result of the encapsulation can be discarded.
+struct SeedRng<'a> {
+    seed: &'a [u8],
+    exhausted: bool,
+}
+
+impl RngCore for SeedRng<'_> {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        if self.try_fill_bytes(dest).is_err() {
+            dest.fill(0);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        if dest.len() > self.seed.len() {
+            self.exhausted = true;
+            let code = NonZeroU32::new(rand_core::Error::CUSTOM_START).expect("nonzero");
+            return Err(code.into());
+        }
+        let (head, tail) = self.seed.split_at(dest.len());
+        dest.copy_from_slice(head);
+        self.seed = tail;
+        Ok(())
+    }
+}
+
+impl CryptoRng for SeedRng<'_> {}
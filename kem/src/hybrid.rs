@@ -0,0 +1,131 @@
+//! Hybrid KEM combiner.
+
+use crate::{Decapsulate, Encapsulate};
+use core::{fmt, marker::PhantomData};
+use digest::{Digest, Output, Update};
+use rand_core::CryptoRngCore;
+
+/// Hybrid KEM which combines two KEMs, e.g. a classical and a post-quantum
+/// one, such that the combined shared secret remains secure as long as either
+/// of the inner KEMs is.
+///
+/// Encapsulation and decapsulation run both inner KEMs, and the combined
+/// shared secret is derived by hashing a domain separation label, both shared
+/// secrets and both encapsulated keys with the digest `D`:
+///
+/// ```text
+/// ss = D(P(label) || P(ss1) || P(ss2) || P(ek1) || P(ek2))
+/// ```
+///
+/// where `label` is [`Hybrid::LABEL`] and `P(x)` is the length of `x` in bytes
+/// encoded as a big-endian `u64` followed by `x` itself (see
+/// [`Update::update_prefixed`]). Length-prefixing keeps the encoding
+/// unambiguous for inner KEMs with variable-size outputs, and binding the
+/// encapsulated keys into the shared secret follows the recommendations for
+/// KEM combiners.
+///
+/// The `SS1` and `SS2` type parameters identify the shared secret types of the
+/// inner KEMs, and can usually be inferred.
+pub struct Hybrid<K1, K2, D, SS1, SS2> {
+    /// First inner KEM.
+    pub k1: K1,
+
+    /// Second inner KEM.
+    pub k2: K2,
+
+    _marker: PhantomData<(D, SS1, SS2)>,
+}
+
+impl<K1, K2, D, SS1, SS2> Hybrid<K1, K2, D, SS1, SS2> {
+    /// Domain separation label hashed into the combined shared secret.
+    pub const LABEL: &'static [u8] = HYBRID_LABEL;
+
+    /// Create a new hybrid KEM from two inner KEMs.
+    pub fn new(k1: K1, k2: K2) -> Self {
+        Self {
+            k1,
+            k2,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K1: fmt::Debug, K2: fmt::Debug, D, SS1, SS2> fmt::Debug for Hybrid<K1, K2, D, SS1, SS2> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hybrid")
+            .field("k1", &self.k1)
+            .field("k2", &self.k2)
+            .finish()
+    }
+}
+
+impl<K1, K2, D, EK1, EK2, SS1, SS2> Encapsulate<(EK1, EK2), Output<D>>
+    for Hybrid<K1, K2, D, SS1, SS2>
+where
+    K1: Encapsulate<EK1, SS1>,
+    K2: Encapsulate<EK2, SS2>,
+    D: Digest + Update,
+    EK1: AsRef<[u8]>,
+    EK2: AsRef<[u8]>,
+    SS1: AsRef<[u8]>,
+    SS2: AsRef<[u8]>,
+{
+    type Error = HybridError<K1::Error, K2::Error>;
+
+    fn encapsulate(
+        &self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<((EK1, EK2), Output<D>), Self::Error> {
+        let (ek1, ss1) = self.k1.encapsulate(rng).map_err(HybridError::First)?;
+        let (ek2, ss2) = self.k2.encapsulate(rng).map_err(HybridError::Second)?;
+        let ss = combine::<D>(ss1.as_ref(), ss2.as_ref(), ek1.as_ref(), ek2.as_ref());
+        Ok(((ek1, ek2), ss))
+    }
+}
+
+impl<K1, K2, D, EK1, EK2, SS1, SS2> Decapsulate<(EK1, EK2), Output<D>>
+    for Hybrid<K1, K2, D, SS1, SS2>
+where
+    K1: Decapsulate<EK1, SS1>,
+    K2: Decapsulate<EK2, SS2>,
+    D: Digest + Update,
+    EK1: AsRef<[u8]>,
+    EK2: AsRef<[u8]>,
+    SS1: AsRef<[u8]>,
+    SS2: AsRef<[u8]>,
+{
+    type Error = HybridError<K1::Error, K2::Error>;
+
+    fn decapsulate(&self, encapsulated_key: &(EK1, EK2)) -> Result<Output<D>, Self::Error> {
+        let (ek1, ek2) = encapsulated_key;
+        let ss1 = self.k1.decapsulate(ek1).map_err(HybridError::First)?;
+        let ss2 = self.k2.decapsulate(ek2).map_err(HybridError::Second)?;
+        Ok(combine::<D>(
+            ss1.as_ref(),
+            ss2.as_ref(),
+            ek1.as_ref(),
+            ek2.as_ref(),
+        ))
+    }
+}
+
+/// Derive the combined shared secret.
+fn combine<D: Digest + Update>(ss1: &[u8], ss2: &[u8], ek1: &[u8], ek2: &[u8]) -> Output<D> {
+    let mut d = D::new();
+    for part in [HYBRID_LABEL, ss1, ss2, ek1, ek2] {
+        d.update_prefixed(part);
+    }
+    d.finalize()
+}
+
+const HYBRID_LABEL: &[u8] = b"RustCrypto kem::Hybrid";
+
+/// Error from one of the inner KEMs of a [`Hybrid`] KEM.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HybridError<E1, E2> {
+    /// Error from the first inner KEM.
+    First(E1),
+
+    /// Error from the second inner KEM.
+    Second(E2),
+}
@@ -0,0 +1,87 @@
+//! KEM-then-KDF combinator.
+
+use crate::{Decapsulate, Encapsulate};
+use core::{fmt, marker::PhantomData};
+use digest::{Digest, Output, Update};
+use rand_core::CryptoRngCore;
+
+/// KEM combinator which passes the raw shared secret of the inner KEM
+/// through a KDF before use.
+///
+/// The resulting shared secret is derived by hashing the raw shared secret
+/// followed by the encapsulated key with the digest `D`:
+///
+/// ```text
+/// ss = D(P(ss_raw) || P(ek))
+/// ```
+///
+/// where `P(x)` is the length of `x` in bytes encoded as a big-endian `u64`
+/// followed by `x` itself (see [`Update::update_prefixed`]). The hashed inputs
+/// and their order are fixed, and length-prefixing keeps the encoding
+/// unambiguous for inner KEMs with variable-size outputs.
+///
+/// The `SS` type parameter identifies the shared secret type of the inner
+/// KEM, and can usually be inferred.
+pub struct KdfWrapped<K, D, SS> {
+    /// Inner KEM.
+    pub kem: K,
+
+    _marker: PhantomData<(D, SS)>,
+}
+
+impl<K, D, SS> KdfWrapped<K, D, SS> {
+    /// Wrap the given inner KEM.
+    pub fn new(kem: K) -> Self {
+        Self {
+            kem,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<K: fmt::Debug, D, SS> fmt::Debug for KdfWrapped<K, D, SS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KdfWrapped")
+            .field("kem", &self.kem)
+            .finish()
+    }
+}
+
+impl<K, D, EK, SS> Encapsulate<EK, Output<D>> for KdfWrapped<K, D, SS>
+where
+    K: Encapsulate<EK, SS>,
+    D: Digest + Update,
+    EK: AsRef<[u8]>,
+    SS: AsRef<[u8]>,
+{
+    type Error = K::Error;
+
+    fn encapsulate(&self, rng: &mut impl CryptoRngCore) -> Result<(EK, Output<D>), Self::Error> {
+        let (ek, ss) = self.kem.encapsulate(rng)?;
+        let ss = derive::<D>(ss.as_ref(), ek.as_ref());
+        Ok((ek, ss))
+    }
+}
+
+impl<K, D, EK, SS> Decapsulate<EK, Output<D>> for KdfWrapped<K, D, SS>
+where
+    K: Decapsulate<EK, SS>,
+    D: Digest + Update,
+    EK: AsRef<[u8]>,
+    SS: AsRef<[u8]>,
+{
+    type Error = K::Error;
+
+    fn decapsulate(&self, encapsulated_key: &EK) -> Result<Output<D>, Self::Error> {
+        let ss = self.kem.decapsulate(encapsulated_key)?;
+        Ok(derive::<D>(ss.as_ref(), encapsulated_key.as_ref()))
+    }
+}
+
+/// Derive the final shared secret.
+fn derive<D: Digest + Update>(ss: &[u8], ek: &[u8]) -> Output<D> {
+    let mut d = D::new();
+    d.update_prefixed(ss);
+    d.update_prefixed(ek);
+    d.finalize()
+}
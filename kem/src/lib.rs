@@ -9,6 +9,20 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs, unused_qualifications, missing_debug_implementations)]
 
+mod deterministic;
+#[cfg(feature = "hybrid")]
+mod hybrid;
+#[cfg(feature = "kdf")]
+mod kdf;
+mod zeroizing;
+
+pub use deterministic::{EncapsulateDeterministic, SeedError, SeededEncapsulator};
+#[cfg(feature = "hybrid")]
+pub use hybrid::{Hybrid, HybridError};
+#[cfg(feature = "kdf")]
+pub use kdf::KdfWrapped;
+pub use zeroizing::{EncapsulateZeroizing, ZeroizingSharedSecret};
+
 use core::fmt::Debug;
 use rand_core::CryptoRngCore;
 
@@ -23,6 +37,58 @@ pub trait Encapsulate<EK, SS> {
     fn encapsulate(&self, rng: &mut impl CryptoRngCore) -> Result<(EK, SS), Self::Error>;
 }
 
+/// Encapsulation which writes the encapsulated key and shared secret directly
+/// into caller-provided buffers, avoiding allocations or large stack moves.
+///
+/// Callers use [`EncapsulateInto::encapsulate_into`], which checks the buffer
+/// lengths against [`KemSizes`] before calling the implementation provided in
+/// [`EncapsulateInto::encapsulate_into_exact`].
+pub trait EncapsulateInto: KemSizes {
+    /// Encapsulation error
+    type Error: Debug;
+
+    /// Encapsulates a fresh shared secret, writing the encapsulated key into
+    /// `ek_out` and the shared secret into `ss_out`.
+    ///
+    /// Returns [`EncapsulateIntoError::InvalidLength`] without calling
+    /// [`encapsulate_into_exact`][Self::encapsulate_into_exact] if `ek_out`
+    /// is not exactly [`KemSizes::ENCAPSULATED_KEY_SIZE`] bytes or `ss_out`
+    /// is not exactly [`KemSizes::SHARED_SECRET_SIZE`] bytes.
+    fn encapsulate_into(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        ek_out: &mut [u8],
+        ss_out: &mut [u8],
+    ) -> Result<(), EncapsulateIntoError<Self::Error>> {
+        if ek_out.len() != Self::ENCAPSULATED_KEY_SIZE || ss_out.len() != Self::SHARED_SECRET_SIZE {
+            return Err(EncapsulateIntoError::InvalidLength);
+        }
+        self.encapsulate_into_exact(rng, ek_out, ss_out)
+            .map_err(EncapsulateIntoError::Encapsulate)
+    }
+
+    /// Encapsulates a fresh shared secret into buffers whose lengths have
+    /// already been checked by [`encapsulate_into`][Self::encapsulate_into],
+    /// i.e. `ek_out` is [`KemSizes::ENCAPSULATED_KEY_SIZE`] bytes and
+    /// `ss_out` is [`KemSizes::SHARED_SECRET_SIZE`] bytes.
+    fn encapsulate_into_exact(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        ek_out: &mut [u8],
+        ss_out: &mut [u8],
+    ) -> Result<(), Self::Error>;
+}
+
+/// Error returned by [`EncapsulateInto::encapsulate_into`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EncapsulateIntoError<E> {
+    /// An output buffer does not have the size given by [`KemSizes`].
+    InvalidLength,
+
+    /// Error from the encapsulation.
+    Encapsulate(E),
+}
+
 /// A value that can be used to decapsulate an encapsulated key.
 ///
 /// Often, this will just be a secret key. But, as with [`Encapsulate`], it can be a bundle
@@ -34,3 +100,81 @@ pub trait Decapsulate<EK, SS> {
     /// Decapsulates the given encapsulated key
     fn decapsulate(&self, encapsulated_key: &EK) -> Result<SS, Self::Error>;
 }
+
+/// Sizes of the encapsulated keys and shared secrets produced by a KEM.
+///
+/// This is useful for pre-allocating buffers. See [`DynKemSizes`] for an
+/// object-safe equivalent.
+pub trait KemSizes {
+    /// Size of an encapsulated key in bytes.
+    const ENCAPSULATED_KEY_SIZE: usize;
+
+    /// Size of a shared secret in bytes.
+    const SHARED_SECRET_SIZE: usize;
+}
+
+/// Object-safe equivalent of [`KemSizes`], for use in a `dyn` context.
+///
+/// Automatically impl'd for any type that impls [`KemSizes`].
+pub trait DynKemSizes {
+    /// Size of an encapsulated key in bytes.
+    fn encapsulated_key_size(&self) -> usize;
+
+    /// Size of a shared secret in bytes.
+    fn shared_secret_size(&self) -> usize;
+}
+
+impl<T: KemSizes> DynKemSizes for T {
+    fn encapsulated_key_size(&self) -> usize {
+        T::ENCAPSULATED_KEY_SIZE
+    }
+
+    fn shared_secret_size(&self) -> usize {
+        T::SHARED_SECRET_SIZE
+    }
+}
+
+/// Decapsulation with implicit rejection, as used by IND-CCA KEMs such as
+/// ML-KEM.
+///
+/// Rather than returning an error, decapsulating an invalid encapsulated key
+/// returns a pseudorandom shared secret which is deterministically derived
+/// from the encapsulated key and a secret rejection value. This prevents
+/// leaking whether or not an encapsulated key was valid, which would
+/// otherwise make the KEM vulnerable to chosen-ciphertext attacks.
+///
+/// # Constant-time requirement
+/// Implementations MUST execute in constant time with respect to the
+/// validity of the encapsulated key: the valid and rejection paths must both
+/// be computed, with the result selected in constant time.
+pub trait DecapsulateImplicitReject<EK, SS> {
+    /// Decapsulates the given encapsulated key, returning a pseudorandom
+    /// shared secret if it's invalid.
+    fn decapsulate_ir(&self, encapsulated_key: &EK) -> SS;
+}
+
+/// Marker trait for authenticated encapsulation, where the encapsulator
+/// bundles a sender's private key so that the resulting shared secret is bound
+/// to the sender's identity (e.g. HPKE's `Auth` mode).
+///
+/// # Security
+/// A shared secret produced by an authenticated encapsulator can only be
+/// recovered by a decapsulator which expects the same sender, i.e. whose
+/// [`AuthDecapsulate::sender_public_key`] corresponds to the sender's private
+/// key. This provides sender authentication, but only insofar as the
+/// recipient has obtained the sender's public key through a trusted channel.
+pub trait AuthEncapsulate<EK, SS>: Encapsulate<EK, SS> {}
+
+/// Decapsulation counterpart of [`AuthEncapsulate`], which decapsulates keys
+/// bound to the identity of an expected sender.
+///
+/// Decapsulation MUST fail (or, for KEMs with implicit rejection, produce an
+/// unrelated shared secret) if the encapsulated key was not produced by the
+/// sender identified by [`AuthDecapsulate::sender_public_key`].
+pub trait AuthDecapsulate<EK, SS>: Decapsulate<EK, SS> {
+    /// Sender public key type
+    type SenderPublicKey;
+
+    /// Public key of the sender whose encapsulations this value expects.
+    fn sender_public_key(&self) -> &Self::SenderPublicKey;
+}
@@ -0,0 +1,85 @@
+//! Shared secrets which are zeroized on drop.
+
+use crate::Encapsulate;
+use core::fmt;
+use rand_core::CryptoRngCore;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Shared secret of `N` bytes which is zeroized on drop.
+///
+/// It can be used as the `SS` type parameter of [`Encapsulate`] and
+/// [`Decapsulate`][crate::Decapsulate] implementations. For KEMs which
+/// produce plain `[u8; N]` shared secrets see
+/// [`EncapsulateZeroizing::encapsulate_zeroizing`].
+///
+/// The [`Debug`][fmt::Debug] impl does not print the secret bytes.
+pub struct ZeroizingSharedSecret<const N: usize>([u8; N]);
+
+impl<const N: usize> ZeroizingSharedSecret<N> {
+    /// Get the shared secret bytes.
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for ZeroizingSharedSecret<N> {
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for ZeroizingSharedSecret<N> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> fmt::Debug for ZeroizingSharedSecret<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ZeroizingSharedSecret(...)")
+    }
+}
+
+impl<const N: usize> Zeroize for ZeroizingSharedSecret<N> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const N: usize> Drop for ZeroizingSharedSecret<N> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<const N: usize> ZeroizeOnDrop for ZeroizingSharedSecret<N> {}
+
+/// Encapsulation which returns the shared secret wrapped into
+/// [`ZeroizingSharedSecret`].
+///
+/// This trait is automatically impl'd for any type that impls [`Encapsulate`]
+/// with `[u8; N]` shared secrets.
+pub trait EncapsulateZeroizing<EK, const N: usize> {
+    /// Encapsulation error
+    type Error: fmt::Debug;
+
+    /// Encapsulates a fresh shared secret which is zeroized on drop.
+    fn encapsulate_zeroizing(
+        &self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(EK, ZeroizingSharedSecret<N>), Self::Error>;
+}
+
+impl<EK, const N: usize, T: Encapsulate<EK, [u8; N]>> EncapsulateZeroizing<EK, N> for T {
+    type Error = T::Error;
+
+    fn encapsulate_zeroizing(
+        &self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(EK, ZeroizingSharedSecret<N>), Self::Error> {
+        let (ek, mut ss) = self.encapsulate(rng)?;
+        let wrapped = ZeroizingSharedSecret::from(ss);
+        ss.zeroize();
+        Ok((ek, wrapped))
+    }
+}
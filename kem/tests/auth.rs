@@ -0,0 +1,85 @@
+mod common;
+
+use common::{xor, ToyKey};
+use kem::{AuthDecapsulate, AuthEncapsulate, Decapsulate, Encapsulate};
+
+use rand_core::CryptoRngCore;
+
+/// Encapsulated key consisting of the shared secret masked with the recipient
+/// key and with the sender key, respectively.
+struct ToyEncappedKey([u8; 16], [u8; 16]);
+
+type SharedSecret = [u8; 16];
+
+/// To encap, we need the recipient's public key and the sender's private key
+struct EncapContext {
+    recipient: ToyKey,
+    sender: ToyKey,
+}
+
+/// To decap, we need the recipient's private key and the sender's public key
+struct DecapContext {
+    recipient: ToyKey,
+    sender: ToyKey,
+}
+
+impl Encapsulate<ToyEncappedKey, SharedSecret> for EncapContext {
+    type Error = ();
+
+    fn encapsulate(
+        &self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(ToyEncappedKey, SharedSecret), ()> {
+        let mut ss = [0u8; 16];
+        rng.fill_bytes(&mut ss);
+        let ek = ToyEncappedKey(xor(&ss, &self.recipient.0), xor(&ss, &self.sender.0));
+        Ok((ek, ss))
+    }
+}
+
+impl AuthEncapsulate<ToyEncappedKey, SharedSecret> for EncapContext {}
+
+impl Decapsulate<ToyEncappedKey, SharedSecret> for DecapContext {
+    type Error = &'static str;
+
+    fn decapsulate(&self, ek: &ToyEncappedKey) -> Result<SharedSecret, Self::Error> {
+        let ss = xor(&ek.0, &self.recipient.0);
+
+        if xor(&ek.1, &self.sender_public_key().0) != ss {
+            return Err("unexpected sender");
+        }
+
+        Ok(ss)
+    }
+}
+
+impl AuthDecapsulate<ToyEncappedKey, SharedSecret> for DecapContext {
+    type SenderPublicKey = ToyKey;
+
+    fn sender_public_key(&self) -> &ToyKey {
+        &self.sender
+    }
+}
+
+#[test]
+fn test_auth_kem() {
+    let mut rng = rand::thread_rng();
+    let recipient = ToyKey([1; 16]);
+    let sender = ToyKey([2; 16]);
+    let impostor = ToyKey([3; 16]);
+
+    let encap = EncapContext { recipient, sender };
+    let (ek, ss1) = encap.encapsulate(&mut rng).unwrap();
+
+    // Decapsulation succeeds when the expected sender is the actual sender
+    let decap = DecapContext { recipient, sender };
+    assert_eq!(decap.sender_public_key(), &sender);
+    assert_eq!(decap.decapsulate(&ek).unwrap(), ss1);
+
+    // Decapsulation fails when expecting a different sender
+    let decap = DecapContext {
+        recipient,
+        sender: impostor,
+    };
+    assert!(decap.decapsulate(&ek).is_err());
+}
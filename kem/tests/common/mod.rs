@@ -0,0 +1,73 @@
+//! Toy KEM shared by the integration tests.
+
+#![allow(dead_code)]
+
+use kem::{Decapsulate, Encapsulate, KemSizes};
+use rand_core::CryptoRngCore;
+
+/// Toy KEM where the "encapsulated key" is the shared secret XORed with the key.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ToyKey<const N: usize = 16>(pub [u8; N]);
+
+/// XOR two byte arrays.
+pub fn xor<const N: usize>(a: &[u8; N], b: &[u8; N]) -> [u8; N] {
+    core::array::from_fn(|i| a[i] ^ b[i])
+}
+
+impl<const N: usize> KemSizes for ToyKey<N> {
+    const ENCAPSULATED_KEY_SIZE: usize = N;
+    const SHARED_SECRET_SIZE: usize = N;
+}
+
+impl<const N: usize> Encapsulate<[u8; N], [u8; N]> for ToyKey<N> {
+    type Error = ();
+
+    fn encapsulate(&self, rng: &mut impl CryptoRngCore) -> Result<([u8; N], [u8; N]), ()> {
+        let mut ss = [0u8; N];
+        rng.fill_bytes(&mut ss);
+        Ok((xor(&ss, &self.0), ss))
+    }
+}
+
+impl<const N: usize> Decapsulate<[u8; N], [u8; N]> for ToyKey<N> {
+    type Error = &'static str;
+
+    fn decapsulate(&self, ek: &[u8; N]) -> Result<[u8; N], Self::Error> {
+        if ek.iter().all(|&b| b == 0) {
+            return Err("invalid encapsulated key");
+        }
+
+        Ok(xor(ek, &self.0))
+    }
+}
+
+/// Toy order-sensitive hash function with a 64-bit output.
+#[cfg(any(feature = "hybrid", feature = "kdf"))]
+#[derive(Clone, Default)]
+pub struct ToyDigest(u64);
+
+#[cfg(any(feature = "hybrid", feature = "kdf"))]
+mod toy_digest {
+    use super::ToyDigest;
+    use digest::{consts::U8, FixedOutput, HashMarker, Output, OutputSizeUser, Update};
+
+    impl HashMarker for ToyDigest {}
+
+    impl Update for ToyDigest {
+        fn update(&mut self, data: &[u8]) {
+            for &b in data {
+                self.0 = (self.0.rotate_left(5) ^ u64::from(b)).wrapping_mul(0x0100_0000_01B3);
+            }
+        }
+    }
+
+    impl OutputSizeUser for ToyDigest {
+        type OutputSize = U8;
+    }
+
+    impl FixedOutput for ToyDigest {
+        fn finalize_into(self, out: &mut Output<Self>) {
+            out.copy_from_slice(&self.0.to_be_bytes());
+        }
+    }
+}
@@ -0,0 +1,51 @@
+mod common;
+
+use common::{xor, ToyKey};
+use kem::{Decapsulate, EncapsulateDeterministic, SeedError, SeededEncapsulator};
+
+/// Toy KEM with its own deterministic encapsulation, taking the shared
+/// secret itself as the seed like ML-KEM takes its message `m`.
+struct KatKey([u8; 16]);
+
+impl EncapsulateDeterministic<[u8; 16], [u8; 16]> for KatKey {
+    type Error = ();
+
+    fn encapsulate_deterministic(&self, seed: &[u8]) -> Result<([u8; 16], [u8; 16]), ()> {
+        let ss: [u8; 16] = seed.try_into().map_err(|_| ())?;
+        Ok((xor(&ss, &self.0), ss))
+    }
+}
+
+#[test]
+fn test_encapsulate_deterministic() {
+    let key = SeededEncapsulator::new(ToyKey([0x42; 16]));
+
+    let (ek1, ss1) = key.encapsulate_deterministic(&[1; 16]).unwrap();
+    let (ek2, ss2) = key.encapsulate_deterministic(&[1; 16]).unwrap();
+    assert_eq!(ek1, ek2);
+    assert_eq!(ss1, ss2);
+    assert_eq!(ss1, [1; 16]);
+    assert_eq!(key.inner().decapsulate(&ek1).unwrap(), ss1);
+
+    let (ek3, _) = key.encapsulate_deterministic(&[2; 16]).unwrap();
+    assert_ne!(ek1, ek3);
+}
+
+#[test]
+fn test_encapsulate_deterministic_short_seed() {
+    let key = SeededEncapsulator::new(ToyKey([0x42; 16]));
+    assert_eq!(
+        key.encapsulate_deterministic(&[1; 8]),
+        Err(SeedError::SeedExhausted)
+    );
+}
+
+#[test]
+fn test_custom_encapsulate_deterministic() {
+    let key = KatKey([0x42; 16]);
+    let (ek, ss) = key.encapsulate_deterministic(&[7; 16]).unwrap();
+    assert_eq!(ss, [7; 16]);
+    assert_eq!(ek, [7 ^ 0x42; 16]);
+    assert_eq!(key.encapsulate_deterministic(&[7; 16]).unwrap(), (ek, ss));
+    assert!(key.encapsulate_deterministic(&[7; 15]).is_err());
+}
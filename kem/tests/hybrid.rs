@@ -0,0 +1,56 @@
+#![cfg(feature = "hybrid")]
+
+mod common;
+
+use common::{ToyDigest, ToyKey};
+use digest::{Digest, Update};
+use kem::{Decapsulate, Encapsulate, Hybrid, HybridError};
+
+type ToyHybrid = Hybrid<ToyKey<16>, ToyKey<32>, ToyDigest, [u8; 16], [u8; 32]>;
+
+#[test]
+fn test_hybrid() {
+    let mut rng = rand::thread_rng();
+    let kem = ToyHybrid::new(ToyKey([1; 16]), ToyKey([2; 32]));
+
+    let (ek, ss1) = kem.encapsulate(&mut rng).unwrap();
+    let ss2 = kem.decapsulate(&ek).unwrap();
+    assert_eq!(ss1, ss2);
+
+    // The combined secret changes if either encapsulated key is tampered with
+    let tampered = (ek.0, [0xff; 32]);
+    assert_ne!(kem.decapsulate(&tampered).unwrap(), ss1);
+}
+
+#[test]
+fn test_hybrid_encoding() {
+    let kem = ToyHybrid::new(ToyKey([1; 16]), ToyKey([2; 32]));
+    let ek = ([3; 16], [4; 32]);
+    let ss = kem.decapsulate(&ek).unwrap();
+
+    // Every input is length-prefixed and the label comes first
+    let mut d = ToyDigest::new();
+    d.update_prefixed(ToyHybrid::LABEL);
+    d.update_prefixed(&[3 ^ 1; 16]);
+    d.update_prefixed(&[4 ^ 2; 32]);
+    d.update_prefixed(&ek.0);
+    d.update_prefixed(&ek.1);
+    assert_eq!(ss, d.finalize());
+
+    let plain = ToyDigest::new()
+        .chain_update([3 ^ 1; 16])
+        .chain_update([4 ^ 2; 32])
+        .chain_update(ek.0)
+        .chain_update(ek.1)
+        .finalize();
+    assert_ne!(ss, plain);
+}
+
+#[test]
+fn test_hybrid_error() {
+    let kem = ToyHybrid::new(ToyKey([1; 16]), ToyKey([2; 32]));
+    assert_eq!(
+        kem.decapsulate(&([1; 16], [0; 32])),
+        Err(HybridError::Second("invalid encapsulated key"))
+    );
+}
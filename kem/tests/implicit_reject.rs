@@ -0,0 +1,77 @@
+mod common;
+
+use common::{xor, ToyKey};
+use kem::{DecapsulateImplicitReject, Encapsulate};
+
+use rand_core::CryptoRngCore;
+
+/// Toy KEM with implicit rejection.
+struct ToyIrKey {
+    key: ToyKey,
+    /// Secret value used to derive shared secrets on rejection
+    z: [u8; 16],
+}
+
+/// Encapsulated key consisting of the masked shared secret and a checksum.
+#[derive(Clone, Copy)]
+struct ToyEncappedKey([u8; 16], [u8; 16]);
+
+/// Position-dependent checksum of the concatenation of `parts`.
+fn mix(parts: &[&[u8]]) -> [u8; 16] {
+    let mut sum = [0u8; 16];
+    for (i, b) in parts.iter().flat_map(|part| part.iter()).enumerate() {
+        sum[i % 16] = sum[i % 16].wrapping_add(*b).rotate_left(3);
+    }
+    sum
+}
+
+fn checksum(ss: &[u8; 16]) -> [u8; 16] {
+    mix(&[b"checksum", ss])
+}
+
+impl Encapsulate<ToyEncappedKey, [u8; 16]> for ToyIrKey {
+    type Error = ();
+
+    fn encapsulate(&self, rng: &mut impl CryptoRngCore) -> Result<(ToyEncappedKey, [u8; 16]), ()> {
+        let (masked, ss) = self.key.encapsulate(rng)?;
+        Ok((ToyEncappedKey(masked, checksum(&ss)), ss))
+    }
+}
+
+impl DecapsulateImplicitReject<ToyEncappedKey, [u8; 16]> for ToyIrKey {
+    fn decapsulate_ir(&self, ek: &ToyEncappedKey) -> [u8; 16] {
+        let ss = xor(&ek.0, &self.key.0);
+        let rejected = mix(&[&self.z, &ek.0, &ek.1]);
+
+        // NOTE: a real implementation must select the result in constant time
+        if checksum(&ss) == ek.1 {
+            ss
+        } else {
+            rejected
+        }
+    }
+}
+
+#[test]
+fn test_implicit_reject() {
+    let mut rng = rand::thread_rng();
+    let key = ToyIrKey {
+        key: ToyKey([1; 16]),
+        z: [2; 16],
+    };
+
+    let (ek, ss) = key.encapsulate(&mut rng).unwrap();
+    assert_eq!(key.decapsulate_ir(&ek), ss);
+
+    // Invalid encapsulated keys yield a deterministic but different secret
+    let mut invalid = ek;
+    invalid.1[0] ^= 1;
+    let rejected = key.decapsulate_ir(&invalid);
+    assert_ne!(rejected, ss);
+    assert_eq!(key.decapsulate_ir(&invalid), rejected);
+
+    // Different invalid encapsulated keys yield different secrets
+    let mut invalid2 = ek;
+    invalid2.1[1] ^= 1;
+    assert_ne!(key.decapsulate_ir(&invalid2), rejected);
+}
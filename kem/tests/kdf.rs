@@ -0,0 +1,40 @@
+#![cfg(feature = "kdf")]
+
+mod common;
+
+use common::{ToyDigest, ToyKey};
+use digest::{FixedOutput, Update};
+use kem::{Decapsulate, Encapsulate, KdfWrapped};
+
+type ToyKdfKem = KdfWrapped<ToyKey, ToyDigest, [u8; 16]>;
+
+#[test]
+fn test_kdf_wrapped() {
+    let mut rng = rand::thread_rng();
+    let kem = ToyKdfKem::new(ToyKey([1; 16]));
+
+    let (ek, ss1) = kem.encapsulate(&mut rng).unwrap();
+    let ss2 = kem.decapsulate(&ek).unwrap();
+    assert_eq!(ss1, ss2);
+
+    // Derived secret is `D(P(ss_raw) || P(ek))`
+    let raw = kem.kem.decapsulate(&ek).unwrap();
+    let mut d = ToyDigest::default();
+    d.update_prefixed(&raw);
+    d.update_prefixed(&ek);
+    assert_eq!(ss1, d.finalize_fixed());
+
+    let mut swapped = ToyDigest::default();
+    swapped.update_prefixed(&ek);
+    swapped.update_prefixed(&raw);
+    assert_ne!(ss1, swapped.finalize_fixed());
+
+    let plain = ToyDigest::default().chain(raw).chain(ek).finalize_fixed();
+    assert_ne!(ss1, plain);
+}
+
+#[test]
+fn test_kdf_wrapped_error() {
+    let kem = ToyKdfKem::new(ToyKey([1; 16]));
+    assert_eq!(kem.decapsulate(&[0; 16]), Err("invalid encapsulated key"));
+}
@@ -0,0 +1,76 @@
+mod common;
+
+use common::ToyKey;
+use kem::{Decapsulate, DynKemSizes, Encapsulate, EncapsulateInto, EncapsulateIntoError, KemSizes};
+
+use rand_core::CryptoRngCore;
+
+impl EncapsulateInto for ToyKey {
+    type Error = ();
+
+    /// Performs no length checks of its own: a mismatch would silently
+    /// produce a truncated output.
+    fn encapsulate_into_exact(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        ek_out: &mut [u8],
+        ss_out: &mut [u8],
+    ) -> Result<(), ()> {
+        rng.fill_bytes(ss_out);
+        for ((ek, ss), k) in ek_out.iter_mut().zip(ss_out.iter()).zip(&self.0) {
+            *ek = ss ^ k;
+        }
+
+        Ok(())
+    }
+}
+
+#[test]
+fn test_kem_sizes() {
+    let mut rng = rand::thread_rng();
+    let key = ToyKey([0x42; 16]);
+
+    let (ek, ss1) = key.encapsulate(&mut rng).unwrap();
+    assert_eq!(ek.len(), <ToyKey>::ENCAPSULATED_KEY_SIZE);
+    assert_eq!(ss1.len(), <ToyKey>::SHARED_SECRET_SIZE);
+
+    // Pre-size buffers using the object-safe accessors
+    let sizes: &dyn DynKemSizes = &key;
+    let mut ek_buf = vec![0u8; sizes.encapsulated_key_size()];
+    let mut ss_buf = vec![0u8; sizes.shared_secret_size()];
+    ek_buf.copy_from_slice(&ek);
+    ss_buf.copy_from_slice(&ss1);
+
+    let ss2 = key.decapsulate(&ek_buf[..].try_into().unwrap()).unwrap();
+    assert_eq!(ss_buf, ss2);
+}
+
+#[test]
+fn test_encapsulate_into() {
+    let mut rng = rand::thread_rng();
+    let key = ToyKey([0x42; 16]);
+
+    let mut ek = [0u8; <ToyKey>::ENCAPSULATED_KEY_SIZE];
+    let mut ss = [0u8; <ToyKey>::SHARED_SECRET_SIZE];
+    key.encapsulate_into(&mut rng, &mut ek, &mut ss).unwrap();
+    assert_eq!(key.decapsulate(&ek).unwrap(), ss);
+
+    let mut short_ek = [0u8; <ToyKey>::ENCAPSULATED_KEY_SIZE - 1];
+    assert_eq!(
+        key.encapsulate_into(&mut rng, &mut short_ek, &mut ss),
+        Err(EncapsulateIntoError::InvalidLength)
+    );
+
+    let mut short_ss = [0u8; <ToyKey>::SHARED_SECRET_SIZE - 1];
+    assert_eq!(
+        key.encapsulate_into(&mut rng, &mut ek, &mut short_ss),
+        Err(EncapsulateIntoError::InvalidLength)
+    );
+
+    let mut long_ss = [0u8; <ToyKey>::SHARED_SECRET_SIZE + 1];
+    assert_eq!(
+        key.encapsulate_into(&mut rng, &mut ek, &mut long_ss),
+        Err(EncapsulateIntoError::InvalidLength)
+    );
+    assert_eq!(long_ss, [0u8; <ToyKey>::SHARED_SECRET_SIZE + 1]);
+}
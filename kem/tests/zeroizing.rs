@@ -0,0 +1,50 @@
+mod common;
+
+use common::{xor, ToyKey};
+use core::{mem::MaybeUninit, ptr};
+use kem::{Decapsulate, EncapsulateZeroizing, ZeroizingSharedSecret};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+impl Decapsulate<[u8; 16], ZeroizingSharedSecret<16>> for ToyKey {
+    type Error = ();
+
+    fn decapsulate(&self, ek: &[u8; 16]) -> Result<ZeroizingSharedSecret<16>, ()> {
+        Ok(xor(ek, &self.0).into())
+    }
+}
+
+fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+
+#[test]
+fn encapsulate_zeroizing_roundtrip() {
+    let key = ToyKey([0x42; 16]);
+    let (ek, ss) = key.encapsulate_zeroizing(&mut rand::thread_rng()).unwrap();
+    let decapsulated: ZeroizingSharedSecret<16> = key.decapsulate(&ek).unwrap();
+    assert_eq!(decapsulated.as_bytes(), ss.as_bytes());
+    assert_zeroize_on_drop::<ZeroizingSharedSecret<16>>();
+}
+
+#[test]
+fn debug_does_not_leak() {
+    let ss = ZeroizingSharedSecret::from([0xAB; 4]);
+    assert_eq!(format!("{ss:?}"), "ZeroizingSharedSecret(...)");
+}
+
+#[test]
+fn zeroize_wipes_bytes() {
+    let mut ss = ZeroizingSharedSecret::from([0xAB; 32]);
+    ss.zeroize();
+    assert_eq!(ss.as_bytes(), &[0; 32]);
+}
+
+#[test]
+fn drop_wipes_bytes() {
+    let mut slot = MaybeUninit::new(ZeroizingSharedSecret::from([0xAB; 32]));
+    // SAFETY: `slot` is initialized and dropped exactly once. The memory is
+    // not deallocated, so its bytes can be inspected after the drop.
+    let bytes = unsafe {
+        ptr::drop_in_place(slot.as_mut_ptr());
+        *(*slot.as_ptr()).as_bytes()
+    };
+    assert_eq!(bytes, [0; 32]);
+}
@@ -0,0 +1,118 @@
+//! Typed identifiers of well-known password hashing algorithms.
+
+use crate::{Error, Ident, Result};
+use core::{fmt, str::FromStr};
+
+/// Identifier of a well-known password hashing algorithm.
+///
+/// This type allows matching on a typed value instead of comparing strings
+/// and rejecting unknown algorithms early. Algorithms which are not listed
+/// here can still be used through the string-based [`Ident`] APIs.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum AlgorithmId {
+    /// Argon2id (`argon2id`).
+    Argon2id,
+
+    /// scrypt (`scrypt`).
+    Scrypt,
+
+    /// PBKDF2 with HMAC-SHA-256 (`pbkdf2-sha256`).
+    Pbkdf2Sha256,
+
+    /// bcrypt (`bcrypt`).
+    Bcrypt,
+}
+
+impl AlgorithmId {
+    /// Get the identifier string of this algorithm.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Argon2id => "argon2id",
+            Self::Scrypt => "scrypt",
+            Self::Pbkdf2Sha256 => "pbkdf2-sha256",
+            Self::Bcrypt => "bcrypt",
+        }
+    }
+
+    /// Get the identifier of this algorithm as an [`Ident`].
+    pub const fn ident(self) -> Ident<'static> {
+        Ident::new_unwrap(self.as_str())
+    }
+}
+
+impl AsRef<str> for AlgorithmId {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl FromStr for AlgorithmId {
+    type Err = Error;
+
+    /// Parse an algorithm identifier.
+    ///
+    /// Returns [`Error::Algorithm`] if the algorithm is not known.
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "argon2id" => Ok(Self::Argon2id),
+            "scrypt" => Ok(Self::Scrypt),
+            "pbkdf2-sha256" => Ok(Self::Pbkdf2Sha256),
+            "bcrypt" => Ok(Self::Bcrypt),
+            _ => Err(Error::Algorithm),
+        }
+    }
+}
+
+impl TryFrom<Ident<'_>> for AlgorithmId {
+    type Error = Error;
+
+    fn try_from(ident: Ident<'_>) -> Result<Self> {
+        ident.as_str().parse()
+    }
+}
+
+impl From<AlgorithmId> for Ident<'static> {
+    fn from(id: AlgorithmId) -> Ident<'static> {
+        id.ident()
+    }
+}
+
+impl fmt::Display for AlgorithmId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AlgorithmId, Error, Ident};
+
+    const KNOWN: [AlgorithmId; 4] = [
+        AlgorithmId::Argon2id,
+        AlgorithmId::Scrypt,
+        AlgorithmId::Pbkdf2Sha256,
+        AlgorithmId::Bcrypt,
+    ];
+
+    #[test]
+    fn parse_known() {
+        for id in KNOWN {
+            assert_eq!(id.as_str().parse::<AlgorithmId>(), Ok(id));
+            assert_eq!(AlgorithmId::try_from(id.ident()), Ok(id));
+            assert_eq!(Ident::from(id).as_str(), id.as_str());
+        }
+        assert_eq!("pbkdf2-sha256".parse(), Ok(AlgorithmId::Pbkdf2Sha256));
+    }
+
+    #[test]
+    fn reject_unknown() {
+        for s in ["argon2", "ARGON2ID", "pbkdf2-sha512", "md5", ""] {
+            assert_eq!(s.parse::<AlgorithmId>(), Err(Error::Algorithm));
+        }
+        assert_eq!(
+            AlgorithmId::try_from(Ident::new_unwrap("sha512-crypt")),
+            Err(Error::Algorithm)
+        );
+    }
+}
@@ -37,6 +37,10 @@ pub enum Error {
         expected: usize,
     },
 
+    /// I/O error while reading a password from a stream.
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+
     /// Duplicate parameter name encountered.
     ParamNameDuplicated,
 
@@ -84,6 +88,8 @@ impl fmt::Display for Error {
                     expected
                 ),
             },
+            #[cfg(feature = "std")]
+            Self::Io(kind) => write!(f, "I/O error: {}", kind),
             Self::ParamNameDuplicated => f.write_str("duplicate parameter"),
             Self::ParamNameInvalid => f.write_str("invalid parameter name"),
             Self::ParamValueInvalid(val_err) => write!(f, "invalid parameter value: {}", val_err),
@@ -123,6 +129,75 @@ impl From<base64ct::InvalidLengthError> for Error {
     }
 }
 
+#[cfg(feature = "std")]
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Error {
+        Error::Io(err.kind())
+    }
+}
+
+/// Detailed password verification errors.
+///
+/// Returned by [`PasswordVerifier::verify_password_detailed`] to distinguish
+/// the reason verification failed.
+///
+/// [`PasswordVerifier::verify_password_detailed`]: crate::PasswordVerifier::verify_password_detailed
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VerifyError {
+    /// Password hash is missing a salt and/or hash output to verify against.
+    NoHash,
+
+    /// Password hash uses an algorithm which is not supported by the verifier.
+    AlgorithmMismatch,
+
+    /// Password does not match the hash.
+    PasswordInvalid,
+
+    /// Other error encountered while computing the password hash.
+    Other(Error),
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> core::result::Result<(), fmt::Error> {
+        match self {
+            Self::NoHash => f.write_str("password hash missing salt or hash output"),
+            Self::AlgorithmMismatch => f.write_str("unsupported algorithm"),
+            Self::PasswordInvalid => f.write_str("invalid password"),
+            Self::Other(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl core::error::Error for VerifyError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Other(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for VerifyError {
+    fn from(err: Error) -> VerifyError {
+        match err {
+            Error::Algorithm => VerifyError::AlgorithmMismatch,
+            Error::Password => VerifyError::PasswordInvalid,
+            other => VerifyError::Other(other),
+        }
+    }
+}
+
+impl From<VerifyError> for Error {
+    fn from(err: VerifyError) -> Error {
+        match err {
+            VerifyError::NoHash | VerifyError::PasswordInvalid => Error::Password,
+            VerifyError::AlgorithmMismatch => Error::Algorithm,
+            VerifyError::Other(other) => other,
+        }
+    }
+}
+
 /// Parse errors relating to invalid parameter values or salts.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
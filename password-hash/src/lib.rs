@@ -27,28 +27,35 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "rand_core")]
 pub use rand_core;
+pub use subtle;
 
 pub mod errors;
 
+mod algorithm;
 mod encoding;
 mod ident;
 mod output;
 mod params;
+mod raw;
 mod salt;
 mod traits;
 mod value;
 
 pub use crate::{
+    algorithm::AlgorithmId,
     encoding::Encoding,
-    errors::{Error, Result},
+    errors::{Error, Result, VerifyError},
     ident::Ident,
     output::Output,
     params::ParamsString,
+    raw::RawHash,
     salt::{Salt, SaltString},
-    traits::{McfHasher, PasswordHasher, PasswordVerifier},
+    traits::{McfHasher, PasswordHasher, PasswordVerifier, RawPasswordHasher, TunableParams},
     value::{Decimal, Value},
 };
 
@@ -192,6 +192,15 @@ impl Output {
         usize::from(self.length)
     }
 
+    /// Compare this output with `other` in constant time.
+    ///
+    /// Outputs of different lengths are never equal. Only the lengths, which
+    /// are not secret, may be leaked through timing. The encoding is not
+    /// taken into account.
+    pub fn ct_eq(&self, other: &Output) -> Choice {
+        self.as_bytes().ct_eq(other.as_bytes())
+    }
+
     /// Parse B64-encoded [`Output`], i.e. using the PHC string
     /// specification's restricted interpretation of Base64.
     pub fn b64_decode(input: &str) -> Result<Self> {
@@ -234,7 +243,7 @@ impl AsRef<[u8]> for Output {
 
 impl ConstantTimeEq for Output {
     fn ct_eq(&self, other: &Self) -> Choice {
-        self.as_ref().ct_eq(other.as_ref())
+        Output::ct_eq(self, other)
     }
 }
 
@@ -332,4 +341,29 @@ mod tests {
         let b = Output::new(&[2u8; 32]).unwrap();
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn ct_eq() {
+        let a = Output::new(&[1u8; 32]).unwrap();
+        let b = Output::new(&[1u8; 32]).unwrap();
+        assert!(bool::from(a.ct_eq(&b)));
+
+        let mut bytes = [1u8; 32];
+        bytes[31] = 2;
+        let c = Output::new(&bytes).unwrap();
+        assert!(!bool::from(a.ct_eq(&c)));
+
+        // Prefix of a different length
+        let d = Output::new(&[1u8; 31]).unwrap();
+        assert!(!bool::from(a.ct_eq(&d)));
+    }
+
+    #[test]
+    fn len() {
+        for len in [Output::MIN_LENGTH, 32, Output::MAX_LENGTH] {
+            let output = Output::new(&[0xAB; Output::MAX_LENGTH][..len]).unwrap();
+            assert_eq!(output.len(), len);
+            assert_eq!(output.as_bytes().len(), len);
+        }
+    }
 }
@@ -0,0 +1,71 @@
+//! Raw password hashes stored without PHC string encoding.
+
+use crate::{errors::InvalidValue, Output, Result, Salt};
+use core::fmt;
+
+/// Password hash as raw salt and hash output bytes.
+///
+/// This is intended for systems which store the salt and the hash in
+/// separate binary columns rather than as a PHC string, allowing them to
+/// avoid serializing and parsing strings on every login.
+#[derive(Clone, Eq, PartialEq)]
+pub struct RawHash {
+    /// Raw salt bytes.
+    salt: [u8; Self::SALT_MAX_LENGTH],
+
+    /// Length of the salt in bytes.
+    salt_length: u8,
+
+    /// Password hashing function output.
+    hash: Output,
+}
+
+impl RawHash {
+    /// Maximum length of the raw salt in bytes: 48-bytes.
+    ///
+    /// This is the number of bytes which can be B64-encoded into a [`Salt`]
+    /// of [`Salt::MAX_LENGTH`] characters.
+    pub const SALT_MAX_LENGTH: usize = Salt::MAX_LENGTH * 3 / 4;
+
+    /// Create a [`RawHash`] from the given raw salt and hash output.
+    ///
+    /// Returns [`Error::SaltInvalid`][crate::Error::SaltInvalid] if the salt is longer than
+    /// [`RawHash::SALT_MAX_LENGTH`].
+    pub fn new(salt: &[u8], hash: Output) -> Result<Self> {
+        let mut bytes = [0u8; Self::SALT_MAX_LENGTH];
+        bytes
+            .get_mut(..salt.len())
+            .ok_or(InvalidValue::TooLong.salt_error())?
+            .copy_from_slice(salt);
+
+        Ok(Self {
+            salt: bytes,
+            salt_length: salt.len() as u8,
+            hash,
+        })
+    }
+
+    /// Borrow the raw salt bytes.
+    pub fn salt_bytes(&self) -> &[u8] {
+        &self.salt[..usize::from(self.salt_length)]
+    }
+
+    /// Borrow the raw hash output bytes.
+    pub fn hash_bytes(&self) -> &[u8] {
+        self.hash.as_bytes()
+    }
+
+    /// Borrow the hash output as an [`Output`].
+    pub fn output(&self) -> &Output {
+        &self.hash
+    }
+}
+
+impl fmt::Debug for RawHash {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawHash")
+            .field("salt", &self.salt_bytes())
+            .field("hash", &self.hash)
+            .finish()
+    }
+}
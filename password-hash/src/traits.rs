@@ -1,7 +1,14 @@
 //! Trait definitions.
 
-use crate::{Decimal, Error, Ident, ParamsString, PasswordHash, Result, Salt};
+use crate::{
+    Decimal, Error, Ident, ParamsString, PasswordHash, RawHash, Result, Salt, SaltString,
+    VerifyError,
+};
 use core::fmt::Debug;
+use subtle::ConstantTimeEq;
+
+#[cfg(all(feature = "alloc", feature = "rand_core"))]
+use {crate::PasswordHashString, rand_core::CryptoRngCore};
 
 /// Trait for password hashing functions.
 pub trait PasswordHasher {
@@ -17,6 +24,9 @@ pub trait PasswordHasher {
     /// defaults.
     ///
     /// When in doubt, use [`PasswordHasher::hash_password`] instead.
+    ///
+    /// Implementations MUST reject `params` for which
+    /// [`PasswordHasher::validate_params`] returns an error.
     fn hash_password_customized<'a>(
         &self,
         password: &[u8],
@@ -35,7 +45,240 @@ pub trait PasswordHasher {
         password: &[u8],
         salt: impl Into<Salt<'a>>,
     ) -> Result<PasswordHash<'a>> {
-        self.hash_password_customized(password, None, None, Self::Params::default(), salt)
+        let params = self.recommended_params();
+        self.validate_params(&params)?;
+        self.hash_password_customized(password, None, None, params, salt)
+    }
+
+    /// Compute a [`PasswordHash`] from the provided password using a random
+    /// [`Salt`] of [`Salt::RECOMMENDED_LENGTH`] bytes drawn from the provided
+    /// RNG, along with the default recommended parameters.
+    ///
+    /// This is useful for deterministic tests and custom entropy sources.
+    /// The result is returned as an owned [`PasswordHashString`] since it
+    /// contains the generated salt.
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    fn hash_password_with_rng(
+        &self,
+        password: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<PasswordHashString> {
+        let salt = SaltString::generate(rng);
+        self.hash_password(password, &salt).map(Into::into)
+    }
+
+    /// Verify the provided password against a raw hash output which was
+    /// stored separately, rather than as part of a [`PasswordHash`].
+    ///
+    /// Computes the hash of `password` using the given `salt` and `params`
+    /// and compares its output against `expected` in constant time.
+    ///
+    /// This is intended for systems migrating from bespoke storage formats.
+    fn verify_password_against_output<'a>(
+        &self,
+        password: &[u8],
+        salt: impl Into<Salt<'a>>,
+        params: Self::Params,
+        expected: &[u8],
+    ) -> Result<()> {
+        self.validate_params(&params)?;
+        let computed_hash = self.hash_password_customized(password, None, None, params, salt)?;
+        let computed_output = computed_hash.hash.ok_or(Error::Password)?;
+
+        if computed_output.as_bytes().ct_eq(expected).into() {
+            Ok(())
+        } else {
+            Err(Error::Password)
+        }
+    }
+
+    /// Check that the provided parameters are acceptable for use with this
+    /// password hashing function, e.g. that cost parameters are within the
+    /// bounds of some policy.
+    ///
+    /// This is invoked by the provided [`PasswordHasher::hash_password`] and
+    /// [`PasswordHasher::verify_password_against_output`] methods before
+    /// hashing. Callers of [`PasswordHasher::hash_password_customized`] which
+    /// don't trust the implementation to enforce it can invoke it themselves.
+    /// The default implementation accepts any parameters.
+    fn validate_params(&self, params: &Self::Params) -> Result<()> {
+        let _ = params;
+        Ok(())
+    }
+
+    /// Get the recommended parameters for this password hashing function,
+    /// which callers can compare or clamp their own parameters against.
+    ///
+    /// These are the parameters used by [`PasswordHasher::hash_password`].
+    /// The default implementation returns [`Default::default`].
+    fn recommended_params(&self) -> Self::Params {
+        Self::Params::default()
+    }
+
+    /// Get the algorithm identifier used by [`PasswordHasher::hash_password`].
+    ///
+    /// Returns `None` by default, in which case [`PasswordHasher::needs_rehash`]
+    /// doesn't compare algorithm identifiers. Implementations which support
+    /// several algorithms (or variants of one) should override this method.
+    fn recommended_algorithm(&self) -> Option<Ident<'static>> {
+        None
+    }
+
+    /// Get the algorithm version used by [`PasswordHasher::hash_password`].
+    ///
+    /// Returns `None` by default, in which case [`PasswordHasher::needs_rehash`]
+    /// doesn't compare versions. Implementations which support several
+    /// versions should override this method.
+    fn recommended_version(&self) -> Option<Decimal> {
+        None
+    }
+
+    /// Determine whether the provided [`PasswordHash`] was computed with an
+    /// algorithm, version or parameters which differ from the recommended
+    /// ones, in which case the password should be rehashed (e.g. on next
+    /// login).
+    ///
+    /// The default implementation compares the hash's algorithm identifier
+    /// against [`PasswordHasher::recommended_algorithm`] and its version
+    /// against [`PasswordHasher::recommended_version`] (if any). It then parses
+    /// the hash's parameters and compares their serialized form against that
+    /// of [`PasswordHasher::recommended_params`], so differences in ordering
+    /// or formatting are not considered meaningful. Hashes whose parameters
+    /// can't be parsed always need rehashing.
+    fn needs_rehash(&self, hash: &PasswordHash<'_>) -> bool {
+        if let Some(algorithm) = self.recommended_algorithm() {
+            if hash.algorithm != algorithm {
+                return true;
+            }
+        }
+
+        if let Some(version) = self.recommended_version() {
+            if hash.version != Some(version) {
+                return true;
+            }
+        }
+
+        let current: Result<ParamsString> =
+            Self::Params::try_from(hash).and_then(TryInto::try_into);
+        let recommended: Result<ParamsString> = self.recommended_params().try_into();
+
+        match (current, recommended) {
+            (Ok(current), Ok(recommended)) => current != recommended,
+            _ => true,
+        }
+    }
+
+    /// Compute a [`PasswordHash`] from a password which is read incrementally
+    /// from the provided [`std::io::Read`] source, using the default
+    /// recommended parameters.
+    ///
+    /// This is intended for large passphrases or file-based secrets which
+    /// shouldn't be loaded into memory in their entirety.
+    ///
+    /// # Algorithm support
+    /// Only algorithms which can absorb the password incrementally are able to
+    /// support this method, and they do so by overriding it and streaming the
+    /// input into the underlying KDF. Such implementations MUST produce the
+    /// same output as [`PasswordHasher::hash_password`] would for the same
+    /// password bytes.
+    ///
+    /// Algorithms which require the full password upfront use the default
+    /// implementation, which returns [`Error::Algorithm`] without reading
+    /// from `reader`.
+    #[cfg(feature = "std")]
+    fn hash_password_reader<'a>(
+        &self,
+        reader: impl std::io::Read,
+        salt: impl Into<Salt<'a>>,
+    ) -> Result<PasswordHash<'a>> {
+        let _ = (reader, salt);
+        Err(Error::Algorithm)
+    }
+
+    /// Find cost parameters for which hashing a password takes about
+    /// `target` time on the current machine, e.g. when a service starts up.
+    ///
+    /// Starting from [`PasswordHasher::recommended_params`], the cost is
+    /// increased using [`TunableParams::increase_cost`] and a trial hash is
+    /// timed at each step. The last parameters whose trial hash didn't exceed
+    /// `target` are returned. The recommended parameters are returned if
+    /// already the first increase exceeds `target`, i.e. the result is never
+    /// weaker than the recommendation.
+    ///
+    /// The result depends on the hardware and its current load and is not
+    /// deterministic, so it should be persisted or logged rather than being
+    /// expected to be reproducible.
+    #[cfg(feature = "std")]
+    fn tune_params(&self, target: core::time::Duration) -> Result<Self::Params>
+    where
+        Self::Params: TunableParams,
+    {
+        let start = std::time::Instant::now();
+        self.tune_params_with_clock(target, || start.elapsed())
+    }
+
+    /// Variant of [`PasswordHasher::tune_params`] which measures time using
+    /// the provided `clock`, which returns the time elapsed since an
+    /// arbitrary fixed point.
+    fn tune_params_with_clock(
+        &self,
+        target: core::time::Duration,
+        mut clock: impl FnMut() -> core::time::Duration,
+    ) -> Result<Self::Params>
+    where
+        Self::Params: TunableParams,
+    {
+        // Fixed 16 zero bytes. Only timing matters, not the resulting hash.
+        let salt = Salt::from_b64("AAAAAAAAAAAAAAAAAAAAAA")?;
+        let mut params = self.recommended_params();
+
+        while let Some(next) = params.increase_cost() {
+            let start = clock();
+            self.hash_password_customized(b"password", None, None, next.clone(), salt)?;
+            if clock().saturating_sub(start) > target {
+                break;
+            }
+            params = next;
+        }
+
+        Ok(params)
+    }
+}
+
+/// Algorithm parameters whose cost can be increased step by step, as used
+/// by [`PasswordHasher::tune_params`].
+pub trait TunableParams: Sized {
+    /// Get the parameters with the next higher cost, or `None` if the cost
+    /// is already at its maximum.
+    ///
+    /// Implementations should increase the cost by a meaningful amount (e.g.
+    /// double the iteration count or memory size) so that tuning finishes
+    /// after a few steps.
+    fn increase_cost(&self) -> Option<Self>;
+}
+
+/// Trait for password hashing which returns raw salt and hash bytes instead
+/// of a PHC string.
+///
+/// Automatically impl'd for any type that impls [`PasswordHasher`].
+///
+/// This is intended for systems which store the salt and the hash in
+/// separate binary columns, see [`RawHash`].
+pub trait RawPasswordHasher {
+    /// Compute a [`RawHash`] from the provided password and raw `salt` bytes.
+    ///
+    /// Uses the default recommended parameters for a given algorithm.
+    /// The resulting hash bytes are equal to the output contained in the
+    /// PHC string computed by [`PasswordHasher::hash_password`] with the
+    /// B64-encoded salt.
+    fn hash_password_raw(&self, password: &[u8], salt: &[u8]) -> Result<RawHash>;
+}
+
+impl<T: PasswordHasher> RawPasswordHasher for T {
+    fn hash_password_raw(&self, password: &[u8], salt: &[u8]) -> Result<RawHash> {
+        let salt_string = SaltString::encode_b64(salt)?;
+        let hash = self.hash_password(password, Salt::from_b64(salt_string.as_str())?)?;
+        RawHash::new(salt, hash.hash.ok_or(Error::Password)?)
     }
 }
 
@@ -51,28 +294,64 @@ pub trait PasswordVerifier {
     /// using the parameters from the provided password hash and see if the
     /// computed output matches.
     fn verify_password(&self, password: &[u8], hash: &PasswordHash<'_>) -> Result<()>;
+
+    /// Verify the provided password against a password hash, returning a
+    /// [`VerifyError`] which distinguishes why verification failed.
+    ///
+    /// Only the failure cases which can be determined without computing the
+    /// password hash (i.e. [`VerifyError::NoHash`]) are distinguishable by
+    /// timing. A correct and an incorrect password both incur a full hash
+    /// computation followed by a constant-time comparison.
+    ///
+    /// The default implementation is provided in terms of
+    /// [`PasswordVerifier::verify_password`].
+    fn verify_password_detailed(
+        &self,
+        password: &[u8],
+        hash: &PasswordHash<'_>,
+    ) -> core::result::Result<(), VerifyError> {
+        if hash.salt.is_none() || hash.hash.is_none() {
+            return Err(VerifyError::NoHash);
+        }
+
+        Ok(self.verify_password(password, hash)?)
+    }
 }
 
 impl<T: PasswordHasher> PasswordVerifier for T {
     fn verify_password(&self, password: &[u8], hash: &PasswordHash<'_>) -> Result<()> {
-        if let (Some(salt), Some(expected_output)) = (&hash.salt, &hash.hash) {
-            let computed_hash = self.hash_password_customized(
-                password,
-                Some(hash.algorithm),
-                hash.version,
-                T::Params::try_from(hash)?,
-                *salt,
-            )?;
-
-            if let Some(computed_output) = &computed_hash.hash {
-                // See notes on `Output` about the use of a constant-time comparison
-                if expected_output == computed_output {
-                    return Ok(());
-                }
-            }
-        }
+        Ok(self.verify_password_detailed(password, hash)?)
+    }
+
+    fn verify_password_detailed(
+        &self,
+        password: &[u8],
+        hash: &PasswordHash<'_>,
+    ) -> core::result::Result<(), VerifyError> {
+        let (Some(salt), Some(expected_output)) = (&hash.salt, &hash.hash) else {
+            return Err(VerifyError::NoHash);
+        };
+
+        let computed_hash = self.hash_password_customized(
+            password,
+            Some(hash.algorithm),
+            hash.version,
+            T::Params::try_from(hash)?,
+            *salt,
+        )?;
+
+        let computed_output = computed_hash.hash.ok_or(VerifyError::PasswordInvalid)?;
 
-        Err(Error::Password)
+        // The outputs are compared using `ConstantTimeEq` explicitly (rather
+        // than relying on `Output`'s `PartialEq` impl) so that the comparison
+        // takes the same amount of time regardless of how many leading bytes
+        // match. See notes on `Output` about the use of a constant-time
+        // comparison.
+        if expected_output.ct_eq(&computed_output).into() {
+            Ok(())
+        } else {
+            Err(VerifyError::PasswordInvalid)
+        }
     }
 }
 
@@ -91,6 +370,25 @@ pub trait McfHasher {
     /// algorithm-specific rules so hashers must parse a raw string themselves.
     fn upgrade_mcf_hash<'a>(&self, hash: &'a str) -> Result<PasswordHash<'a>>;
 
+    /// Downgrade a PHC hash to an MCF hash, i.e. the inverse of
+    /// [`McfHasher::upgrade_mcf_hash`].
+    ///
+    /// This is intended for legacy systems which still persist hashes in MCF,
+    /// e.g. for `crypt(3)` compatibility.
+    ///
+    /// The conversion may be lossy: any fields of the PHC hash which have no
+    /// MCF representation (e.g. unknown parameters) are discarded. It's only
+    /// guaranteed to round-trip a PHC hash which was obtained by calling
+    /// [`McfHasher::upgrade_mcf_hash`].
+    ///
+    /// The default implementation returns [`Error::Algorithm`], which is the
+    /// expected behavior for algorithms which have no MCF form.
+    #[cfg(feature = "alloc")]
+    fn downgrade_to_mcf(&self, hash: &PasswordHash<'_>) -> Result<alloc::string::String> {
+        let _ = hash;
+        Err(Error::Algorithm)
+    }
+
     /// Verify a password hash in MCF format against the provided password.
     fn verify_mcf_hash(&self, password: &[u8], mcf_hash: &str) -> Result<()>
     where
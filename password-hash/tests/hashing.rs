@@ -1,7 +1,9 @@
 //! Password hashing tests
 
 pub use password_hash::{
-    Decimal, Error, Ident, Output, ParamsString, PasswordHash, PasswordHasher, Result, Salt,
+    errors::InvalidValue, Decimal, Error, Ident, Output, ParamsString, PasswordHash,
+    PasswordHasher, PasswordVerifier, RawHash, RawPasswordHasher, Result, Salt, SaltString,
+    VerifyError,
 };
 
 const ALG: Ident = Ident::new_unwrap("example");
@@ -43,6 +45,36 @@ impl PasswordHasher for StubPasswordHasher {
             hash: Some(hash),
         })
     }
+
+    #[cfg(feature = "std")]
+    fn hash_password_reader<'a>(
+        &self,
+        mut reader: impl std::io::Read,
+        salt: impl Into<Salt<'a>>,
+    ) -> Result<PasswordHash<'a>> {
+        let salt = salt.into();
+        let mut output = b"pw".to_vec();
+        let mut buf = [0u8; 4];
+
+        loop {
+            let n = reader.read(&mut buf)?;
+            if n == 0 {
+                break;
+            }
+            output.extend_from_slice(&buf[..n]);
+        }
+
+        output.extend_from_slice(b",salt:");
+        output.extend_from_slice(salt.as_str().as_bytes());
+
+        Ok(PasswordHash {
+            algorithm: ALG,
+            version: None,
+            params: StubParams.try_into()?,
+            salt: Some(salt),
+            hash: Some(Output::new(&output)?),
+        })
+    }
 }
 
 /// Stub parameters
@@ -65,6 +97,82 @@ impl TryFrom<StubParams> for ParamsString {
     }
 }
 
+/// Stub password hashing function with a cost parameter for testing.
+pub struct CostPasswordHasher;
+
+impl CostPasswordHasher {
+    const MIN_COST: Decimal = 10;
+    const VERSION: Decimal = 2;
+}
+
+impl PasswordHasher for CostPasswordHasher {
+    type Params = CostParams;
+
+    fn hash_password_customized<'a>(
+        &self,
+        password: &[u8],
+        algorithm: Option<Ident<'a>>,
+        version: Option<Decimal>,
+        params: CostParams,
+        salt: impl Into<Salt<'a>>,
+    ) -> Result<PasswordHash<'a>> {
+        self.validate_params(&params)?;
+        let version = version.or(Some(Self::VERSION));
+        let mut hash = StubPasswordHasher
+            .hash_password_customized(password, algorithm, version, StubParams, salt)?;
+        hash.params = params.try_into()?;
+        Ok(hash)
+    }
+
+    fn validate_params(&self, params: &CostParams) -> Result<()> {
+        if params.cost < Self::MIN_COST {
+            return Err(InvalidValue::TooShort.param_error());
+        }
+
+        Ok(())
+    }
+
+    fn recommended_params(&self) -> CostParams {
+        CostParams { cost: 12 }
+    }
+
+    fn recommended_algorithm(&self) -> Option<Ident<'static>> {
+        Some(ALG)
+    }
+
+    fn recommended_version(&self) -> Option<Decimal> {
+        Some(Self::VERSION)
+    }
+}
+
+/// Stub parameters with a cost
+#[derive(Clone, Debug, Default)]
+pub struct CostParams {
+    cost: Decimal,
+}
+
+impl<'a> TryFrom<&PasswordHash<'a>> for CostParams {
+    type Error = Error;
+
+    fn try_from(hash: &PasswordHash<'a>) -> Result<Self> {
+        let cost = hash
+            .params
+            .get_decimal("cost")
+            .ok_or(InvalidValue::Malformed.param_error())?;
+        Ok(Self { cost })
+    }
+}
+
+impl TryFrom<CostParams> for ParamsString {
+    type Error = Error;
+
+    fn try_from(params: CostParams) -> Result<Self> {
+        let mut output = Self::new();
+        output.add_decimal("cost", params.cost)?;
+        Ok(output)
+    }
+}
+
 #[test]
 fn verify_password_hash() {
     let valid_password = "test password";
@@ -86,3 +194,337 @@ fn verify_password_hash() {
         Err(Error::Password)
     );
 }
+
+#[test]
+fn verify_password_detailed() {
+    let valid_password = b"test password";
+    let salt = Salt::from_b64("test-salt").unwrap();
+    let hash = StubPasswordHasher
+        .hash_password(valid_password, salt)
+        .unwrap();
+
+    assert_eq!(
+        StubPasswordHasher.verify_password_detailed(valid_password, &hash),
+        Ok(())
+    );
+
+    assert_eq!(
+        StubPasswordHasher.verify_password_detailed(b"wrong password", &hash),
+        Err(VerifyError::PasswordInvalid)
+    );
+
+    let mut no_hash = hash.clone();
+    no_hash.hash = None;
+    assert_eq!(
+        StubPasswordHasher.verify_password_detailed(valid_password, &no_hash),
+        Err(VerifyError::NoHash)
+    );
+    assert_eq!(
+        StubPasswordHasher.verify_password(valid_password, &no_hash),
+        Err(Error::Password)
+    );
+
+    let mut other_alg = hash.clone();
+    other_alg.algorithm = Ident::new_unwrap("other");
+    assert_eq!(
+        StubPasswordHasher.verify_password_detailed(valid_password, &other_alg),
+        Err(VerifyError::AlgorithmMismatch)
+    );
+    assert_eq!(
+        StubPasswordHasher.verify_password(valid_password, &other_alg),
+        Err(Error::Algorithm)
+    );
+}
+
+#[test]
+fn validate_params() {
+    let hasher = CostPasswordHasher;
+    let salt = Salt::from_b64("test-salt").unwrap();
+    let recommended = hasher.recommended_params();
+    let too_low = CostParams { cost: 4 };
+
+    assert_eq!(hasher.validate_params(&recommended), Ok(()));
+    assert_eq!(
+        hasher.validate_params(&too_low),
+        Err(Error::ParamValueInvalid(InvalidValue::TooShort))
+    );
+    assert!(too_low.cost < recommended.cost);
+
+    let hash = hasher.hash_password(b"test password", salt).unwrap();
+    assert_eq!(hash.params.get_decimal("cost"), Some(recommended.cost));
+
+    assert_eq!(
+        hasher.hash_password_customized(b"test password", None, None, too_low, salt),
+        Err(Error::ParamValueInvalid(InvalidValue::TooShort))
+    );
+}
+
+/// Stub password hashing function whose `hash_password_customized` does not
+/// validate its parameters, and whose recommended cost is configurable.
+struct UncheckedCostHasher(Decimal);
+
+impl PasswordHasher for UncheckedCostHasher {
+    type Params = CostParams;
+
+    fn hash_password_customized<'a>(
+        &self,
+        password: &[u8],
+        algorithm: Option<Ident<'a>>,
+        version: Option<Decimal>,
+        params: CostParams,
+        salt: impl Into<Salt<'a>>,
+    ) -> Result<PasswordHash<'a>> {
+        let mut hash = StubPasswordHasher
+            .hash_password_customized(password, algorithm, version, StubParams, salt)?;
+        hash.params = params.try_into()?;
+        Ok(hash)
+    }
+
+    fn validate_params(&self, params: &CostParams) -> Result<()> {
+        CostPasswordHasher.validate_params(params)
+    }
+
+    fn recommended_params(&self) -> CostParams {
+        CostParams { cost: self.0 }
+    }
+}
+
+#[test]
+fn validate_params_before_hashing() {
+    let salt = Salt::from_b64("test-salt").unwrap();
+    let too_low = Error::ParamValueInvalid(InvalidValue::TooShort);
+
+    assert!(UncheckedCostHasher(12)
+        .hash_password(b"test password", salt)
+        .is_ok());
+    assert_eq!(
+        UncheckedCostHasher(4).hash_password(b"test password", salt),
+        Err(too_low)
+    );
+
+    let hash = UncheckedCostHasher(4)
+        .hash_password_customized(b"test password", None, None, CostParams { cost: 4 }, salt)
+        .unwrap();
+    assert_eq!(
+        UncheckedCostHasher(12).verify_password_against_output(
+            b"test password",
+            salt,
+            CostParams { cost: 4 },
+            hash.hash.unwrap().as_bytes(),
+        ),
+        Err(too_low)
+    );
+}
+
+#[cfg(all(feature = "alloc", feature = "rand_core"))]
+#[test]
+fn hash_password_with_rng() {
+    use password_hash::rand_core::{impls, CryptoRng, RngCore};
+
+    /// RNG which always outputs the same byte.
+    struct FixedRng(u8);
+
+    impl RngCore for FixedRng {
+        fn next_u32(&mut self) -> u32 {
+            impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            dest.fill(self.0);
+        }
+
+        fn try_fill_bytes(
+            &mut self,
+            dest: &mut [u8],
+        ) -> core::result::Result<(), password_hash::rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for FixedRng {}
+
+    let password = b"test password";
+    let hash1 = StubPasswordHasher
+        .hash_password_with_rng(password, &mut FixedRng(42))
+        .unwrap();
+    let hash2 = StubPasswordHasher
+        .hash_password_with_rng(password, &mut FixedRng(42))
+        .unwrap();
+    assert_eq!(hash1, hash2);
+
+    let expected_salt =
+        password_hash::SaltString::encode_b64(&[42; Salt::RECOMMENDED_LENGTH]).unwrap();
+    assert_eq!(hash1.salt().unwrap(), expected_salt.as_salt());
+
+    let hash3 = StubPasswordHasher
+        .hash_password_with_rng(password, &mut FixedRng(7))
+        .unwrap();
+    assert_ne!(hash1, hash3);
+}
+
+#[test]
+fn verify_password_against_output() {
+    let password = b"test password";
+    let salt = Salt::from_b64("test-salt").unwrap();
+    let hash = StubPasswordHasher.hash_password(password, salt).unwrap();
+    let output = hash.hash.unwrap();
+
+    assert_eq!(
+        StubPasswordHasher.verify_password_against_output(
+            password,
+            salt,
+            StubParams,
+            output.as_bytes()
+        ),
+        Ok(())
+    );
+
+    assert_eq!(
+        StubPasswordHasher.verify_password_against_output(
+            b"wrong password",
+            salt,
+            StubParams,
+            output.as_bytes()
+        ),
+        Err(Error::Password)
+    );
+
+    assert_eq!(
+        StubPasswordHasher.verify_password_against_output(
+            password,
+            salt,
+            StubParams,
+            &output.as_bytes()[1..]
+        ),
+        Err(Error::Password)
+    );
+}
+
+#[test]
+fn needs_rehash() {
+    let hasher = CostPasswordHasher;
+    let salt = Salt::from_b64("test-salt").unwrap();
+
+    let current = hasher.hash_password(b"test password", salt).unwrap();
+    assert!(!hasher.needs_rehash(&current));
+
+    let outdated = hasher
+        .hash_password_customized(b"test password", None, None, CostParams { cost: 10 }, salt)
+        .unwrap();
+    assert!(hasher.needs_rehash(&outdated));
+
+    let mut malformed = current.clone();
+    malformed.params = ParamsString::new();
+    assert!(hasher.needs_rehash(&malformed));
+}
+
+/// Stub password hashing function which produces versioned hashes, but
+/// doesn't override [`PasswordHasher::recommended_version`].
+struct VersionedPasswordHasher;
+
+impl PasswordHasher for VersionedPasswordHasher {
+    type Params = StubParams;
+
+    fn hash_password_customized<'a>(
+        &self,
+        password: &[u8],
+        algorithm: Option<Ident<'a>>,
+        version: Option<Decimal>,
+        params: StubParams,
+        salt: impl Into<Salt<'a>>,
+    ) -> Result<PasswordHash<'a>> {
+        StubPasswordHasher.hash_password_customized(
+            password,
+            algorithm,
+            version.or(Some(3)),
+            params,
+            salt,
+        )
+    }
+}
+
+#[test]
+fn needs_rehash_version_and_algorithm() {
+    let hasher = CostPasswordHasher;
+    let salt = Salt::from_b64("test-salt").unwrap();
+    let params = hasher.recommended_params();
+
+    let outdated = hasher
+        .hash_password_customized(b"test password", None, Some(1), params, salt)
+        .unwrap();
+    assert!(hasher.needs_rehash(&outdated));
+
+    let mut unversioned = hasher.hash_password(b"test password", salt).unwrap();
+    unversioned.version = None;
+    assert!(hasher.needs_rehash(&unversioned));
+
+    let mut other_algorithm = hasher.hash_password(b"test password", salt).unwrap();
+    other_algorithm.algorithm = Ident::new_unwrap("other");
+    assert!(hasher.needs_rehash(&other_algorithm));
+
+    // Versions are not compared for hashers without a recommended version
+    let current = VersionedPasswordHasher
+        .hash_password(b"test password", salt)
+        .unwrap();
+    assert_eq!(current.version, Some(3));
+    assert!(!VersionedPasswordHasher.needs_rehash(&current));
+    let other_version = VersionedPasswordHasher
+        .hash_password_customized(b"test password", None, Some(1), StubParams, salt)
+        .unwrap();
+    assert!(!VersionedPasswordHasher.needs_rehash(&other_version));
+    let unversioned = StubPasswordHasher
+        .hash_password(b"test password", salt)
+        .unwrap();
+    assert!(!VersionedPasswordHasher.needs_rehash(&unversioned));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn hash_password_reader() {
+    let password = "test password";
+    let salt = Salt::from_b64("test-salt").unwrap();
+    let expected = StubPasswordHasher
+        .hash_password(password.as_bytes(), salt)
+        .unwrap();
+    let actual = StubPasswordHasher
+        .hash_password_reader(std::io::Cursor::new(password), salt)
+        .unwrap();
+
+    assert_eq!(expected, actual);
+}
+
+#[test]
+fn hash_password_raw() {
+    let password = b"test password";
+    let salt = [0x5Au8, 1, 2, 3, 4, 5, 6, 7];
+    let raw = StubPasswordHasher
+        .hash_password_raw(password, &salt)
+        .unwrap();
+    assert_eq!(raw.salt_bytes(), salt);
+
+    // Raw bytes match the ones inside the equivalent PHC string
+    let salt_string = SaltString::encode_b64(&salt).unwrap();
+    let phc = StubPasswordHasher
+        .hash_password(password, salt_string.as_salt())
+        .unwrap()
+        .to_string();
+    let hash = PasswordHash::new(&phc).unwrap();
+
+    let mut buf = [0u8; RawHash::SALT_MAX_LENGTH];
+    assert_eq!(hash.salt.unwrap().decode_b64(&mut buf).unwrap(), salt);
+    assert_eq!(raw.hash_bytes(), hash.hash.unwrap().as_bytes());
+    assert_eq!(raw.output(), &hash.hash.unwrap());
+}
+
+#[test]
+fn hash_password_raw_salt_too_long() {
+    let salt = [0u8; RawHash::SALT_MAX_LENGTH + 1];
+    assert!(StubPasswordHasher
+        .hash_password_raw(b"test password", &salt)
+        .is_err());
+}
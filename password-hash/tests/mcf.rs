@@ -0,0 +1,77 @@
+//! Modular Crypt Format (MCF) tests
+
+#![cfg(feature = "alloc")]
+
+use password_hash::{Error, Ident, McfHasher, Output, PasswordHash, Result};
+
+const ALG: Ident = Ident::new_unwrap("example");
+
+/// Stub hasher whose MCF form is `$example$<rounds>$<salt>$<hash>`.
+pub struct StubMcfHasher;
+
+impl McfHasher for StubMcfHasher {
+    fn upgrade_mcf_hash<'a>(&self, hash: &'a str) -> Result<PasswordHash<'a>> {
+        let mut fields = hash.split('$');
+
+        if fields.next() != Some("") || fields.next() != Some(ALG.as_str()) {
+            return Err(Error::Algorithm);
+        }
+
+        let rounds = fields.next().ok_or(Error::PhcStringField)?;
+        let salt = fields.next().ok_or(Error::PhcStringField)?;
+        let hash = fields.next().ok_or(Error::PhcStringField)?;
+
+        if fields.next().is_some() {
+            return Err(Error::PhcStringTrailingData);
+        }
+
+        let mut params = password_hash::ParamsString::new();
+        params.add_str("rounds", rounds)?;
+
+        Ok(PasswordHash {
+            algorithm: ALG,
+            version: None,
+            params,
+            salt: Some(salt.try_into()?),
+            hash: Some(Output::b64_decode(hash)?),
+        })
+    }
+
+    fn downgrade_to_mcf(&self, hash: &PasswordHash<'_>) -> Result<String> {
+        if hash.algorithm != ALG {
+            return Err(Error::Algorithm);
+        }
+
+        let rounds = hash.params.get_str("rounds").ok_or(Error::PhcStringField)?;
+        let salt = hash.salt.ok_or(Error::PhcStringField)?;
+        let output = hash.hash.ok_or(Error::PhcStringField)?;
+
+        Ok(format!("${}${}${}${}", ALG, rounds, salt, output))
+    }
+}
+
+/// Hasher which has no MCF form.
+pub struct NoMcfHasher;
+
+impl McfHasher for NoMcfHasher {
+    fn upgrade_mcf_hash<'a>(&self, _hash: &'a str) -> Result<PasswordHash<'a>> {
+        Err(Error::Algorithm)
+    }
+}
+
+const EXAMPLE_MCF: &str = "$example$5000$saltsalt$AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8";
+
+#[test]
+fn mcf_round_trip() {
+    let phc = StubMcfHasher.upgrade_mcf_hash(EXAMPLE_MCF).unwrap();
+    assert_eq!(phc.params.get_str("rounds"), Some("5000"));
+
+    let mcf = StubMcfHasher.downgrade_to_mcf(&phc).unwrap();
+    assert_eq!(mcf, EXAMPLE_MCF);
+}
+
+#[test]
+fn downgrade_unsupported() {
+    let phc = StubMcfHasher.upgrade_mcf_hash(EXAMPLE_MCF).unwrap();
+    assert_eq!(NoMcfHasher.downgrade_to_mcf(&phc), Err(Error::Algorithm));
+}
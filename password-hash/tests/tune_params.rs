@@ -0,0 +1,125 @@
+//! Cost parameter tuning tests
+
+use core::{cell::Cell, time::Duration};
+use password_hash::{
+    Decimal, Error, Ident, ParamsString, PasswordHash, PasswordHasher, Result, Salt, TunableParams,
+};
+
+const ALG: Ident = Ident::new_unwrap("example");
+
+/// Time taken by each unit of cost on the fake clock.
+const COST_UNIT: Duration = Duration::from_millis(10);
+
+/// Stub password hashing function which advances a fake clock in proportion
+/// to its cost parameter.
+struct ClockedPasswordHasher {
+    now: Cell<Duration>,
+}
+
+impl ClockedPasswordHasher {
+    fn new() -> Self {
+        Self {
+            now: Cell::new(Duration::ZERO),
+        }
+    }
+
+    fn tune(&self, target: Duration) -> Result<ClockedParams> {
+        self.tune_params_with_clock(target, || self.now.get())
+    }
+}
+
+impl PasswordHasher for ClockedPasswordHasher {
+    type Params = ClockedParams;
+
+    fn hash_password_customized<'a>(
+        &self,
+        _password: &[u8],
+        _algorithm: Option<Ident<'a>>,
+        _version: Option<Decimal>,
+        params: ClockedParams,
+        salt: impl Into<Salt<'a>>,
+    ) -> Result<PasswordHash<'a>> {
+        self.now.set(self.now.get() + COST_UNIT * params.cost);
+
+        Ok(PasswordHash {
+            algorithm: ALG,
+            version: None,
+            params: params.try_into()?,
+            salt: Some(salt.into()),
+            hash: None,
+        })
+    }
+
+    fn recommended_params(&self) -> ClockedParams {
+        ClockedParams { cost: 4 }
+    }
+}
+
+/// Stub parameters with a cost which is increased in steps of one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+struct ClockedParams {
+    cost: Decimal,
+}
+
+impl TunableParams for ClockedParams {
+    fn increase_cost(&self) -> Option<Self> {
+        let cost = self.cost.checked_add(1).filter(|&cost| cost <= 64)?;
+        Some(Self { cost })
+    }
+}
+
+impl<'a> TryFrom<&PasswordHash<'a>> for ClockedParams {
+    type Error = Error;
+
+    fn try_from(hash: &PasswordHash<'a>) -> Result<Self> {
+        let cost = hash
+            .params
+            .get_decimal("cost")
+            .ok_or(Error::ParamNameInvalid)?;
+        Ok(Self { cost })
+    }
+}
+
+impl TryFrom<ClockedParams> for ParamsString {
+    type Error = Error;
+
+    fn try_from(params: ClockedParams) -> Result<Self> {
+        let mut output = Self::new();
+        output.add_decimal("cost", params.cost)?;
+        Ok(output)
+    }
+}
+
+#[test]
+fn tune_params_to_target() {
+    let hasher = ClockedPasswordHasher::new();
+    let params = hasher.tune(Duration::from_millis(250)).unwrap();
+    assert_eq!(params, ClockedParams { cost: 25 });
+
+    // Exactly hitting the target is still acceptable
+    let params = hasher.tune(Duration::from_millis(200)).unwrap();
+    assert_eq!(params, ClockedParams { cost: 20 });
+}
+
+#[test]
+fn tune_params_never_below_recommended() {
+    let hasher = ClockedPasswordHasher::new();
+    let params = hasher.tune(Duration::from_millis(1)).unwrap();
+    assert_eq!(params, hasher.recommended_params());
+}
+
+#[test]
+fn tune_params_stops_at_max_cost() {
+    let hasher = ClockedPasswordHasher::new();
+    let params = hasher.tune(Duration::from_secs(3600)).unwrap();
+    assert_eq!(params, ClockedParams { cost: 64 });
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn tune_params_real_clock() {
+    // Trial hashes of the stub take practically no real time
+    let hasher = ClockedPasswordHasher::new();
+    let params = hasher.tune_params(Duration::from_secs(3600)).unwrap();
+    assert_eq!(params, ClockedParams { cost: 64 });
+}
@@ -0,0 +1,55 @@
+//! Adapters between message signers and digest signers.
+
+use crate::{digest::Digest, DigestSigner, Error, PrehashSignature, Signer};
+
+/// [`DigestSigner`] adapter for [`Signer`] types which sign the finalized
+/// output of the preferred digest of the signature type.
+///
+/// For `S: PrehashSignature` it implements `DigestSigner<S::Digest, S>` by
+/// finalizing the provided digest and passing its output to
+/// [`Signer::try_sign`] as the message.
+///
+/// # ⚠️ Applicability
+///
+/// This adapter is only sound if the wrapped signer treats its message
+/// input as a prehash, i.e. if signing `𝐇(𝒎)` with it is exactly how the
+/// algorithm signs `𝒎`. This is the case e.g. for signers which forward
+/// messages to a remote service (such as a KMS or an HSM) operating on
+/// precomputed digests.
+///
+/// Most [`Signer`] implementations hash the message internally. Wrapping them
+/// produces signatures over `𝐇(𝐇(𝒎))`, which will not verify against `𝒎`
+/// with the corresponding [`DigestVerifier`][crate::DigestVerifier] or
+/// [`Verifier`][crate::Verifier].
+#[derive(Clone, Debug)]
+pub struct PrehashBridge<T> {
+    signer: T,
+}
+
+impl<T> PrehashBridge<T> {
+    /// Wrap the given signer, which MUST treat its message input as
+    /// a prehash (see type-level documentation).
+    pub fn new(signer: T) -> Self {
+        Self { signer }
+    }
+
+    /// Get reference to the wrapped signer.
+    pub fn inner(&self) -> &T {
+        &self.signer
+    }
+
+    /// Get the wrapped signer.
+    pub fn into_inner(self) -> T {
+        self.signer
+    }
+}
+
+impl<S, T> DigestSigner<S::Digest, S> for PrehashBridge<T>
+where
+    S: PrehashSignature,
+    T: Signer<S>,
+{
+    fn try_sign_digest(&self, digest: S::Digest) -> Result<S, Error> {
+        self.signer.try_sign(&digest.finalize())
+    }
+}
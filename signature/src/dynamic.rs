@@ -0,0 +1,106 @@
+//! Object-safe signing and verification over encoded signatures.
+
+use crate::{Error, SignatureEncoding, Signer, Verifier};
+use alloc::vec::Vec;
+use core::{fmt, marker::PhantomData};
+
+/// Object-safe signer which produces signatures in their encoded form.
+///
+/// Unlike [`Signer`], this trait is not generic over the signature type,
+/// which makes it possible to store signers for different algorithms in a
+/// single collection, e.g. `Vec<Box<dyn DynSigner>>`. Any [`Signer`] can be
+/// used as a [`DynSigner`] by wrapping it in a [`DynAdapter`].
+pub trait DynSigner {
+    /// Attempt to sign the given message, returning the encoded signature on
+    /// success, or an error if something went wrong.
+    fn sign_dyn(&self, msg: &[u8]) -> Result<Vec<u8>, Error>;
+}
+
+/// Object-safe verifier which accepts signatures in their encoded form.
+///
+/// Unlike [`Verifier`], this trait is not generic over the signature type.
+/// Any [`Verifier`] can be used as a [`DynVerifier`] by wrapping it in a
+/// [`DynAdapter`].
+pub trait DynVerifier {
+    /// Decode the given signature and use it to verify the message.
+    ///
+    /// Returns [`Error`] if the signature could not be decoded or is invalid.
+    fn verify_dyn(&self, msg: &[u8], signature: &[u8]) -> Result<(), Error>;
+}
+
+impl<T: DynSigner + ?Sized> DynSigner for &T {
+    fn sign_dyn(&self, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        T::sign_dyn(self, msg)
+    }
+}
+
+impl<T: DynVerifier + ?Sized> DynVerifier for &T {
+    fn verify_dyn(&self, msg: &[u8], signature: &[u8]) -> Result<(), Error> {
+        T::verify_dyn(self, msg, signature)
+    }
+}
+
+/// Adapter which erases the signature type `S` of a [`Signer`] or
+/// [`Verifier`], implementing [`DynSigner`] and [`DynVerifier`] respectively.
+///
+/// The signature type has to be fixed by the adapter because a single type
+/// may implement [`Signer`] for several signature types.
+pub struct DynAdapter<T, S> {
+    inner: T,
+    signature: PhantomData<fn() -> S>,
+}
+
+impl<T, S> DynAdapter<T, S> {
+    /// Wrap the given signer or verifier.
+    pub fn new(inner: T) -> Self {
+        Self {
+            inner,
+            signature: PhantomData,
+        }
+    }
+
+    /// Get reference to the wrapped signer or verifier.
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    /// Get the wrapped signer or verifier.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Clone, S> Clone for DynAdapter<T, S> {
+    fn clone(&self) -> Self {
+        Self::new(self.inner.clone())
+    }
+}
+
+impl<T: fmt::Debug, S> fmt::Debug for DynAdapter<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynAdapter")
+            .field("inner", &self.inner)
+            .finish()
+    }
+}
+
+impl<T, S> DynSigner for DynAdapter<T, S>
+where
+    T: Signer<S>,
+    S: SignatureEncoding,
+{
+    fn sign_dyn(&self, msg: &[u8]) -> Result<Vec<u8>, Error> {
+        self.inner.try_sign(msg).map(|sig| sig.to_vec())
+    }
+}
+
+impl<T, S> DynVerifier for DynAdapter<T, S>
+where
+    T: Verifier<S>,
+    S: SignatureEncoding,
+{
+    fn verify_dyn(&self, msg: &[u8], signature: &[u8]) -> Result<(), Error> {
+        let signature = S::try_from(signature).map_err(|_| Error::new())?;
+        self.inner.verify(msg, &signature)
+    }
+}
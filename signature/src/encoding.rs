@@ -1,7 +1,10 @@
 //! Encoding support.
 
 #[cfg(feature = "alloc")]
-use alloc::vec::Vec;
+use {
+    crate::Error,
+    alloc::{string::String, vec::Vec},
+};
 
 /// Support for decoding/encoding signatures as bytes.
 pub trait SignatureEncoding:
@@ -28,4 +31,76 @@ pub trait SignatureEncoding:
     fn encoded_len(&self) -> usize {
         self.to_bytes().as_ref().len()
     }
+
+    /// Encode signature as a lowercase hexadecimal string.
+    ///
+    /// Signatures are public values, so the encoding is not constant-time.
+    #[cfg(feature = "alloc")]
+    fn to_hex(&self) -> String {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+        let bytes = self.to_bytes();
+        let mut s = String::with_capacity(2 * bytes.as_ref().len());
+        for &b in bytes.as_ref() {
+            s.push(char::from(HEX[usize::from(b >> 4)]));
+            s.push(char::from(HEX[usize::from(b & 0xF)]));
+        }
+        s
+    }
+
+    /// Decode signature from a hexadecimal string (either case).
+    ///
+    /// Signatures are public values, so the decoding is not constant-time.
+    #[cfg(feature = "alloc")]
+    fn from_hex(s: &str) -> Result<Self, Error> {
+        fn nibble(c: u8) -> Result<u8, Error> {
+            match c {
+                b'0'..=b'9' => Ok(c - b'0'),
+                b'a'..=b'f' => Ok(c - b'a' + 10),
+                b'A'..=b'F' => Ok(c - b'A' + 10),
+                _ => Err(Error::new()),
+            }
+        }
+
+        let s = s.as_bytes();
+        if s.len() % 2 != 0 {
+            return Err(Error::new());
+        }
+        let bytes = s
+            .chunks_exact(2)
+            .map(|pair| Ok((nibble(pair[0])? << 4) | nibble(pair[1])?))
+            .collect::<Result<Vec<u8>, Error>>()?;
+        Self::try_from(&bytes).map_err(|_| Error::new())
+    }
+
+    /// Encode signature as a padded standard Base64 string.
+    ///
+    /// Signatures are public values, so the encoding is not constant-time.
+    #[cfg(feature = "base64")]
+    fn to_base64(&self) -> String {
+        use base64ct::Encoding;
+        base64ct::Base64::encode_string(self.to_bytes().as_ref())
+    }
+
+    /// Decode signature from a padded standard Base64 string.
+    ///
+    /// Signatures are public values, so the decoding is not constant-time.
+    #[cfg(feature = "base64")]
+    fn from_base64(s: &str) -> Result<Self, Error> {
+        use base64ct::Encoding;
+        let bytes = base64ct::Base64::decode_vec(s).map_err(|_| Error::new())?;
+        Self::try_from(&bytes).map_err(|_| Error::new())
+    }
+}
+
+/// Signatures whose encoding has the same length for every signature, so
+/// buffers can be sized before signing.
+///
+/// Schemes with variable-length encodings, such as ASN.1 DER-encoded ECDSA
+/// signatures, must not implement this trait.
+pub trait FixedLengthSignature: SignatureEncoding {
+    /// Length of the encoded signature in bytes.
+    ///
+    /// This must be equal to the length of [`SignatureEncoding::to_bytes`]
+    /// of every signature.
+    const SIGNATURE_LENGTH: usize;
 }
@@ -110,3 +110,46 @@ impl core::error::Error for Error {
         }
     }
 }
+
+/// Signature verification errors which distinguish malformed signatures
+/// from genuine verification failures.
+///
+/// This is intended for callers which need to tell the two cases apart,
+/// e.g. for security monitoring, and is returned by
+/// [`Verifier::verify_detailed`][crate::Verifier::verify_detailed]. Use
+/// [`Error`] (which this type converts into) where this distinction is not
+/// needed.
+///
+/// # Security considerations
+///
+/// [`VerifyError::Malformed`] MUST only be returned for failures which depend
+/// solely on public data (i.e. the encoding of the signature). For all other
+/// failures [`VerifyError::Invalid`] MUST be returned, and it MUST be computed
+/// in constant time relative to successful verification, so that the returned
+/// variant and its timing do not leak secret-dependent information.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum VerifyError {
+    /// Signature could not be decoded.
+    Malformed,
+
+    /// Signature is well-formed but does not verify.
+    Invalid,
+}
+
+impl Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Malformed => f.write_str("malformed signature"),
+            Self::Invalid => f.write_str("invalid signature"),
+        }
+    }
+}
+
+impl core::error::Error for VerifyError {}
+
+impl From<VerifyError> for Error {
+    fn from(_: VerifyError) -> Error {
+        Error::new()
+    }
+}
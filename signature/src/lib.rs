@@ -111,6 +111,8 @@
 //!
 //! The following unstable features are presently supported:
 //!
+//! - `const-oid`: enables the [`PrehashSignature::OID`] associated constant
+//!   which can be used to obtain the algorithm OID of a signature type.
 //! - `digest`: enables the [`DigestSigner`] and [`DigestVerifier`]
 //!   traits which are based on the [`Digest`] trait from the [`digest`] crate.
 //!   These traits are used for representing signature systems based on the
@@ -130,9 +132,16 @@
 
 #[cfg(feature = "alloc")]
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 pub mod hazmat;
 
+#[cfg(feature = "digest")]
+pub mod digest_adapter;
+
+#[cfg(feature = "alloc")]
+mod dynamic;
 mod encoding;
 mod error;
 mod keypair;
@@ -144,6 +153,9 @@ mod prehash_signature;
 
 pub use crate::{encoding::*, error::*, keypair::*, signer::*, verifier::*};
 
+#[cfg(feature = "alloc")]
+pub use crate::dynamic::*;
+
 #[cfg(feature = "derive")]
 pub use derive::{Signer, Verifier};
 
@@ -153,5 +165,7 @@ pub use derive::{DigestSigner, DigestVerifier};
 #[cfg(feature = "digest")]
 pub use {crate::prehash_signature::*, digest};
 
+#[cfg(feature = "const-oid")]
+pub use const_oid;
 #[cfg(feature = "rand_core")]
 pub use rand_core;
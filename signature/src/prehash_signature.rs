@@ -28,4 +28,11 @@ use crate::{
 pub trait PrehashSignature {
     /// Preferred `Digest` algorithm to use when computing this signature type.
     type Digest: digest::Digest;
+
+    /// Algorithm OID of this signature type (e.g. `ecdsa-with-SHA256`), if any.
+    ///
+    /// This can be used by generic encoders to emit `AlgorithmIdentifier`s.
+    /// Defaults to `None`.
+    #[cfg(feature = "const-oid")]
+    const OID: Option<const_oid::ObjectIdentifier> = None;
 }
@@ -1,13 +1,16 @@
 //! Traits for generating digital signatures
 
-use crate::error::Error;
+use crate::{error::Error, Keypair, Verifier};
 
 #[cfg(feature = "digest")]
-use crate::digest::Digest;
+use crate::{digest::Digest, PrehashSignature};
 
 #[cfg(feature = "rand_core")]
 use crate::rand_core::CryptoRngCore;
 
+#[cfg(feature = "std")]
+use {core::fmt, std::time::Duration};
+
 /// Sign the provided message bytestring using `Self` (e.g. a cryptographic key
 /// or connection to an HSM), returning a digital signature.
 pub trait Signer<S> {
@@ -48,6 +51,218 @@ impl<S, T: Signer<S>> SignerMut<S> for T {
     }
 }
 
+/// [`Signer`] adapter which verifies every produced signature using the
+/// verifying key of the wrapped keypair before returning it.
+///
+/// This protects against faults during signing (e.g. glitches in hardware)
+/// which could otherwise result in release of an invalid signature, which
+/// for some algorithms can leak the signing key. The verifying key is
+/// obtained once on construction and cached.
+#[derive(Clone, Debug)]
+pub struct VerifyingSigner<T: Keypair> {
+    signer: T,
+    verifying_key: T::VerifyingKey,
+}
+
+impl<T: Keypair> VerifyingSigner<T> {
+    /// Wrap the given signing keypair.
+    pub fn new(signer: T) -> Self {
+        let verifying_key = signer.verifying_key();
+        Self {
+            signer,
+            verifying_key,
+        }
+    }
+
+    /// Get reference to the wrapped signer.
+    pub fn inner(&self) -> &T {
+        &self.signer
+    }
+
+    /// Get the wrapped signer.
+    pub fn into_inner(self) -> T {
+        self.signer
+    }
+}
+
+impl<T: Keypair> Keypair for VerifyingSigner<T> {
+    type VerifyingKey = T::VerifyingKey;
+
+    fn verifying_key(&self) -> Self::VerifyingKey {
+        self.verifying_key.clone()
+    }
+}
+
+impl<S, T> Signer<S> for VerifyingSigner<T>
+where
+    T: Signer<S> + Keypair,
+    T::VerifyingKey: Verifier<S>,
+{
+    /// Sign the given message and verify the resulting signature.
+    ///
+    /// Returns [`Error`] if either signing or self-verification fails.
+    fn try_sign(&self, msg: &[u8]) -> Result<S, Error> {
+        let signature = self.signer.try_sign(msg)?;
+        self.verifying_key.verify(msg, &signature)?;
+        Ok(signature)
+    }
+}
+
+/// Sign a to-be-signed (TBS) structure, e.g. a certificate signing request,
+/// and return the signature together with the verifying key to embed into
+/// the signed structure.
+///
+/// This trait is blanket-implemented for all types which implement
+/// [`Keypair`] and [`Signer`].
+///
+/// # ⚠️ Warning
+///
+/// The returned verifying key is meant for inclusion in the signed
+/// structure only. A signature which verifies under a key embedded in the
+/// same structure merely proves possession of the signing key; relying
+/// parties MUST establish trust in the verifying key by other means.
+pub trait SignMessage<S>: Keypair + Signer<S> {
+    /// Sign `tbs`, returning the signature and the verifying key of `self`.
+    fn sign_tbs(&self, tbs: &[u8]) -> Result<(S, Self::VerifyingKey), Error> {
+        let signature = self.try_sign(tbs)?;
+        Ok((signature, self.verifying_key()))
+    }
+}
+
+impl<S, T: Keypair + Signer<S>> SignMessage<S> for T {}
+
+/// [`Signer`] wrapper which retries failed signing operations, e.g. to
+/// tolerate transient errors of a remote KMS or HSM.
+///
+/// Signing is attempted at most `1 + retries` times. Before retry number
+/// `n` (starting at 1), the current thread sleeps for the duration returned
+/// by `backoff(n)`. If all attempts fail, the error of the last one is
+/// returned.
+///
+/// # ⚠️ Warning
+///
+/// Only wrap signers for which signing the same message again is harmless:
+/// deterministic schemes (e.g. Ed25519, RFC6979 ECDSA) or schemes which
+/// tolerate re-signing with fresh randomness. A failed attempt may still
+/// have produced (and e.g. logged) a signature, so stateful schemes (e.g.
+/// XMSS, LMS) or signers with per-signature quotas MUST NOT be retried.
+#[cfg(feature = "std")]
+#[derive(Clone)]
+pub struct Retry<T, F> {
+    signer: T,
+    retries: u32,
+    backoff: F,
+}
+
+#[cfg(feature = "std")]
+impl<T, F> Retry<T, F>
+where
+    F: Fn(u32) -> Duration,
+{
+    /// Wrap the given signer, retrying up to `retries` times with delays
+    /// given by `backoff`.
+    pub fn new(signer: T, retries: u32, backoff: F) -> Self {
+        Self {
+            signer,
+            retries,
+            backoff,
+        }
+    }
+
+    /// Get the maximum number of retries.
+    pub fn retries(&self) -> u32 {
+        self.retries
+    }
+
+    /// Get reference to the wrapped signer.
+    pub fn inner(&self) -> &T {
+        &self.signer
+    }
+
+    /// Get the wrapped signer.
+    pub fn into_inner(self) -> T {
+        self.signer
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug, F> fmt::Debug for Retry<T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Retry")
+            .field("signer", &self.signer)
+            .field("retries", &self.retries)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Keypair, F> Keypair for Retry<T, F> {
+    type VerifyingKey = T::VerifyingKey;
+
+    fn verifying_key(&self) -> Self::VerifyingKey {
+        self.signer.verifying_key()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<S, T, F> Signer<S> for Retry<T, F>
+where
+    T: Signer<S>,
+    F: Fn(u32) -> Duration,
+{
+    fn try_sign(&self, msg: &[u8]) -> Result<S, Error> {
+        let mut result = self.signer.try_sign(msg);
+        for retry in 1..=self.retries {
+            if result.is_ok() {
+                break;
+            }
+            std::thread::sleep((self.backoff)(retry));
+            result = self.signer.try_sign(msg);
+        }
+        result
+    }
+}
+
+/// Sign a message provided in multiple segments using `Self`, returning a
+/// digital signature.
+///
+/// Signing a slice of segments MUST be equivalent to signing their
+/// concatenation with [`Signer`], which allows signing large messages
+/// without buffering them in a contiguous slice.
+///
+/// When the `digest` feature is enabled, this trait is implemented for all
+/// [`DigestSigner`] types whose signature type implements
+/// [`PrehashSignature`]: the segments are streamed through the preferred
+/// digest of the signature.
+pub trait MultipartSigner<S> {
+    /// Sign the concatenation of the given message segments and return
+    /// a digital signature.
+    fn multipart_sign(&self, msg: &[&[u8]]) -> S {
+        self.try_multipart_sign(msg)
+            .expect("signature operation failed")
+    }
+
+    /// Attempt to sign the concatenation of the given message segments,
+    /// returning a digital signature on success, or an error if something
+    /// went wrong.
+    fn try_multipart_sign(&self, msg: &[&[u8]]) -> Result<S, Error>;
+}
+
+#[cfg(feature = "digest")]
+impl<S, T> MultipartSigner<S> for T
+where
+    S: PrehashSignature,
+    T: DigestSigner<S::Digest, S>,
+{
+    fn try_multipart_sign(&self, msg: &[&[u8]]) -> Result<S, Error> {
+        let mut digest = S::Digest::new();
+        for segment in msg {
+            digest.update(segment);
+        }
+        self.try_sign_digest(digest)
+    }
+}
+
 /// Sign the given prehashed message [`Digest`] using `Self`.
 ///
 /// ## Notes
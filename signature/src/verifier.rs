@@ -1,9 +1,20 @@
 //! Trait for verifying digital signatures
 
-use crate::error::Error;
+use crate::{
+    error::{Error, VerifyError},
+    SignatureEncoding,
+};
+use core::{cmp::Ordering, marker::PhantomData};
 
 #[cfg(feature = "digest")]
-use crate::digest::Digest;
+use crate::{
+    digest::{Digest, Output},
+    hazmat::PrehashVerifier,
+    PrehashSignature,
+};
+
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant};
 
 /// Verify the provided message bytestring using `Self` (e.g. a public key)
 pub trait Verifier<S> {
@@ -12,6 +23,36 @@ pub trait Verifier<S> {
     ///
     /// Returns `Error` if it is inauthentic, or otherwise returns `()`.
     fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error>;
+
+    /// Decode a signature from its byte encoding and verify it, reporting
+    /// why verification failed.
+    ///
+    /// Returns [`VerifyError::Malformed`] if `encoded` can't be decoded as `S`,
+    /// and [`VerifyError::Invalid`] if the decoded signature is inauthentic.
+    fn verify_detailed(&self, msg: &[u8], encoded: &[u8]) -> Result<(), VerifyError>
+    where
+        S: SignatureEncoding,
+    {
+        let signature = S::try_from(encoded).map_err(|_| VerifyError::Malformed)?;
+        self.verify(msg, &signature)
+            .map_err(|_| VerifyError::Invalid)
+    }
+
+    /// Verify the signature against an already computed `prehash` of the
+    /// message, e.g. one obtained by streaming a large message through `D`.
+    ///
+    /// The prehash is taken as [`Output<D>`], where `D` is the preferred
+    /// digest of the signature type, which binds its length to the output
+    /// size of that digest. This method forwards to
+    /// [`PrehashVerifier::verify_prehash`].
+    #[cfg(feature = "digest")]
+    fn verify_prehashed<D: Digest>(&self, prehash: &Output<D>, signature: &S) -> Result<(), Error>
+    where
+        Self: PrehashVerifier<S> + Sized,
+        S: PrehashSignature<Digest = D>,
+    {
+        self.verify_prehash(prehash, signature)
+    }
 }
 
 /// Verify the provided signature for the given prehashed message [`Digest`]
@@ -39,3 +80,280 @@ pub trait DigestVerifier<D: Digest, S> {
     /// Verify the signature against the given [`Digest`] output.
     fn verify_digest(&self, digest: D, signature: &S) -> Result<(), Error>;
 }
+
+/// Identifiers of digest algorithms which can be used with
+/// [`MultiDigestVerifier`].
+#[cfg(feature = "digest")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum DigestId {
+    /// SHA-256
+    Sha256,
+    /// SHA-384
+    Sha384,
+    /// SHA-512
+    Sha512,
+    /// SHA3-256
+    Sha3_256,
+    /// SHA3-384
+    Sha3_384,
+    /// SHA3-512
+    Sha3_512,
+}
+
+/// Verify signatures over messages prehashed with one of several digest
+/// algorithms, e.g. during a transition from SHA-256 to SHA-384.
+///
+/// Implementations dispatch each [`DigestId`] they support to the
+/// corresponding [`DigestVerifier`] impl in
+/// [`verify_with_digest`][Self::verify_with_digest].
+///
+/// # ⚠️ Security Warning
+///
+/// Accepting several digests means a signature is only as strong as the
+/// weakest digest in the allowed set: an attacker can pick whichever one
+/// is easiest to attack. Keep the allowed set as small as possible and
+/// remove old digests once the transition is complete. Since signatures
+/// generally do not record the digest they were made with, verification
+/// may also have to be attempted once per allowed digest.
+#[cfg(feature = "digest")]
+pub trait MultiDigestVerifier<S> {
+    /// Verify `signature` over `msg` prehashed with the digest `digest`.
+    ///
+    /// Returns [`Error`] if the signature is invalid or `digest` is not
+    /// supported.
+    fn verify_with_digest(&self, digest: DigestId, msg: &[u8], signature: &S) -> Result<(), Error>;
+
+    /// Verify `signature` over `msg` prehashed with any of the `allowed`
+    /// digests.
+    ///
+    /// Digests are tried in the given order. Returns [`Error`] if the
+    /// signature is not valid under any of them, including when `allowed`
+    /// is empty.
+    fn verify_any_digest(
+        &self,
+        msg: &[u8],
+        signature: &S,
+        allowed: &[DigestId],
+    ) -> Result<(), Error> {
+        if allowed
+            .iter()
+            .any(|&digest| self.verify_with_digest(digest, msg, signature).is_ok())
+        {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// [`Verifier`] combinator which accepts a signature if it is valid under
+/// any of the verifiers in the collection `V` (e.g. an array or a `Vec`).
+///
+/// This is useful e.g. during key rotation, when signatures produced with
+/// both the old and the new key are accepted.
+///
+/// All verifiers are always run, i.e. verification does not short-circuit
+/// on the first success, so timing does not reveal which verifier accepted
+/// the signature. Timing still depends on the number of verifiers and on
+/// timing of the individual verifiers. An empty collection accepts nothing.
+#[derive(Clone, Debug)]
+pub struct AnyOf<V>(pub V);
+
+impl<S, V, K> Verifier<S> for AnyOf<V>
+where
+    for<'a> &'a V: IntoIterator<Item = &'a K>,
+    K: Verifier<S>,
+{
+    fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error> {
+        let valid = (&self.0)
+            .into_iter()
+            .fold(false, |acc, v| v.verify(msg, signature).is_ok() | acc);
+        if valid {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// [`Verifier`] combinator which accepts a signature only if it is valid
+/// under all of the verifiers in the collection `V` (e.g. an array or
+/// a `Vec`).
+///
+/// All verifiers are always run, i.e. verification does not short-circuit
+/// on the first failure, so timing does not reveal which verifier rejected
+/// the signature. Timing still depends on the number of verifiers and on
+/// timing of the individual verifiers. An empty collection accepts nothing.
+#[derive(Clone, Debug)]
+pub struct AllOf<V>(pub V);
+
+impl<S, V, K> Verifier<S> for AllOf<V>
+where
+    for<'a> &'a V: IntoIterator<Item = &'a K>,
+    K: Verifier<S>,
+{
+    fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error> {
+        let (count, valid) = (&self.0)
+            .into_iter()
+            .fold((0usize, true), |(count, acc), v| {
+                (count + 1, v.verify(msg, signature).is_ok() & acc)
+            });
+        if count != 0 && valid {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// Signatures which expose their `S` component, e.g. ECDSA signatures.
+///
+/// Used by [`LowSOnly`] to reject non-canonical signatures.
+pub trait HasScalarS {
+    /// Big-endian encoding of a scalar.
+    type Bytes: AsRef<[u8]>;
+
+    /// Big-endian encoding of the order of the scalar field, `n`.
+    ///
+    /// The encoding of `S` returned by [`HasScalarS::s_bytes`] must have the
+    /// same length.
+    const ORDER: &'static [u8];
+
+    /// Get the big-endian encoding of the `S` component of the signature.
+    fn s_bytes(&self) -> Self::Bytes;
+}
+
+/// [`Verifier`] wrapper which rejects signatures whose `S` component is
+/// greater than `n / 2` ("high-S"), and otherwise delegates to the inner
+/// verifier.
+///
+/// For ECDSA both `S` and `n - S` are valid, so any signature can be turned
+/// into a different valid signature for the same message. Systems which
+/// rely on signatures being unique (e.g. Bitcoin transaction IDs) only
+/// accept the canonical low-S form.
+///
+/// This requires cooperation from the signature scheme, which must expose
+/// `S` via [`HasScalarS`].
+#[derive(Clone, Debug)]
+pub struct LowSOnly<V, S> {
+    verifier: V,
+    signature: PhantomData<fn(&S)>,
+}
+
+impl<V, S> LowSOnly<V, S>
+where
+    V: Verifier<S>,
+    S: HasScalarS,
+{
+    /// Wrap the given verifier.
+    pub fn new(verifier: V) -> Self {
+        Self {
+            verifier,
+            signature: PhantomData,
+        }
+    }
+
+    /// Borrow the inner verifier.
+    pub fn inner(&self) -> &V {
+        &self.verifier
+    }
+
+    /// Unwrap the inner verifier.
+    pub fn into_inner(self) -> V {
+        self.verifier
+    }
+}
+
+impl<V, S> Verifier<S> for LowSOnly<V, S>
+where
+    V: Verifier<S>,
+    S: HasScalarS,
+{
+    fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error> {
+        if !is_low_s(signature.s_bytes().as_ref(), S::ORDER) {
+            return Err(Error::new());
+        }
+        self.verifier.verify(msg, signature)
+    }
+}
+
+/// [`Verifier`] wrapper which pads the time taken by verification to a
+/// configured minimum duration.
+///
+/// After the inner verifier returns, the current thread sleeps for the
+/// remainder of the minimum duration, both on success and on failure. This
+/// hides timing differences between outcomes from remote observers as long
+/// as the inner verification finishes within the minimum.
+///
+/// # ⚠️ Warning
+///
+/// This is a coarse mitigation against remote timing analysis only. Sleep
+/// granularity and scheduling are imprecise, verification which exceeds the
+/// minimum is not padded at all, and local observers (e.g. via caches or
+/// CPU load) are not affected. It is NOT a substitute for constant-time
+/// implementations of the underlying primitives.
+#[cfg(feature = "std")]
+#[derive(Clone, Debug)]
+pub struct ConstantTime<V> {
+    verifier: V,
+    min_duration: Duration,
+}
+
+#[cfg(feature = "std")]
+impl<V> ConstantTime<V> {
+    /// Wrap the given verifier, padding verification to `min_duration`.
+    pub fn new(verifier: V, min_duration: Duration) -> Self {
+        Self {
+            verifier,
+            min_duration,
+        }
+    }
+
+    /// Get the configured minimum verification duration.
+    pub fn min_duration(&self) -> Duration {
+        self.min_duration
+    }
+
+    /// Borrow the inner verifier.
+    pub fn inner(&self) -> &V {
+        &self.verifier
+    }
+
+    /// Unwrap the inner verifier.
+    pub fn into_inner(self) -> V {
+        self.verifier
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V, S> Verifier<S> for ConstantTime<V>
+where
+    V: Verifier<S>,
+{
+    fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error> {
+        let start = Instant::now();
+        let result = self.verifier.verify(msg, signature);
+        if let Some(remaining) = self.min_duration.checked_sub(start.elapsed()) {
+            std::thread::sleep(remaining);
+        }
+        result
+    }
+}
+
+/// Check `s <= n / 2`, where both values are big-endian encoded.
+///
+/// Signatures are public, so this is not constant-time.
+fn is_low_s(s: &[u8], order: &[u8]) -> bool {
+    if s.len() != order.len() {
+        return false;
+    }
+
+    let mut carry = 0u8;
+    let half_order = order.iter().map(|&b| {
+        let half = (b >> 1) | carry;
+        carry = b << 7;
+        half
+    });
+    s.iter().copied().cmp(half_order) != Ordering::Greater
+}
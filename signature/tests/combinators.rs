@@ -0,0 +1,105 @@
+//! Tests for the `AnyOf` and `AllOf` verifier combinators.
+
+use core::cell::Cell;
+use signature::{AllOf, AnyOf, Error, Verifier};
+
+/// Mock signature which is the message checksum combined with the key.
+#[derive(Debug)]
+struct MockSignature(u8);
+
+/// Mock verifying key which counts verification attempts.
+struct MockVerifier {
+    key: u8,
+    calls: Cell<usize>,
+}
+
+impl MockVerifier {
+    fn new(key: u8) -> Self {
+        Self {
+            key,
+            calls: Cell::new(0),
+        }
+    }
+
+    fn sign(&self, msg: &[u8]) -> MockSignature {
+        MockSignature(msg.iter().fold(self.key, |acc, b| acc.wrapping_add(*b)))
+    }
+}
+
+impl Verifier<MockSignature> for MockVerifier {
+    fn verify(&self, msg: &[u8], signature: &MockSignature) -> Result<(), Error> {
+        self.calls.set(self.calls.get() + 1);
+        if self.sign(msg).0 == signature.0 {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+const MSG: &[u8] = b"rotated message";
+
+fn verifiers() -> [MockVerifier; 3] {
+    [
+        MockVerifier::new(1),
+        MockVerifier::new(2),
+        MockVerifier::new(3),
+    ]
+}
+
+fn calls(verifiers: &[MockVerifier]) -> Vec<usize> {
+    verifiers.iter().map(|v| v.calls.get()).collect()
+}
+
+#[test]
+fn any_of_success() {
+    let any = AnyOf(verifiers());
+    for i in 0..3 {
+        let sig = any.0[i].sign(MSG);
+        assert!(any.verify(MSG, &sig).is_ok());
+    }
+    // No short-circuiting
+    assert_eq!(calls(&any.0), [3, 3, 3]);
+}
+
+#[test]
+fn any_of_failure() {
+    let any = AnyOf(verifiers());
+    let sig = MockVerifier::new(4).sign(MSG);
+    assert!(any.verify(MSG, &sig).is_err());
+    assert_eq!(calls(&any.0), [1, 1, 1]);
+
+    let sig = any.0[0].sign(MSG);
+    assert!(any.verify(b"other message", &sig).is_err());
+
+    let empty = AnyOf(Vec::<MockVerifier>::new());
+    assert!(empty.verify(MSG, &sig).is_err());
+}
+
+#[test]
+fn all_of_partial_failure() {
+    // All verifiers share a key, except the last one
+    let all = AllOf(vec![
+        MockVerifier::new(1),
+        MockVerifier::new(1),
+        MockVerifier::new(2),
+    ]);
+    let sig = all.0[0].sign(MSG);
+    assert!(all.verify(MSG, &sig).is_err());
+    assert_eq!(calls(&all.0), [1, 1, 1]);
+
+    // First verifier fails, the rest are still run
+    let sig = all.0[2].sign(MSG);
+    assert!(all.verify(MSG, &sig).is_err());
+    assert_eq!(calls(&all.0), [2, 2, 2]);
+}
+
+#[test]
+fn all_of_success() {
+    let all = AllOf([MockVerifier::new(7), MockVerifier::new(7)]);
+    let sig = all.0[0].sign(MSG);
+    assert!(all.verify(MSG, &sig).is_ok());
+
+    let empty = AllOf([] as [MockVerifier; 0]);
+    assert!(empty.verify(MSG, &sig).is_err());
+}
@@ -0,0 +1,50 @@
+//! Tests for the `ConstantTime` verifier wrapper.
+
+#![cfg(feature = "std")]
+
+use signature::{ConstantTime, Error, Verifier};
+use std::time::{Duration, Instant};
+
+/// Mock signature which is valid if it equals the message length.
+struct MockSignature(usize);
+
+/// Mock verifier which accepts [`MockSignature`]s equal to the message
+/// length.
+struct MockVerifier;
+
+impl Verifier<MockSignature> for MockVerifier {
+    fn verify(&self, msg: &[u8], signature: &MockSignature) -> Result<(), Error> {
+        if msg.len() == signature.0 {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+const MIN: Duration = Duration::from_millis(20);
+const MSG: &[u8] = b"hello";
+
+#[test]
+fn success_takes_min_duration() {
+    let verifier = ConstantTime::new(MockVerifier, MIN);
+    let start = Instant::now();
+    assert!(verifier.verify(MSG, &MockSignature(MSG.len())).is_ok());
+    assert!(start.elapsed() >= MIN);
+}
+
+#[test]
+fn failure_takes_min_duration() {
+    let verifier = ConstantTime::new(MockVerifier, MIN);
+    let start = Instant::now();
+    assert!(verifier.verify(MSG, &MockSignature(0)).is_err());
+    assert!(start.elapsed() >= MIN);
+}
+
+#[test]
+fn accessors() {
+    let verifier = ConstantTime::new(MockVerifier, MIN);
+    assert_eq!(verifier.min_duration(), MIN);
+    let _: &MockVerifier = verifier.inner();
+    let _: MockVerifier = verifier.into_inner();
+}
@@ -0,0 +1,144 @@
+//! Tests for the object-safe `DynSigner` and `DynVerifier` traits.
+
+#![cfg(feature = "alloc")]
+
+use signature::{DynAdapter, DynSigner, DynVerifier, Error, SignatureEncoding, Signer, Verifier};
+
+/// Mock 4-byte signature: XOR of the key with the message folded to 4 bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ShortSignature([u8; 4]);
+
+/// Mock 8-byte signature: wrapping sum of the key and the message folded
+/// to 8 bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct LongSignature([u8; 8]);
+
+macro_rules! impl_encoding {
+    ($sig:ident, $len:expr) => {
+        impl TryFrom<&[u8]> for $sig {
+            type Error = Error;
+
+            fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+                bytes.try_into().map(Self).map_err(|_| Error::new())
+            }
+        }
+
+        impl From<$sig> for [u8; $len] {
+            fn from(sig: $sig) -> [u8; $len] {
+                sig.0
+            }
+        }
+
+        impl SignatureEncoding for $sig {
+            type Repr = [u8; $len];
+        }
+    };
+}
+
+impl_encoding!(ShortSignature, 4);
+impl_encoding!(LongSignature, 8);
+
+/// Mock key which produces both signature types.
+#[derive(Clone, Debug)]
+struct MockKey(u8);
+
+impl MockKey {
+    fn short(&self, msg: &[u8]) -> ShortSignature {
+        let mut out = [self.0; 4];
+        for (i, b) in msg.iter().enumerate() {
+            out[i % 4] ^= b;
+        }
+        ShortSignature(out)
+    }
+
+    fn long(&self, msg: &[u8]) -> LongSignature {
+        let mut out = [self.0; 8];
+        for (i, b) in msg.iter().enumerate() {
+            out[i % 8] = out[i % 8].wrapping_add(*b);
+        }
+        LongSignature(out)
+    }
+}
+
+impl Signer<ShortSignature> for MockKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<ShortSignature, Error> {
+        Ok(self.short(msg))
+    }
+}
+
+impl Signer<LongSignature> for MockKey {
+    fn try_sign(&self, msg: &[u8]) -> Result<LongSignature, Error> {
+        Ok(self.long(msg))
+    }
+}
+
+impl Verifier<ShortSignature> for MockKey {
+    fn verify(&self, msg: &[u8], signature: &ShortSignature) -> Result<(), Error> {
+        if *signature == self.short(msg) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl Verifier<LongSignature> for MockKey {
+    fn verify(&self, msg: &[u8], signature: &LongSignature) -> Result<(), Error> {
+        if *signature == self.long(msg) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+const MSG: &[u8] = b"hello, world";
+
+#[test]
+fn heterogeneous_signers() {
+    let key = MockKey(0x42);
+    let signers: Vec<Box<dyn DynSigner>> = vec![
+        Box::new(DynAdapter::<_, ShortSignature>::new(key.clone())),
+        Box::new(DynAdapter::<_, LongSignature>::new(key.clone())),
+    ];
+
+    let sigs = signers
+        .iter()
+        .map(|signer| signer.sign_dyn(MSG).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(sigs[0], key.short(MSG).to_vec());
+    assert_eq!(sigs[1], key.long(MSG).to_vec());
+}
+
+#[test]
+fn heterogeneous_verifiers() {
+    let key = MockKey(7);
+    let verifiers: Vec<Box<dyn DynVerifier>> = vec![
+        Box::new(DynAdapter::<_, ShortSignature>::new(key.clone())),
+        Box::new(DynAdapter::<_, LongSignature>::new(key.clone())),
+    ];
+
+    let short = key.short(MSG).to_vec();
+    let long = key.long(MSG).to_vec();
+
+    assert!(verifiers[0].verify_dyn(MSG, &short).is_ok());
+    assert!(verifiers[1].verify_dyn(MSG, &long).is_ok());
+
+    // Wrong length fails to decode.
+    assert!(verifiers[0].verify_dyn(MSG, &long).is_err());
+    assert!(verifiers[1].verify_dyn(MSG, &short).is_err());
+
+    // Wrong message fails to verify.
+    assert!(verifiers[0].verify_dyn(b"other", &short).is_err());
+    assert!(verifiers[1].verify_dyn(b"other", &long).is_err());
+}
+
+#[test]
+fn sign_then_verify_round_trip() {
+    let adapter = DynAdapter::<_, LongSignature>::new(MockKey(1));
+    let sig = adapter.sign_dyn(MSG).unwrap();
+    assert!(adapter.verify_dyn(MSG, &sig).is_ok());
+    assert_eq!(adapter.inner().0, 1);
+    assert_eq!(adapter.into_inner().0, 1);
+}
@@ -0,0 +1,63 @@
+//! Tests for the hex and Base64 helpers on `SignatureEncoding`.
+
+#![cfg(feature = "alloc")]
+
+use signature::{Error, SignatureEncoding};
+
+/// Fixed-size mock signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MockSignature([u8; 8]);
+
+impl TryFrom<&[u8]> for MockSignature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        bytes.try_into().map(Self).map_err(|_| Error::new())
+    }
+}
+
+impl From<MockSignature> for [u8; 8] {
+    fn from(sig: MockSignature) -> [u8; 8] {
+        sig.0
+    }
+}
+
+impl SignatureEncoding for MockSignature {
+    type Repr = [u8; 8];
+}
+
+const SIG: MockSignature = MockSignature([0x00, 0x01, 0x7f, 0x80, 0xab, 0xcd, 0xef, 0xff]);
+
+#[test]
+fn hex_round_trip() {
+    let hex = SIG.to_hex();
+    assert_eq!(hex, "00017f80abcdefff");
+    assert_eq!(MockSignature::from_hex(&hex).unwrap(), SIG);
+}
+
+#[test]
+fn hex_accepts_uppercase() {
+    assert_eq!(MockSignature::from_hex("00017F80ABCDEFFF").unwrap(), SIG);
+}
+
+#[test]
+fn hex_rejects_invalid() {
+    assert!(MockSignature::from_hex("00017f80abcdeff").is_err());
+    assert!(MockSignature::from_hex("00017f80abcdefzz").is_err());
+    assert!(MockSignature::from_hex("00017f80abcdef").is_err());
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn base64_round_trip() {
+    let b64 = SIG.to_base64();
+    assert_eq!(b64, "AAF/gKvN7/8=");
+    assert_eq!(MockSignature::from_base64(&b64).unwrap(), SIG);
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn base64_rejects_invalid() {
+    assert!(MockSignature::from_base64("AAF/gKvN7/8").is_err());
+    assert!(MockSignature::from_base64("AAF/gKvN").is_err());
+}
@@ -0,0 +1,47 @@
+//! Tests for `FixedLengthSignature`.
+
+use signature::{Error, FixedLengthSignature, SignatureEncoding};
+
+/// Fixed-size mock signature.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MockSignature([u8; 48]);
+
+impl TryFrom<&[u8]> for MockSignature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        bytes.try_into().map(Self).map_err(|_| Error::new())
+    }
+}
+
+impl From<MockSignature> for [u8; 48] {
+    fn from(sig: MockSignature) -> [u8; 48] {
+        sig.0
+    }
+}
+
+impl SignatureEncoding for MockSignature {
+    type Repr = [u8; 48];
+}
+
+impl FixedLengthSignature for MockSignature {
+    const SIGNATURE_LENGTH: usize = 48;
+}
+
+#[test]
+fn signature_length_matches_encoding() {
+    for b in [0x00, 0x5a, 0xff] {
+        let sig = MockSignature([b; 48]);
+        assert_eq!(sig.to_bytes().len(), MockSignature::SIGNATURE_LENGTH);
+        assert_eq!(sig.encoded_len(), MockSignature::SIGNATURE_LENGTH);
+    }
+}
+
+#[test]
+fn presized_buffer() {
+    let sig = MockSignature([7; 48]);
+    let mut frame = [0u8; 2 + MockSignature::SIGNATURE_LENGTH];
+    frame[..2].copy_from_slice(b"SG");
+    frame[2..].copy_from_slice(&sig.to_bytes());
+    assert_eq!(MockSignature::try_from(&frame[2..]).unwrap(), sig);
+}
@@ -0,0 +1,92 @@
+//! Tests for the `LowSOnly` verifier wrapper.
+
+use signature::{Error, HasScalarS, LowSOnly, Verifier};
+
+/// Tiny odd group order used by the mock, `n = 0x0101 = 257`.
+const ORDER: [u8; 2] = [0x01, 0x01];
+
+/// Mock signature which is the `S` component only.
+#[derive(Debug)]
+struct MockSignature(u16);
+
+impl HasScalarS for MockSignature {
+    type Bytes = [u8; 2];
+    const ORDER: &'static [u8] = &ORDER;
+
+    fn s_bytes(&self) -> [u8; 2] {
+        self.0.to_be_bytes()
+    }
+}
+
+/// Mock verifying key which accepts both `S` and `n - S`, like ECDSA.
+struct MockVerifier;
+
+impl MockVerifier {
+    fn expected_s(msg: &[u8]) -> u16 {
+        msg.iter()
+            .fold(1u16, |acc, &b| (acc * 7 + u16::from(b)) % 257)
+    }
+}
+
+impl Verifier<MockSignature> for MockVerifier {
+    fn verify(&self, msg: &[u8], signature: &MockSignature) -> Result<(), Error> {
+        let s = Self::expected_s(msg);
+        if signature.0 == s || signature.0 == 257 - s {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// Get the low-S and high-S signatures of `msg`.
+fn sign(msg: &[u8]) -> (MockSignature, MockSignature) {
+    let s = MockVerifier::expected_s(msg);
+    let (low, high) = if s <= 128 { (s, 257 - s) } else { (257 - s, s) };
+    (MockSignature(low), MockSignature(high))
+}
+
+const MSG: &[u8] = b"transaction";
+
+#[test]
+fn accepts_low_s() {
+    let (low, high) = sign(MSG);
+    assert!(MockVerifier.verify(MSG, &low).is_ok());
+    assert!(MockVerifier.verify(MSG, &high).is_ok());
+
+    let verifier = LowSOnly::new(MockVerifier);
+    assert!(verifier.verify(MSG, &low).is_ok());
+}
+
+#[test]
+fn rejects_high_s() {
+    let (_, high) = sign(MSG);
+    let verifier = LowSOnly::new(MockVerifier);
+    assert!(verifier.verify(MSG, &high).is_err());
+}
+
+#[test]
+fn rejects_invalid_low_s() {
+    let (low, _) = sign(MSG);
+    let verifier = LowSOnly::new(MockVerifier);
+    assert!(verifier.verify(b"other", &low).is_err());
+}
+
+#[test]
+fn half_order_boundary() {
+    /// Verifier which accepts everything.
+    struct AcceptAll;
+
+    impl Verifier<MockSignature> for AcceptAll {
+        fn verify(&self, _msg: &[u8], _signature: &MockSignature) -> Result<(), Error> {
+            Ok(())
+        }
+    }
+
+    // n / 2 = 128
+    let verifier = LowSOnly::new(AcceptAll);
+    assert!(verifier.verify(MSG, &MockSignature(0)).is_ok());
+    assert!(verifier.verify(MSG, &MockSignature(128)).is_ok());
+    assert!(verifier.verify(MSG, &MockSignature(129)).is_err());
+    assert!(verifier.verify(MSG, &MockSignature(256)).is_err());
+}
@@ -0,0 +1,97 @@
+//! Tests for verification with several acceptable digests.
+
+#![cfg(feature = "digest")]
+
+use digest::Digest;
+use sha2::{Sha256, Sha384};
+use signature::{DigestId, DigestVerifier, Error, MultiDigestVerifier};
+
+/// Mock signature which contains the message digest XORed with a key.
+struct MockSignature(Vec<u8>);
+
+/// Mock verifying key for signatures which XOR the digest with the key
+/// byte.
+struct MockVerifier {
+    key: u8,
+}
+
+impl MockVerifier {
+    fn check(&self, digest: &[u8], signature: &MockSignature) -> Result<(), Error> {
+        let expected = digest.iter().map(|b| b ^ self.key);
+        if digest.len() == signature.0.len() && signature.0.iter().copied().eq(expected) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl DigestVerifier<Sha256, MockSignature> for MockVerifier {
+    fn verify_digest(&self, digest: Sha256, signature: &MockSignature) -> Result<(), Error> {
+        self.check(&digest.finalize(), signature)
+    }
+}
+
+impl DigestVerifier<Sha384, MockSignature> for MockVerifier {
+    fn verify_digest(&self, digest: Sha384, signature: &MockSignature) -> Result<(), Error> {
+        self.check(&digest.finalize(), signature)
+    }
+}
+
+impl MultiDigestVerifier<MockSignature> for MockVerifier {
+    fn verify_with_digest(
+        &self,
+        digest: DigestId,
+        msg: &[u8],
+        signature: &MockSignature,
+    ) -> Result<(), Error> {
+        match digest {
+            DigestId::Sha256 => self.verify_digest(Sha256::new_with_prefix(msg), signature),
+            DigestId::Sha384 => self.verify_digest(Sha384::new_with_prefix(msg), signature),
+            _ => Err(Error::new()),
+        }
+    }
+}
+
+fn sign<D: Digest>(key: u8, msg: &[u8]) -> MockSignature {
+    MockSignature(D::digest(msg).iter().map(|b| b ^ key).collect())
+}
+
+const MSG: &[u8] = b"algorithm transition";
+const KEY: u8 = 0x5A;
+
+#[test]
+fn sha256_signature_accepted_when_allowed() {
+    let verifier = MockVerifier { key: KEY };
+    let sig = sign::<Sha256>(KEY, MSG);
+    assert!(verifier
+        .verify_any_digest(MSG, &sig, &[DigestId::Sha256])
+        .is_ok());
+    assert!(verifier
+        .verify_any_digest(MSG, &sig, &[DigestId::Sha384, DigestId::Sha256])
+        .is_ok());
+}
+
+#[test]
+fn sha256_signature_rejected_when_not_allowed() {
+    let verifier = MockVerifier { key: KEY };
+    let sig = sign::<Sha256>(KEY, MSG);
+    assert!(verifier
+        .verify_any_digest(MSG, &sig, &[DigestId::Sha384])
+        .is_err());
+    assert!(verifier
+        .verify_any_digest(MSG, &sig, &[DigestId::Sha512])
+        .is_err());
+    assert!(verifier.verify_any_digest(MSG, &sig, &[]).is_err());
+}
+
+#[test]
+fn sha384_signature_and_wrong_message() {
+    let verifier = MockVerifier { key: KEY };
+    let sig = sign::<Sha384>(KEY, MSG);
+    let allowed = [DigestId::Sha256, DigestId::Sha384];
+    assert!(verifier.verify_any_digest(MSG, &sig, &allowed).is_ok());
+    assert!(verifier
+        .verify_any_digest(b"other message", &sig, &allowed)
+        .is_err());
+}
@@ -0,0 +1,57 @@
+//! Tests for the `MultipartSigner` trait.
+
+#![cfg(feature = "digest")]
+
+use digest::{Digest, Output};
+use sha2::Sha256;
+use signature::{DigestSigner, Error, MultipartSigner, PrehashSignature, Signer};
+
+/// Mock signature which contains the message digest XORed with a key.
+#[derive(Debug, PartialEq, Eq)]
+struct MockSignature(Output<Sha256>);
+
+impl PrehashSignature for MockSignature {
+    type Digest = Sha256;
+}
+
+/// Mock signer which "signs" a digest by XORing it with the key byte.
+struct MockSigner {
+    key: u8,
+}
+
+impl DigestSigner<Sha256, MockSignature> for MockSigner {
+    fn try_sign_digest(&self, digest: Sha256) -> Result<MockSignature, Error> {
+        let mut out = digest.finalize();
+        out.iter_mut().for_each(|b| *b ^= self.key);
+        Ok(MockSignature(out))
+    }
+}
+
+impl Signer<MockSignature> for MockSigner {
+    fn try_sign(&self, msg: &[u8]) -> Result<MockSignature, Error> {
+        self.try_sign_digest(Sha256::new_with_prefix(msg))
+    }
+}
+
+const MSG: &[u8] = b"the quick brown fox jumps over the lazy dog";
+
+#[test]
+fn multipart_matches_single_slice() {
+    let signer = MockSigner { key: 0x5A };
+    let expected = signer.sign(MSG);
+
+    for split in [0, 1, 10, MSG.len()] {
+        let (a, b) = MSG.split_at(split);
+        assert_eq!(signer.multipart_sign(&[a, b]), expected);
+    }
+
+    let segments: Vec<&[u8]> = MSG.chunks(3).collect();
+    assert_eq!(signer.try_multipart_sign(&segments).unwrap(), expected);
+}
+
+#[test]
+fn multipart_empty() {
+    let signer = MockSigner { key: 0x5A };
+    assert_eq!(signer.multipart_sign(&[]), signer.sign(&[]));
+    assert_eq!(signer.multipart_sign(&[&[], &[]]), signer.sign(&[]));
+}
@@ -0,0 +1,69 @@
+//! Tests for the `PrehashBridge` adapter.
+
+#![cfg(feature = "digest")]
+
+use digest::{Digest, Output};
+use sha2::Sha256;
+use signature::{
+    digest_adapter::PrehashBridge, DigestSigner, Error, MultipartSigner, PrehashSignature, Signer,
+};
+
+/// Mock signature which contains the signed prehash XORed with a key.
+#[derive(Debug, PartialEq, Eq)]
+struct MockSignature(Output<Sha256>);
+
+impl PrehashSignature for MockSignature {
+    type Digest = Sha256;
+}
+
+/// Mock remote signer which expects SHA-256 prehashes as messages and
+/// "signs" them by XORing with the key byte.
+struct MockPrehashSigner {
+    key: u8,
+}
+
+impl Signer<MockSignature> for MockPrehashSigner {
+    fn try_sign(&self, prehash: &[u8]) -> Result<MockSignature, Error> {
+        let mut out = Output::<Sha256>::try_from(prehash).map_err(|_| Error::new())?;
+        out.iter_mut().for_each(|b| *b ^= self.key);
+        Ok(MockSignature(out))
+    }
+}
+
+const MSG: &[u8] = b"the quick brown fox jumps over the lazy dog";
+
+#[test]
+fn sign_digest_signs_finalized_output() {
+    let signer = PrehashBridge::new(MockPrehashSigner { key: 0x5A });
+    let sig = signer.sign_digest(Sha256::new_with_prefix(MSG));
+    assert_eq!(sig, signer.inner().sign(&Sha256::digest(MSG)));
+
+    let mut expected = Sha256::digest(MSG);
+    expected.iter_mut().for_each(|b| *b ^= 0x5A);
+    assert_eq!(sig, MockSignature(expected));
+}
+
+#[test]
+fn multipart_through_bridge() {
+    let signer = PrehashBridge::new(MockPrehashSigner { key: 0x5A });
+    let (a, b) = MSG.split_at(10);
+    assert_eq!(
+        signer.multipart_sign(&[a, b]),
+        signer.sign_digest(Sha256::new_with_prefix(MSG))
+    );
+}
+
+#[test]
+fn inner_error_is_propagated() {
+    struct FailingSigner;
+
+    impl Signer<MockSignature> for FailingSigner {
+        fn try_sign(&self, _: &[u8]) -> Result<MockSignature, Error> {
+            Err(Error::new())
+        }
+    }
+
+    let signer = PrehashBridge::new(FailingSigner);
+    assert!(signer.try_sign_digest(Sha256::new()).is_err());
+    let _: FailingSigner = signer.into_inner();
+}
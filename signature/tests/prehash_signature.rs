@@ -0,0 +1,44 @@
+//! Tests for the `PrehashSignature` trait.
+
+#![cfg(all(feature = "digest", feature = "const-oid"))]
+
+use sha2::Sha256;
+use signature::{const_oid::ObjectIdentifier, PrehashSignature};
+
+/// Mock signature type which reports `ecdsa-with-SHA256` as its OID.
+struct MockEcdsaSignature;
+
+impl PrehashSignature for MockEcdsaSignature {
+    type Digest = Sha256;
+
+    const OID: Option<ObjectIdentifier> = Some(ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2"));
+}
+
+/// Mock signature type which uses the default OID.
+struct MockSignature;
+
+impl PrehashSignature for MockSignature {
+    type Digest = Sha256;
+}
+
+/// Generic helper which only depends on the trait.
+fn oid_of<S: PrehashSignature>() -> Option<ObjectIdentifier> {
+    S::OID
+}
+
+#[test]
+fn reports_oid() {
+    assert_eq!(
+        oid_of::<MockEcdsaSignature>(),
+        Some(ObjectIdentifier::new_unwrap("1.2.840.10045.4.3.2"))
+    );
+    assert_eq!(
+        oid_of::<MockEcdsaSignature>().unwrap().to_string(),
+        "1.2.840.10045.4.3.2"
+    );
+}
+
+#[test]
+fn defaults_to_none() {
+    assert_eq!(oid_of::<MockSignature>(), None);
+}
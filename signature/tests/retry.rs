@@ -0,0 +1,74 @@
+//! Tests for the `Retry` signer wrapper.
+
+#![cfg(feature = "std")]
+
+use signature::{Error, Retry, Signer};
+use std::{cell::Cell, time::Duration};
+
+/// Mock signature containing the message length.
+#[derive(Debug, PartialEq)]
+struct MockSignature(usize);
+
+/// Mock signer which fails its first `failures` attempts.
+struct FlakySigner {
+    failures: u32,
+    attempts: Cell<u32>,
+}
+
+impl FlakySigner {
+    fn new(failures: u32) -> Self {
+        Self {
+            failures,
+            attempts: Cell::new(0),
+        }
+    }
+}
+
+impl Signer<MockSignature> for FlakySigner {
+    fn try_sign(&self, msg: &[u8]) -> Result<MockSignature, Error> {
+        let attempt = self.attempts.get() + 1;
+        self.attempts.set(attempt);
+        if attempt <= self.failures {
+            Err(Error::new())
+        } else {
+            Ok(MockSignature(msg.len()))
+        }
+    }
+}
+
+const MSG: &[u8] = b"hello";
+
+#[test]
+fn succeeds_after_transient_failures() {
+    let backoffs = Cell::new(Vec::new());
+    let signer = Retry::new(FlakySigner::new(2), 3, |n| {
+        let mut v = backoffs.take();
+        v.push(n);
+        backoffs.set(v);
+        Duration::ZERO
+    });
+    assert_eq!(signer.try_sign(MSG).unwrap(), MockSignature(MSG.len()));
+    assert_eq!(signer.inner().attempts.get(), 3);
+    assert_eq!(backoffs.take(), [1, 2]);
+}
+
+#[test]
+fn no_retry_on_success() {
+    let signer = Retry::new(FlakySigner::new(0), 3, |_| Duration::ZERO);
+    assert!(signer.try_sign(MSG).is_ok());
+    assert_eq!(signer.into_inner().attempts.get(), 1);
+}
+
+#[test]
+fn returns_error_when_exhausted() {
+    let signer = Retry::new(FlakySigner::new(5), 3, |_| Duration::ZERO);
+    assert!(signer.try_sign(MSG).is_err());
+    assert_eq!(signer.inner().attempts.get(), 4);
+}
+
+#[test]
+fn zero_retries_tries_once() {
+    let signer = Retry::new(FlakySigner::new(1), 0, |_| Duration::ZERO);
+    assert!(signer.try_sign(MSG).is_err());
+    assert_eq!(signer.inner().attempts.get(), 1);
+}
@@ -0,0 +1,66 @@
+//! Tests for the `SignMessage` helper trait.
+
+use signature::{Error, Keypair, SignMessage, Signer, Verifier};
+
+/// Mock signature which contains a checksum of the message and the key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MockSignature([u8; 8]);
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MockVerifyingKey(u8);
+
+fn checksum(key: u8, msg: &[u8]) -> [u8; 8] {
+    let mut out = [key; 8];
+    for (i, b) in msg.iter().enumerate() {
+        out[i % 8] = out[i % 8].wrapping_add(*b).rotate_left(3);
+    }
+    out
+}
+
+impl Verifier<MockSignature> for MockVerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &MockSignature) -> Result<(), Error> {
+        if signature.0 == checksum(self.0, msg) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// Mock keypair which refuses to sign empty messages.
+struct MockKeypair(u8);
+
+impl Keypair for MockKeypair {
+    type VerifyingKey = MockVerifyingKey;
+
+    fn verifying_key(&self) -> MockVerifyingKey {
+        MockVerifyingKey(self.0)
+    }
+}
+
+impl Signer<MockSignature> for MockKeypair {
+    fn try_sign(&self, msg: &[u8]) -> Result<MockSignature, Error> {
+        if msg.is_empty() {
+            return Err(Error::new());
+        }
+        Ok(MockSignature(checksum(self.0, msg)))
+    }
+}
+
+const TBS: &[u8] = b"CN=example.com";
+
+#[test]
+fn sign_tbs_returns_consistent_pair() {
+    let keypair = MockKeypair(42);
+    let (signature, verifying_key): (MockSignature, _) = keypair.sign_tbs(TBS).unwrap();
+    assert_eq!(verifying_key, keypair.verifying_key());
+    assert_eq!(signature, keypair.sign(TBS));
+    assert!(verifying_key.verify(TBS, &signature).is_ok());
+    assert!(verifying_key.verify(b"CN=other", &signature).is_err());
+}
+
+#[test]
+fn sign_tbs_propagates_error() {
+    let result: Result<(MockSignature, _), _> = MockKeypair(1).sign_tbs(b"");
+    assert!(result.is_err());
+}
@@ -0,0 +1,76 @@
+//! Tests for the `VerifyError` type.
+
+use signature::{Error, SignatureEncoding, Verifier, VerifyError};
+
+/// Mock signature which encodes a single byte and must be exactly one byte long.
+#[derive(Clone)]
+struct MockSignature(u8);
+
+impl From<MockSignature> for [u8; 1] {
+    fn from(signature: MockSignature) -> [u8; 1] {
+        [signature.0]
+    }
+}
+
+impl SignatureEncoding for MockSignature {
+    type Repr = [u8; 1];
+}
+
+impl TryFrom<&[u8]> for MockSignature {
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Error> {
+        match bytes {
+            [b] => Ok(Self(*b)),
+            _ => Err(Error::new()),
+        }
+    }
+}
+
+/// Mock verifier which accepts the XOR of all message bytes as a signature.
+struct MockVerifier;
+
+impl Verifier<MockSignature> for MockVerifier {
+    fn verify(&self, msg: &[u8], signature: &MockSignature) -> Result<(), Error> {
+        let expected = msg.iter().fold(0, |acc, b| acc ^ b);
+        if expected == signature.0 {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+const MSG: &[u8] = &[1, 2, 4];
+
+#[test]
+fn valid() {
+    assert_eq!(MockVerifier.verify_detailed(MSG, &[7]), Ok(()));
+}
+
+#[test]
+fn malformed() {
+    assert_eq!(
+        MockVerifier.verify_detailed(MSG, &[]),
+        Err(VerifyError::Malformed)
+    );
+    assert_eq!(
+        MockVerifier.verify_detailed(MSG, &[7, 0]),
+        Err(VerifyError::Malformed)
+    );
+}
+
+#[test]
+fn invalid() {
+    assert_eq!(
+        MockVerifier.verify_detailed(MSG, &[6]),
+        Err(VerifyError::Invalid)
+    );
+}
+
+#[test]
+fn converts_to_opaque_error() {
+    let err: Error = VerifyError::Malformed.into();
+    assert_eq!(err.to_string(), "signature error");
+    assert_eq!(VerifyError::Invalid.to_string(), "invalid signature");
+}
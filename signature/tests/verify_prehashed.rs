@@ -0,0 +1,73 @@
+//! Tests for verification of prehashed messages.
+
+#![cfg(feature = "digest")]
+
+use digest::{Digest, Output};
+use sha2::Sha256;
+use signature::{hazmat::PrehashVerifier, Error, PrehashSignature, Verifier};
+
+/// Mock signature which contains the message digest XORed with a key.
+#[derive(Debug, PartialEq, Eq)]
+struct MockSignature(Output<Sha256>);
+
+impl PrehashSignature for MockSignature {
+    type Digest = Sha256;
+}
+
+/// Mock verifying key for signatures which XOR the prehash with the key byte.
+struct MockVerifier {
+    key: u8,
+}
+
+impl PrehashVerifier<MockSignature> for MockVerifier {
+    fn verify_prehash(&self, prehash: &[u8], signature: &MockSignature) -> Result<(), Error> {
+        let expected = signature.0.iter().map(|b| b ^ self.key);
+        if prehash.len() == signature.0.len() && prehash.iter().copied().eq(expected) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl Verifier<MockSignature> for MockVerifier {
+    fn verify(&self, msg: &[u8], signature: &MockSignature) -> Result<(), Error> {
+        self.verify_prehash(&Sha256::digest(msg), signature)
+    }
+}
+
+fn sign(key: u8, prehash: &Output<Sha256>) -> MockSignature {
+    let mut sig = *prehash;
+    sig.iter_mut().for_each(|b| *b ^= key);
+    MockSignature(sig)
+}
+
+const MSG: &[u8] = b"the quick brown fox jumps over the lazy dog";
+
+#[test]
+fn verify_prehashed_streamed_digest() {
+    let verifier = MockVerifier { key: 0x5A };
+
+    let mut hasher = Sha256::new();
+    for chunk in MSG.chunks(5) {
+        hasher.update(chunk);
+    }
+    let prehash = hasher.finalize();
+    let sig = sign(0x5A, &prehash);
+
+    assert!(verifier.verify_prehashed(&prehash, &sig).is_ok());
+    assert!(verifier.verify(MSG, &sig).is_ok());
+}
+
+#[test]
+fn verify_prehashed_rejects_mismatch() {
+    let verifier = MockVerifier { key: 0x5A };
+    let prehash = Sha256::digest(MSG);
+    let sig = sign(0x5A, &prehash);
+
+    let other = Sha256::digest(b"other message");
+    assert!(verifier.verify_prehashed(&other, &sig).is_err());
+
+    let wrong_key = sign(0x42, &prehash);
+    assert!(verifier.verify_prehashed(&prehash, &wrong_key).is_err());
+}
@@ -0,0 +1,93 @@
+//! Tests for the `VerifyingSigner` adapter.
+
+use core::cell::Cell;
+use signature::{Error, Keypair, Signer, Verifier, VerifyingSigner};
+
+/// Mock signature which contains a checksum of the message and the key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct MockSignature([u8; 8]);
+
+#[derive(Clone, Debug)]
+struct MockVerifyingKey(u8);
+
+fn checksum(key: u8, msg: &[u8]) -> [u8; 8] {
+    let mut out = [key; 8];
+    for (i, b) in msg.iter().enumerate() {
+        out[i % 8] = out[i % 8].wrapping_add(*b).rotate_left(3);
+    }
+    out
+}
+
+impl Verifier<MockSignature> for MockVerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &MockSignature) -> Result<(), Error> {
+        if signature.0 == checksum(self.0, msg) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// Mock signer which deterministically corrupts every `fault_period`-th
+/// signature, emulating a hardware fault.
+struct MockSigner {
+    key: u8,
+    fault_period: usize,
+    counter: Cell<usize>,
+}
+
+impl MockSigner {
+    fn new(key: u8, fault_period: usize) -> Self {
+        Self {
+            key,
+            fault_period,
+            counter: Cell::new(0),
+        }
+    }
+}
+
+impl Keypair for MockSigner {
+    type VerifyingKey = MockVerifyingKey;
+
+    fn verifying_key(&self) -> MockVerifyingKey {
+        MockVerifyingKey(self.key)
+    }
+}
+
+impl Signer<MockSignature> for MockSigner {
+    fn try_sign(&self, msg: &[u8]) -> Result<MockSignature, Error> {
+        let n = self.counter.get() + 1;
+        self.counter.set(n);
+        let mut sig = checksum(self.key, msg);
+        if n % self.fault_period == 0 {
+            sig[0] ^= 0x01;
+        }
+        Ok(MockSignature(sig))
+    }
+}
+
+const MSG: &[u8] = b"the quick brown fox jumps over the lazy dog";
+
+#[test]
+fn valid_signatures_pass_through() {
+    let signer = VerifyingSigner::new(MockSigner::new(0x5A, usize::MAX));
+    let sig = signer.try_sign(MSG).unwrap();
+    assert_eq!(sig, MockSignature(checksum(0x5A, MSG)));
+    assert!(signer.verifying_key().verify(MSG, &sig).is_ok());
+}
+
+#[test]
+fn corrupted_signatures_are_caught() {
+    // Every second signature is corrupted
+    let raw = MockSigner::new(0x5A, 2);
+    assert!(raw.try_sign(MSG).is_ok());
+    let corrupted = raw.try_sign(MSG).unwrap();
+    assert!(raw.verifying_key().verify(MSG, &corrupted).is_err());
+
+    let signer = VerifyingSigner::new(MockSigner::new(0x5A, 2));
+    assert!(signer.try_sign(MSG).is_ok());
+    assert!(signer.try_sign(MSG).is_err());
+    assert!(signer.try_sign(MSG).is_ok());
+    assert!(signer.try_sign(MSG).is_err());
+    assert_eq!(signer.into_inner().counter.get(), 4);
+}
@@ -69,7 +69,7 @@ pub trait UniversalHash: BlockSizeUser + Sized {
             #[inline(always)]
             fn call<B: UhfBackend<BlockSize = BS>>(self, backend: &mut B) {
                 let pb = B::ParBlocksSize::USIZE;
-                if pb > 1 {
+                if pb > 1 && B::par_blocks_runtime() >= pb {
                     let (par_blocks, tail) = Array::slice_as_chunks(self.blocks);
                     for par_block in par_blocks {
                         backend.proc_par_blocks(par_block);
@@ -96,6 +96,17 @@ pub trait UniversalHash: BlockSizeUser + Sized {
     /// Message Authentication Codes (MACs) based on universal hashing.
     #[inline]
     fn update_padded(&mut self, data: &[u8]) {
+        self.update_padded_counted(data);
+    }
+
+    /// Input data into the universal hash function in the same way as
+    /// [`update_padded`][UniversalHash::update_padded] and return the number
+    /// of data bytes in the final zero-padded block.
+    ///
+    /// Returns zero if the length of the data is a multiple of the block size,
+    /// i.e. if no padding was applied.
+    #[inline]
+    fn update_padded_counted(&mut self, data: &[u8]) -> usize {
         let (blocks, tail) = Array::slice_as_chunks(data);
 
         self.update(blocks);
@@ -105,11 +116,43 @@ pub trait UniversalHash: BlockSizeUser + Sized {
             padded_block[..tail.len()].copy_from_slice(tail);
             self.update(slice::from_ref(&padded_block));
         }
+
+        tail.len()
     }
 
     /// Retrieve result and consume hasher instance.
     fn finalize(self) -> Block<Self>;
 
+    /// Compute [`UniversalHash`] of `data` using the provided `key`.
+    ///
+    /// This is a convenience method which initializes the hash function,
+    /// inputs `data` using [`update_padded`][UniversalHash::update_padded],
+    /// and returns the result of [`finalize`][UniversalHash::finalize].
+    #[inline]
+    fn compute(key: &Key<Self>, data: &[u8]) -> Block<Self>
+    where
+        Self: KeyInit,
+    {
+        let mut uhf = Self::new(key);
+        uhf.update_padded(data);
+        uhf.finalize()
+    }
+
+    /// Verify that [`UniversalHash`] of `data` computed using the provided
+    /// `key` matches a given `expected` value.
+    ///
+    /// See [`compute`][UniversalHash::compute] for details about how `data`
+    /// is processed.
+    #[inline]
+    fn compute_verify(key: &Key<Self>, data: &[u8], expected: &Block<Self>) -> Result<(), Error>
+    where
+        Self: KeyInit,
+    {
+        let mut uhf = Self::new(key);
+        uhf.update_padded(data);
+        uhf.verify(expected)
+    }
+
     /// Obtain the output of a [`UniversalHash`] computation and reset it back
     /// to its initial state.
     #[inline]
@@ -137,7 +180,37 @@ pub trait UniversalHash: BlockSizeUser + Sized {
     }
 }
 
-/// Error type used by the [`UniversalHash::verify`] method
+/// Extension trait for keyed universal hash functions which can be
+/// re-initialized with a key.
+///
+/// Keyed UHFs are often used to compute several one-time MACs, each with
+/// its own key. Unlike [`Reset`], which must restore the key the instance
+/// was created with, methods of this trait explicitly re-initialize the hash
+/// function from a provided key, so its state never depends on how
+/// [`Reset`] is implemented.
+///
+/// This trait is blanket-implemented for all types which implement
+/// [`UniversalHash`] and [`KeyInit`].
+pub trait KeyedUhf: UniversalHash + KeyInit {
+    /// Discard the current state and re-initialize the hash function
+    /// using `key`.
+    #[inline]
+    fn reset_with_key(&mut self, key: &Key<Self>) {
+        *self = Self::new(key);
+    }
+
+    /// Obtain the output of a [`UniversalHash`] computation and re-initialize
+    /// the hash function using `key`.
+    #[inline]
+    fn finalize_reset_rekey(&mut self, key: &Key<Self>) -> Block<Self> {
+        core::mem::replace(self, Self::new(key)).finalize()
+    }
+}
+
+impl<T: UniversalHash + KeyInit> KeyedUhf for T {}
+
+/// Error type used by the [`UniversalHash::verify`] and
+/// [`UniversalHash::compute_verify`] methods
 /// to indicate that UHF output is not equal the expected value.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]
 pub struct Error;
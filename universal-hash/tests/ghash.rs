@@ -0,0 +1,169 @@
+//! Tests for the provided `UniversalHash` methods using a test GHASH implementation.
+
+use hex_literal::hex;
+use universal_hash::{
+    consts::{U1, U16},
+    crypto_common::{BlockSizeUser, KeySizeUser, ParBlocksSizeUser},
+    Block, Error, Key, KeyInit, KeyedUhf, UhfBackend, UhfClosure, UniversalHash,
+};
+
+/// Straightforward (slow and not constant-time) GHASH implementation
+/// as described in NIST SP 800-38D. Used only for testing.
+#[derive(Clone, Debug)]
+struct TestGhash {
+    h: u128,
+    y: u128,
+}
+
+impl KeySizeUser for TestGhash {
+    type KeySize = U16;
+}
+
+impl KeyInit for TestGhash {
+    fn new(key: &Key<Self>) -> Self {
+        Self {
+            h: u128::from_be_bytes((*key).into()),
+            y: 0,
+        }
+    }
+}
+
+impl BlockSizeUser for TestGhash {
+    type BlockSize = U16;
+}
+
+impl ParBlocksSizeUser for TestGhash {
+    type ParBlocksSize = U1;
+}
+
+impl UhfBackend for TestGhash {
+    fn proc_block(&mut self, block: &Block<Self>) {
+        let x = self.y ^ u128::from_be_bytes((*block).into());
+        self.y = gf_mul(x, self.h);
+    }
+}
+
+impl UniversalHash for TestGhash {
+    fn update_with_backend(&mut self, f: impl UhfClosure<BlockSize = Self::BlockSize>) {
+        f.call(self);
+    }
+
+    fn finalize(self) -> Block<Self> {
+        self.y.to_be_bytes().into()
+    }
+}
+
+fn gf_mul(x: u128, y: u128) -> u128 {
+    const R: u128 = 0xE1 << 120;
+    let mut z = 0;
+    let mut v = y;
+    for i in 0..128 {
+        if (x >> (127 - i)) & 1 == 1 {
+            z ^= v;
+        }
+        v = if v & 1 == 1 { (v >> 1) ^ R } else { v >> 1 };
+    }
+    z
+}
+
+/// GCM specification test case 2: ciphertext followed by the length block.
+const H: [u8; 16] = hex!("66e94bd4ef8a2c3b884cfa59ca342b2e");
+const DATA: [u8; 32] = hex!(
+    "0388dace60b6a392f328c2b971b2fe78"
+    "00000000000000000000000000000080"
+);
+const EXPECTED: [u8; 16] = hex!("f38cbb1ad69223dcc3457ae5b6b0f885");
+
+#[test]
+fn compute_known_vector() {
+    let out = TestGhash::compute(&H.into(), &DATA);
+    assert_eq!(out, Block::<TestGhash>::from(EXPECTED));
+}
+
+#[test]
+fn compute_matches_incremental() {
+    let data = [0x5Au8; 37];
+    let mut uhf = TestGhash::new(&H.into());
+    uhf.update_padded(&data);
+    assert_eq!(TestGhash::compute(&H.into(), &data), uhf.finalize());
+}
+
+#[test]
+fn compute_verify() {
+    assert_eq!(
+        TestGhash::compute_verify(&H.into(), &DATA, &EXPECTED.into()),
+        Ok(())
+    );
+
+    let mut bad = EXPECTED;
+    bad[15] ^= 1;
+    assert_eq!(
+        TestGhash::compute_verify(&H.into(), &DATA, &bad.into()),
+        Err(Error)
+    );
+}
+
+#[test]
+fn update_padded_counted() {
+    let data = [0x5Au8; 48];
+    for (len, tail_len) in [
+        (0, 0),
+        (1, 1),
+        (15, 15),
+        (16, 0),
+        (17, 1),
+        (32, 0),
+        (47, 15),
+    ] {
+        let mut uhf = TestGhash::new(&H.into());
+        assert_eq!(uhf.update_padded_counted(&data[..len]), tail_len);
+
+        let mut expected = TestGhash::new(&H.into());
+        expected.update_padded(&data[..len]);
+        assert_eq!(uhf.finalize(), expected.finalize());
+    }
+}
+
+#[test]
+fn update_padded_counted_pads_with_zeros() {
+    let mut uhf = TestGhash::new(&H.into());
+    assert_eq!(uhf.update_padded_counted(&[1, 2, 3]), 3);
+
+    let mut block = Block::<TestGhash>::default();
+    block[..3].copy_from_slice(&[1, 2, 3]);
+    let mut expected = TestGhash::new(&H.into());
+    expected.update(&[block]);
+    assert_eq!(uhf.finalize(), expected.finalize());
+}
+
+#[test]
+fn finalize_reset_rekey() {
+    let key2 = hex!("b83b533708bf535d0aa6e52980d53b78");
+    let data2 = [0x5Au8; 37];
+
+    let mut uhf = TestGhash::new(&H.into());
+    uhf.update_padded(&DATA);
+    let out1 = uhf.finalize_reset_rekey(&key2.into());
+    assert_eq!(out1, Block::<TestGhash>::from(EXPECTED));
+
+    uhf.update_padded(&data2);
+    let out2 = uhf.finalize_reset_rekey(&H.into());
+    assert_eq!(out2, TestGhash::compute(&key2.into(), &data2));
+
+    // The first key is restored
+    uhf.update_padded(&DATA);
+    assert_eq!(uhf.finalize(), Block::<TestGhash>::from(EXPECTED));
+}
+
+#[test]
+fn reset_with_key() {
+    let key2 = hex!("b83b533708bf535d0aa6e52980d53b78");
+    let data2 = [0x5Au8; 37];
+
+    let mut uhf = TestGhash::new(&key2.into());
+    // Partially processed state is discarded
+    uhf.update_padded(&data2);
+    uhf.reset_with_key(&H.into());
+    uhf.update_padded(&DATA);
+    assert_eq!(uhf.finalize(), Block::<TestGhash>::from(EXPECTED));
+}